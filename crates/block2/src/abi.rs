@@ -5,13 +5,11 @@
 //! [ABI]: https://clang.llvm.org/docs/Block-ABI-Apple.html
 #![allow(unused)]
 
-use core::ffi::{c_char, c_int, c_ulong, c_void};
+use core::ffi::{c_char, c_int, c_ulong, c_void, CStr};
 use core::fmt;
 use core::mem::MaybeUninit;
 use core::ops::{BitAnd, BitOr};
 
-use alloc::format;
-
 use crate::ffi::Class;
 
 /// Block descriptor flags.
@@ -124,48 +122,67 @@ impl BitAnd for BlockFlags {
 }
 
 impl fmt::Debug for BlockFlags {
+    /// Print the flags symbolically, e.g. `BLOCK_HAS_COPY_DISPOSE |
+    /// BLOCK_HAS_SIGNATURE`, followed by the reference count if non-zero.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut f = f.debug_struct("BlockFlags");
-        f.field("value", &format!("{:032b}", self.0));
-
-        macro_rules! test_flags {
+        let mut remaining = self.0 & !Self::BLOCK_REFCOUNT_MASK.0;
+        let mut first = true;
+        let mut write = |f: &mut fmt::Formatter<'_>, s: fmt::Arguments<'_>| {
+            if !first {
+                f.write_str(" | ")?;
+            }
+            first = false;
+            f.write_fmt(s)
+        };
+
+        macro_rules! write_flags {
             {$(
                 $(#[$m:meta])?
-                $name:ident: $flag:ident
-            );* $(;)?} => ($(
+                $flag:ident
+            ),* $(,)?} => ($(
                 $(#[$m])?
-                f.field(stringify!($name), &self.has(Self::$flag));
+                if self.has(Self::$flag) {
+                    write(f, format_args!(stringify!($flag)))?;
+                    remaining &= !Self::$flag.0;
+                }
             )*)
         }
-        test_flags! {
+        // Ordered by bit.
+        write_flags! {
             #[cfg(target_vendor = "apple")]
-            deallocating: BLOCK_DEALLOCATING;
+            BLOCK_DEALLOCATING,
             #[cfg(target_vendor = "apple")]
-            inline_layout_string: BLOCK_INLINE_LAYOUT_STRING;
+            BLOCK_INLINE_LAYOUT_STRING,
             #[cfg(target_vendor = "apple")]
-            small_descriptor: BLOCK_SMALL_DESCRIPTOR;
+            BLOCK_SMALL_DESCRIPTOR,
             #[cfg(target_vendor = "apple")]
-            is_noescape: BLOCK_IS_NOESCAPE;
+            BLOCK_IS_NOESCAPE,
             #[cfg(target_vendor = "apple")]
-            needs_free: BLOCK_NEEDS_FREE;
-            has_copy_dispose: BLOCK_HAS_COPY_DISPOSE;
-            has_ctor: BLOCK_HAS_CTOR;
+            BLOCK_NEEDS_FREE,
+            BLOCK_HAS_COPY_DISPOSE,
+            BLOCK_HAS_CTOR,
             #[cfg(target_vendor = "apple")]
-            is_gc: BLOCK_IS_GC;
-            is_global: BLOCK_IS_GLOBAL;
-            use_stret: BLOCK_USE_STRET;
-            has_signature: BLOCK_HAS_SIGNATURE;
+            BLOCK_IS_GC,
+            BLOCK_IS_GLOBAL,
+            BLOCK_USE_STRET,
+            BLOCK_HAS_SIGNATURE,
             #[cfg(target_vendor = "apple")]
-            has_extended_layout: BLOCK_HAS_EXTENDED_LAYOUT;
+            BLOCK_HAS_EXTENDED_LAYOUT,
         }
 
-        f.field("over_referenced", &self.has(Self::BLOCK_REFCOUNT_MASK));
-        f.field(
-            "reference_count",
-            &((*self & Self::BLOCK_REFCOUNT_MASK).0 >> 1),
-        );
+        // Unknown flags.
+        if remaining != 0 {
+            write(f, format_args!("{remaining:#x}"))?;
+        }
+        if first {
+            f.write_str("(empty)")?;
+        }
 
-        f.finish_non_exhaustive()
+        let reference_count = (*self & Self::BLOCK_REFCOUNT_MASK).0 >> 1;
+        if reference_count != 0 {
+            write!(f, " (reference count: {reference_count})")?;
+        }
+        Ok(())
     }
 }
 
@@ -241,6 +258,41 @@ pub struct BlockHeader {
     pub(crate) descriptor: BlockDescriptorPtr,
 }
 
+impl BlockHeader {
+    /// The Objective-C type encoding of the block, if it has one.
+    ///
+    /// All the supported runtimes use the same flag and descriptor layout for
+    /// this, so we only need to check which descriptor is in use.
+    ///
+    /// Blocks with a small descriptor (which Apple's compiler may emit) store
+    /// relative offsets instead of pointers, which we don't support reading,
+    /// so for those this returns `None`.
+    ///
+    /// # Safety
+    ///
+    /// The descriptor must be valid, and match the flags.
+    pub(crate) unsafe fn encoding(&self) -> Option<&CStr> {
+        if !self.flags.has(BlockFlags::BLOCK_HAS_SIGNATURE) {
+            return None;
+        }
+        if self.flags.has(BlockFlags::BLOCK_SMALL_DESCRIPTOR) {
+            return None;
+        }
+        let encoding = if self.flags.has(BlockFlags::BLOCK_HAS_COPY_DISPOSE) {
+            unsafe { (*self.descriptor.with_copy_dispose_signature).encoding }
+        } else {
+            unsafe { (*self.descriptor.with_signature).encoding }
+        };
+        if encoding.is_null() {
+            None
+        } else {
+            // SAFETY: The encoding is a valid C string, and it lives at least
+            // as long as the descriptor.
+            Some(unsafe { CStr::from_ptr(encoding) })
+        }
+    }
+}
+
 /// The type of this is:
 /// ```pseudo-code
 /// match (BLOCK_HAS_COPY_DISPOSE, BLOCK_HAS_SIGNATURE) {
@@ -351,10 +403,88 @@ pub(crate) struct BlockDescriptorCopyDisposeSignature {
     pub(crate) encoding: *const c_char,
 }
 
+/// The header of a `__block` variable.
+///
+/// This is followed by the variable itself.
+#[repr(C)]
+#[doc(alias = "Block_byref")]
+#[doc(alias = "block_byref_obj")]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct BlockByRefHeader {
+    /// Initialized to NULL by the compiler.
+    ///
+    /// Set to `_NSConcreteWeakBlockVariable` for `__weak __block` variables.
+    pub(crate) isa: *const Class,
+    /// Pointer to the current location of the variable.
+    ///
+    /// Points to the variable itself while it is on the stack, and is
+    /// updated to point to the heap copy once it has been copied.
+    pub(crate) forwarding: *mut BlockByRefHeader,
+    /// Flags and reference count.
+    ///
+    /// `BLOCK_HAS_COPY_DISPOSE` signifies that the helpers below are present.
+    pub(crate) flags: BlockFlags,
+    /// Size of the header plus the variable.
+    pub(crate) size: u32,
+    /// Move the variable from `src` to `dst`, when copying it to the heap.
+    pub(crate) keep: Option<unsafe extern "C-unwind" fn(dst: *mut c_void, src: *mut c_void)>,
+    /// Destroy the variable.
+    #[doc(alias = "byref_dispose")]
+    pub(crate) destroy: Option<unsafe extern "C-unwind" fn(src: *mut c_void)>,
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::format;
+    use core::{mem, ptr};
+
     use super::*;
 
+    #[test]
+    fn debug_flags() {
+        assert_eq!(format!("{:?}", BlockFlags::EMPTY), "(empty)");
+        assert_eq!(
+            format!(
+                "{:?}",
+                BlockFlags::BLOCK_HAS_SIGNATURE | BlockFlags::BLOCK_HAS_COPY_DISPOSE
+            ),
+            "BLOCK_HAS_COPY_DISPOSE | BLOCK_HAS_SIGNATURE"
+        );
+        // Reference count.
+        assert_eq!(
+            format!("{:?}", BlockFlags::BLOCK_HAS_COPY_DISPOSE | BlockFlags(2)),
+            if BlockFlags::BLOCK_REFCOUNT_MASK.0 != 0 {
+                "BLOCK_HAS_COPY_DISPOSE (reference count: 1)"
+            } else {
+                "BLOCK_HAS_COPY_DISPOSE | 0x2"
+            }
+        );
+    }
+
+    #[test]
+    fn encoding_small_descriptor() {
+        let encoding = CStr::from_bytes_with_nul(b"v8@?0\0").unwrap();
+        let descriptor = BlockDescriptorSignature {
+            reserved: 0,
+            size: mem::size_of::<BlockHeader>() as c_ulong,
+            encoding: encoding.as_ptr(),
+        };
+        let mut header = BlockHeader {
+            isa: ptr::null(),
+            flags: BlockFlags::BLOCK_HAS_SIGNATURE,
+            reserved: MaybeUninit::new(0),
+            invoke: None,
+            descriptor: BlockDescriptorPtr {
+                with_signature: &descriptor,
+            },
+        };
+        assert_eq!(unsafe { header.encoding() }, Some(encoding));
+
+        // The descriptor is laid out differently, so we can't read it.
+        header.flags = header.flags | BlockFlags::BLOCK_SMALL_DESCRIPTOR;
+        assert_eq!(unsafe { header.encoding() }, None);
+    }
+
     fn assert_no_trailing_padding<T>() {
         struct AddU8<T> {
             t: T,
@@ -366,6 +496,7 @@ mod tests {
     #[test]
     fn no_types_have_padding() {
         assert_no_trailing_padding::<BlockHeader>();
+        assert_no_trailing_padding::<BlockByRefHeader>();
         assert_no_trailing_padding::<BlockDescriptorPtr>();
         assert_no_trailing_padding::<BlockDescriptor>();
         assert_no_trailing_padding::<BlockDescriptorCopyDispose>();
@@ -1,10 +1,11 @@
+use core::ffi::CStr;
 use core::fmt;
 use core::marker::PhantomData;
 use core::ptr::NonNull;
 
 use objc2::encode::{Encoding, RefEncode};
 
-use crate::abi::BlockHeader;
+use crate::abi::{BlockFlags, BlockHeader};
 use crate::debug::debug_block_header;
 use crate::rc_block::block_copy_fail;
 use crate::{BlockFn, RcBlock};
@@ -67,7 +68,7 @@ unsafe impl<F: ?Sized + BlockFn> RefEncode for Block<F> {
 }
 
 impl<F: ?Sized> Block<F> {
-    fn header(&self) -> &BlockHeader {
+    pub(crate) fn header(&self) -> &BlockHeader {
         let ptr: NonNull<Self> = NonNull::from(self);
         let ptr: NonNull<BlockHeader> = ptr.cast();
         // SAFETY: `Block` is `BlockHeader` + closure
@@ -84,22 +85,73 @@ impl<F: ?Sized> Block<F> {
     /// This distinction should not matter, except for micro-optimizations.
     ///
     /// [`StackBlock`]: crate::StackBlock
+    ///
+    /// More precisely, the returned block is:
+    /// - The same block if this is a global block (e.g. from
+    ///   [`global_block!`]). These live for the duration of the program and
+    ///   are not reference-counted, so nothing is copied.
+    /// - The same block, with its reference count incremented, if this is
+    ///   already a heap block (e.g. from [`RcBlock`]).
+    /// - A new heap block, if this is a stack block. The closure is cloned
+    ///   into the new allocation.
+    ///
+    /// Either way, the returned `RcBlock` owns a retain count, and releases
+    /// it when dropped, so it is safe to e.g. [`RcBlock::into_raw`] it, and
+    /// hand the pointer to C code that later calls `_Block_release`.
+    ///
+    /// [`global_block!`]: crate::global_block
     #[doc(alias = "Block_copy")]
     #[doc(alias = "_Block_copy")]
     #[inline]
     pub fn copy(&self) -> RcBlock<F> {
         let ptr: *const Self = self;
         let ptr: *mut Block<F> = ptr as *mut _;
+        if self.header().flags.has(BlockFlags::BLOCK_IS_GLOBAL) {
+            // Global blocks are not reference-counted, `_Block_copy` would
+            // return the same pointer, and `_Block_release` is a no-op, so we
+            // can avoid the call into the runtime.
+            //
+            // SAFETY: The pointer is valid, and the block lives forever.
+            return unsafe { RcBlock::from_raw(ptr) }.unwrap_or_else(|| block_copy_fail());
+        }
         // SAFETY: The lifetime of the block is extended from `&self` to that
         // of the `RcBlock`, which is fine, because the lifetime of the
         // contained closure `F` is still carried along to the `RcBlock`.
         unsafe { RcBlock::copy(ptr) }.unwrap_or_else(|| block_copy_fail())
     }
 
+    /// The Objective-C type encoding of the block's signature, if any.
+    ///
+    /// This is stored in the block's descriptor, and is only present if the
+    /// block was created with an encoding, e.g. with
+    /// [`RcBlock::with_encoding`], or by a compiler that emits it.
+    ///
+    /// Blocks using Apple's compact descriptor layout (marked with the
+    /// private `BLOCK_SMALL_DESCRIPTOR` flag) are not supported, and always
+    /// return `None`.
+    ///
+    /// [`RcBlock::with_encoding`]: crate::RcBlock::with_encoding
+    #[doc(alias = "signature")]
+    #[doc(alias = "_Block_signature")]
+    #[inline]
+    pub fn encoding(&self) -> Option<&CStr> {
+        // SAFETY: The block's header and descriptor are valid.
+        unsafe { self.header().encoding() }
+    }
+
     /// Call the block.
     ///
     /// The arguments must be passed as a tuple. The return is the output of
     /// the block.
+    ///
+    /// Any return type that implements [`EncodeReturn`] is supported,
+    /// including structs that are too large to be returned in registers.
+    /// The block's invoke function is called with the exact signature of the
+    /// block, so the compiler takes care of passing such values through a
+    /// hidden pointer as the platform's C ABI requires (what Clang marks on
+    /// the block with `BLOCK_USE_STRET`).
+    ///
+    /// [`EncodeReturn`]: objc2::encode::EncodeReturn
     #[doc(alias = "invoke")]
     pub fn call(&self, args: F::Args) -> F::Output
     where
@@ -131,6 +183,7 @@ mod tests {
     use core::sync::atomic::{AtomicUsize, Ordering};
 
     use super::*;
+    use crate::{ManualBlockEncoding, StackBlock};
 
     /// Test that the way you specify lifetimes are as documented in the
     /// reference.
@@ -181,6 +234,129 @@ mod tests {
         assert_eq!(captured.get(), 1);
     }
 
+    #[test]
+    fn copy_identity() {
+        crate::global_block! {
+            static GLOBAL = || {};
+        }
+        let global_ptr: *const Block<dyn Fn()> = &*GLOBAL;
+        let copy = GLOBAL.copy();
+        assert_eq!(RcBlock::as_ptr(&copy).cast_const(), global_ptr);
+        // Copying again, and dropping the copies, is fine too.
+        assert_eq!(RcBlock::as_ptr(&copy.copy()).cast_const(), global_ptr);
+        drop(copy);
+        GLOBAL.call(());
+
+        let stack_block = StackBlock::new(|| {});
+        let stack_ptr: *const Block<dyn Fn()> = &*stack_block;
+        let heap_block = stack_block.copy();
+        assert_ne!(RcBlock::as_ptr(&heap_block).cast_const(), stack_ptr);
+
+        // Copying a heap block returns the same block.
+        let heap_copy = heap_block.copy();
+        assert!(RcBlock::ptr_eq(&heap_block, &heap_copy));
+    }
+
+    #[test]
+    fn encoding() {
+        struct Enc;
+        unsafe impl ManualBlockEncoding for Enc {
+            type Arguments = (i32, i32);
+            type Return = i32;
+            #[cfg(target_pointer_width = "64")]
+            const ENCODING_CSTR: &'static CStr =
+                unsafe { CStr::from_bytes_with_nul_unchecked(b"i16@?0i8i12\0") };
+            #[cfg(not(target_pointer_width = "64"))]
+            const ENCODING_CSTR: &'static CStr =
+                unsafe { CStr::from_bytes_with_nul_unchecked(b"i12@?0i4i8\0") };
+        }
+
+        let stack_block = StackBlock::with_encoding::<Enc>(|a, b| a + b);
+        assert_eq!(stack_block.encoding(), Some(Enc::ENCODING_CSTR));
+        // Preserved when copied to the heap.
+        assert_eq!(stack_block.copy().encoding(), Some(Enc::ENCODING_CSTR));
+
+        let rc_block = RcBlock::with_encoding::<_, _, _, Enc>(|a: i32, b: i32| a + b);
+        assert_eq!(rc_block.encoding(), Some(Enc::ENCODING_CSTR));
+        assert_eq!(rc_block.call((1, 2)), 3);
+
+        // Blocks without an encoding.
+        assert_eq!(RcBlock::new(|a: i32, b: i32| a + b).encoding(), None);
+        assert_eq!(StackBlock::new(|| {}).encoding(), None);
+        // `global_block!` doesn't emit an encoding yet.
+        crate::global_block! {
+            static GLOBAL = || {};
+        }
+        assert_eq!(GLOBAL.encoding(), None);
+    }
+
+    #[test]
+    fn large_struct_return() {
+        use objc2::encode::Encode;
+
+        // 40 bytes, which is larger than what can be returned in registers
+        // on both x86_64 and aarch64.
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Big {
+            a: u64,
+            b: f64,
+            c: u32,
+            d: i64,
+            e: u8,
+        }
+
+        unsafe impl Encode for Big {
+            const ENCODING: Encoding = Encoding::Struct(
+                "Big",
+                &[
+                    u64::ENCODING,
+                    f64::ENCODING,
+                    u32::ENCODING,
+                    i64::ENCODING,
+                    u8::ENCODING,
+                ],
+            );
+        }
+
+        fn big(x: u8) -> Big {
+            Big {
+                a: u64::MAX - x as u64,
+                b: 1.5 * x as f64,
+                c: 0xdead_beef,
+                d: -(x as i64),
+                e: x,
+            }
+        }
+
+        fn call(block: &Block<dyn Fn(u8, Big) -> Big>, x: u8) -> Big {
+            block.call((x, big(x)))
+        }
+
+        let stack_block = StackBlock::new(|x: u8, big: Big| {
+            assert_eq!(big.e, x);
+            Big { e: x + 1, ..big }
+        });
+        assert_eq!(call(&stack_block, 3), Big { e: 4, ..big(3) });
+
+        let rc_block = stack_block.copy();
+        assert_eq!(call(&rc_block, 42), Big { e: 43, ..big(42) });
+
+        crate::global_block! {
+            static GLOBAL = |x: u8, big: Big| -> Big {
+                Big { a: big.a - 1, d: big.d - 1, e: x, ..big }
+            };
+        }
+        assert_eq!(
+            call(&GLOBAL, 7),
+            Big {
+                a: u64::MAX - 8,
+                d: -8,
+                ..big(7)
+            }
+        );
+    }
+
     #[allow(dead_code)]
     fn unspecified_in_fn_is_static(block: &Block<dyn Fn()>) -> &Block<dyn Fn() + 'static> {
         block
@@ -0,0 +1,346 @@
+use core::cell::UnsafeCell;
+use core::ffi::c_void;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::{self, ManuallyDrop};
+use core::ptr::{self, NonNull};
+
+use crate::abi::{BlockByRefHeader, BlockFlags, BLOCK_FIELD_IS_BYREF};
+use crate::ffi;
+
+/// The alignment that `malloc` guarantees on all supported platforms.
+///
+/// The runtime allocates `__block` variables with `malloc`, so we cannot
+/// support types with a greater alignment than this.
+const MALLOC_ALIGN: usize = 2 * mem::size_of::<usize>();
+
+#[repr(C)]
+struct ByRefInner<T> {
+    header: BlockByRefHeader,
+    value: UnsafeCell<T>,
+}
+
+/// A variable that can be shared mutably between Rust code and blocks.
+///
+/// This is the equivalent of a `__block` marked variable in C, and uses the
+/// same memory management scheme, i.e. `_Block_object_assign` and
+/// `_Block_object_dispose`. Cloning a `ByRef` creates a new reference to the
+/// same variable, so changes made through one clone (e.g. from within a
+/// block) are visible through all the others.
+///
+/// Access to the variable works similarly to [`Cell`], since it may be
+/// shared.
+///
+/// [`Cell`]: core::cell::Cell
+///
+///
+/// # Heap allocation
+///
+/// In C, `__block` variables start out on the stack, and are moved to the
+/// heap when a block that captures them is copied. This is not possible in
+/// Rust, since values may be moved around freely, so instead the variable is
+/// moved to the heap when the `ByRef` is created.
+///
+///
+/// # Example
+///
+/// ```
+/// use block2::{ByRef, RcBlock};
+///
+/// let counter = ByRef::new(0);
+/// let block = RcBlock::new({
+///     let counter = counter.clone();
+///     move || counter.set(counter.get() + 1)
+/// });
+///
+/// block.call(());
+/// counter.set(counter.get() + 10);
+/// block.call(());
+/// assert_eq!(counter.get(), 12);
+/// ```
+#[doc(alias = "__block")]
+pub struct ByRef<T> {
+    /// A pointer to the heap-allocated variable.
+    ptr: NonNull<ByRefInner<T>>,
+    /// We own the value, for the purposes of drop check.
+    p: PhantomData<ByRefInner<T>>,
+}
+
+impl<T> ByRef<T> {
+    unsafe extern "C-unwind" fn keep(dst: *mut c_void, src: *mut c_void) {
+        let dst: *mut ByRefInner<T> = dst.cast();
+        let src: *mut ByRefInner<T> = src.cast();
+        // SAFETY: The runtime calls this with the variable on the stack as
+        // `src`, and the newly allocated variable as `dst`. The value is
+        // moved, since the variable on the stack is never dropped.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                ptr::addr_of!((*src).value),
+                ptr::addr_of_mut!((*dst).value),
+                1,
+            );
+        }
+    }
+
+    unsafe extern "C-unwind" fn destroy(byref: *mut c_void) {
+        let byref: *mut ByRefInner<T> = byref.cast();
+        // SAFETY: The runtime calls this once, when the last reference to
+        // the variable is disposed.
+        unsafe { ptr::drop_in_place(ptr::addr_of_mut!((*byref).value)) };
+    }
+
+    /// Create a new shared variable containing the given value.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is over-aligned, i.e. has a greater alignment than
+    /// what `malloc` guarantees, or if the runtime fails to allocate it.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        assert!(
+            mem::align_of::<ByRefInner<T>>() <= MALLOC_ALIGN,
+            "over-aligned types are not supported in ByRef"
+        );
+        let size = mem::size_of::<ByRefInner<T>>()
+            .try_into()
+            .expect("value too large for ByRef");
+
+        // Construct the variable on the stack, similar to what the compiler
+        // would do. It is never dropped, the value is moved out in `keep`.
+        let mut stack = ManuallyDrop::new(ByRefInner {
+            header: BlockByRefHeader {
+                isa: ptr::null(),
+                forwarding: ptr::null_mut(),
+                // Reference count of zero, which marks it as being on the
+                // stack.
+                flags: BlockFlags::BLOCK_HAS_COPY_DISPOSE,
+                size,
+                keep: Some(Self::keep),
+                destroy: Some(Self::destroy),
+            },
+            value: UnsafeCell::new(value),
+        });
+        let stack: *mut ByRefInner<T> = &mut *stack;
+        // SAFETY: The pointer is valid.
+        unsafe { (*stack).header.forwarding = stack.cast() };
+
+        // Move the variable to the heap.
+        let mut heap: *mut c_void = ptr::null_mut();
+        // SAFETY: The variable is a valid `__block` variable on the stack,
+        // and the destination is valid for writes.
+        unsafe {
+            ffi::_Block_object_assign(
+                ptr::addr_of_mut!(heap).cast(),
+                stack.cast(),
+                BLOCK_FIELD_IS_BYREF,
+            );
+        };
+        let ptr = NonNull::new(heap.cast()).expect("failed allocating ByRef");
+        // The heap variable starts out with a reference count of two; one
+        // for us, and one for the variable on the stack. Release the latter,
+        // since the stack variable is going out of scope.
+        //
+        // SAFETY: The stack variable's forwarding pointer now points to the
+        // heap variable, which is still retained by us, so this doesn't
+        // destroy the value.
+        unsafe { ffi::_Block_object_dispose(stack.cast(), BLOCK_FIELD_IS_BYREF) };
+        Self {
+            ptr,
+            p: PhantomData,
+        }
+    }
+
+    /// A pointer to the contained value.
+    ///
+    /// The pointer stays valid for as long as any clone of this `ByRef` (or
+    /// a block that has captured one) is alive.
+    #[inline]
+    pub fn as_ptr(&self) -> *mut T {
+        // SAFETY: The variable is valid. It is already on the heap, so the
+        // forwarding pointer points to itself, but we go through it anyhow
+        // to match the ABI.
+        unsafe {
+            let inner: *mut ByRefInner<T> = (*self.ptr.as_ptr()).header.forwarding.cast();
+            (*inner).value.get()
+        }
+    }
+
+    /// Get a copy of the contained value.
+    #[inline]
+    pub fn get(&self) -> T
+    where
+        T: Copy,
+    {
+        // SAFETY: The type is not `Sync`, and no references to the value are
+        // handed out, so the value is not being mutated concurrently.
+        unsafe { *self.as_ptr() }
+    }
+
+    /// Set the contained value.
+    #[inline]
+    pub fn set(&self, value: T) {
+        drop(self.replace(value));
+    }
+
+    /// Replace the contained value, and return the old value.
+    #[inline]
+    pub fn replace(&self, value: T) -> T {
+        // SAFETY: See `get`.
+        mem::replace(unsafe { &mut *self.as_ptr() }, value)
+    }
+
+    /// Take the contained value, leaving [`Default::default`] in its place.
+    #[inline]
+    pub fn take(&self) -> T
+    where
+        T: Default,
+    {
+        self.replace(T::default())
+    }
+}
+
+impl<T> Clone for ByRef<T> {
+    /// Create a new reference to the same variable.
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut ptr: *mut c_void = ptr::null_mut();
+        // SAFETY: The variable is a valid `__block` variable on the heap, so
+        // this just increments the reference count.
+        unsafe {
+            ffi::_Block_object_assign(
+                ptr::addr_of_mut!(ptr).cast(),
+                self.ptr.as_ptr().cast(),
+                BLOCK_FIELD_IS_BYREF,
+            );
+        };
+        debug_assert_eq!(ptr, self.ptr.as_ptr().cast());
+        Self {
+            ptr: self.ptr,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for ByRef<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The variable is valid, and we own a reference to it.
+        unsafe { ffi::_Block_object_dispose(self.ptr.as_ptr().cast(), BLOCK_FIELD_IS_BYREF) };
+    }
+}
+
+impl<T: Default> Default for ByRef<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for ByRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByRef").field("value", &self.get()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    use super::*;
+    use crate::{RcBlock, StackBlock};
+
+    #[test]
+    fn shared_with_block() {
+        let counter = ByRef::new(0);
+        let block = RcBlock::new({
+            let counter = counter.clone();
+            move || counter.set(counter.get() + 1)
+        });
+
+        block.call(());
+        counter.set(counter.get() + 1);
+        block.call(());
+        assert_eq!(counter.get(), 3);
+
+        drop(block);
+        counter.set(counter.get() + 1);
+        assert_eq!(counter.get(), 4);
+    }
+
+    #[test]
+    fn shared_between_blocks() {
+        let counter = ByRef::new(0i32);
+        let increment = RcBlock::new({
+            let counter = counter.clone();
+            move || counter.set(counter.get() + 1)
+        });
+        let add = RcBlock::new({
+            let counter = counter.clone();
+            move |n: i32| {
+                counter.set(counter.get() + n);
+                counter.get()
+            }
+        });
+
+        increment.call(());
+        assert_eq!(add.call((10,)), 11);
+        increment.call(());
+        assert_eq!(counter.get(), 12);
+
+        // The variable outlives both blocks.
+        drop(increment);
+        drop(add);
+        counter.set(counter.get() + 1);
+        assert_eq!(counter.get(), 13);
+    }
+
+    #[test]
+    fn shared_with_copied_stack_block() {
+        let counter = ByRef::new(0);
+        let stack_block = StackBlock::new({
+            let counter = counter.clone();
+            move || counter.set(counter.get() + 1)
+        });
+        let heap_block = stack_block.copy();
+
+        stack_block.call(());
+        heap_block.call(());
+        counter.set(counter.get() + 1);
+        assert_eq!(counter.get(), 3);
+    }
+
+    #[test]
+    fn dropped_once() {
+        struct Tracked(Rc<Cell<usize>>);
+
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let var = ByRef::new(Tracked(drops.clone()));
+        let clone = var.clone();
+        assert_eq!(drops.get(), 0);
+
+        var.set(Tracked(drops.clone()));
+        assert_eq!(drops.get(), 1);
+
+        drop(var);
+        assert_eq!(drops.get(), 1);
+        drop(clone);
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn take() {
+        let var = ByRef::new(5);
+        assert_eq!(var.take(), 5);
+        assert_eq!(var.get(), 0);
+        assert_eq!(format!("{var:?}"), "ByRef { value: 0 }");
+    }
+}
@@ -0,0 +1,150 @@
+use alloc::sync::Arc;
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::RcBlock;
+
+/// A token for cooperatively cancelling work done in a block.
+///
+/// The token can be cloned and sent to other threads, and all clones share
+/// the same cancellation state; so one clone can be given to the block with
+/// [`RcBlock::new_cancellable`], while another is kept around by the caller
+/// to [`cancel`][Self::cancel] the work.
+///
+/// Cancellation is cooperative: the closure must itself check
+/// [`is_cancelled`][Self::is_cancelled] and return early.
+///
+///
+/// # Example
+///
+/// ```
+/// use block2::{CancelToken, RcBlock};
+///
+/// let token = CancelToken::new();
+/// let block = RcBlock::new_cancellable(token.clone(), |token| {
+///     for _ in 0..1000 {
+///         if token.is_cancelled() {
+///             return;
+///         }
+///         // Do some work...
+///     }
+/// });
+///
+/// token.cancel();
+/// // The closure is not called when already cancelled.
+/// block.call(());
+/// ```
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Create a new token that has not yet been cancelled.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request that the work associated with this token is cancelled.
+    ///
+    /// This can be called from any thread, and calling it multiple times is
+    /// fine.
+    #[inline]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Whether [`cancel`][Self::cancel] has been called on this token or any
+    /// of its clones.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
+
+impl fmt::Debug for CancelToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancelToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl<'f> RcBlock<dyn Fn() + 'f> {
+    /// Construct a `RcBlock` that runs the given closure, unless the token
+    /// has been cancelled.
+    ///
+    /// The closure is given the token, so that it can check
+    /// [`CancelToken::is_cancelled`] during long-running work, and return
+    /// early if it has been cancelled. If the token is cancelled before the
+    /// block is called, the closure is not called at all.
+    ///
+    /// This is useful for e.g. work items submitted to Grand Central
+    /// Dispatch.
+    pub fn new_cancellable<Closure>(token: CancelToken, closure: Closure) -> Self
+    where
+        Closure: Fn(&CancelToken) + 'f,
+    {
+        Self::new(move || {
+            if !token.is_cancelled() {
+                closure(&token);
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn cancel_mid_work() {
+        use std::thread;
+
+        let token = CancelToken::new();
+        let handle = token.clone();
+        let steps = Rc::new(Cell::new(0));
+
+        let block = RcBlock::new_cancellable(token, {
+            let steps = steps.clone();
+            move |token| {
+                for i in 0..100 {
+                    if token.is_cancelled() {
+                        return;
+                    }
+                    if i == 10 {
+                        // Cancel from another thread.
+                        let handle = handle.clone();
+                        thread::spawn(move || handle.cancel()).join().unwrap();
+                    }
+                    steps.set(steps.get() + 1);
+                }
+            }
+        });
+
+        block.call(());
+        assert_eq!(steps.get(), 11);
+
+        // Already cancelled, so the closure isn't called again.
+        block.call(());
+        assert_eq!(steps.get(), 11);
+    }
+
+    #[test]
+    fn not_cancelled() {
+        let token = CancelToken::new();
+        let called = Rc::new(Cell::new(false));
+        let block = RcBlock::new_cancellable(token.clone(), {
+            let called = called.clone();
+            move |_| called.set(true)
+        });
+        block.call(());
+        assert!(called.get());
+        assert!(!token.is_cancelled());
+    }
+}
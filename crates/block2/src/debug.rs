@@ -114,7 +114,31 @@ impl Debug for BlockDescriptorHelper {
 
 #[cfg(test)]
 mod tests {
+    use alloc::format;
+    use alloc::string::{String, ToString};
+
     use super::*;
+    use crate::StackBlock;
+
+    #[test]
+    fn flags() {
+        fn flags(f: &dyn Debug) -> String {
+            let s = format!("{f:?}");
+            let start = s.find("flags: ").unwrap() + "flags: ".len();
+            let end = s[start..].find(", reserved").unwrap();
+            s[start..start + end].to_string()
+        }
+
+        let stack_block = StackBlock::new(|| {});
+        assert_eq!(flags(&stack_block), "BLOCK_HAS_COPY_DISPOSE");
+        assert!(format!("{stack_block:?}").contains("isa: _NSConcreteStackBlock"));
+
+        crate::global_block! {
+            static GLOBAL_BLOCK = || {};
+        }
+        assert_eq!(flags(&GLOBAL_BLOCK), "BLOCK_IS_GLOBAL | BLOCK_USE_STRET");
+        assert!(format!("{:?}", &*GLOBAL_BLOCK).contains("isa: _NSConcreteGlobalBlock"));
+    }
 
     #[test]
     #[allow(unused_unsafe)]
@@ -132,6 +132,33 @@ impl<F: ?Sized> fmt::Debug for GlobalBlock<F> {
 /// assert_eq!(ADDER_BLOCK.call((5, 7)), 12);
 /// ```
 ///
+/// Since the block is stored in a `static`, it cannot capture any state.
+/// Referring to constants and other statics works however, since they are
+/// not captured:
+///
+/// ```
+/// use block2::global_block;
+/// const FACTOR: i32 = 3;
+/// global_block! {
+///     static MUL = |x: i32| -> i32 {
+///         x * FACTOR
+///     };
+/// }
+/// assert_eq!(MUL.call((5,)), 15);
+/// ```
+///
+/// While referring to a local variable fails to compile:
+///
+/// ```compile_fail,E0435
+/// use block2::global_block;
+/// let factor = 3;
+/// global_block! {
+///     static MUL = |x: i32| -> i32 {
+///         x * factor
+///     };
+/// }
+/// ```
+///
 /// The following does not compile because [`Box`] is not [`EncodeReturn`]:
 ///
 /// ```compile_fail,E0277
@@ -231,6 +258,16 @@ mod tests {
         NOOP_BLOCK.call(());
     }
 
+    #[test]
+    fn test_refers_to_const() {
+        const FACTOR: i32 = 3;
+        static OFFSET: i32 = 1;
+        global_block!(static MUL = |x: i32| -> i32 {
+            x * FACTOR + OFFSET
+        });
+        assert_eq!(MUL.call((5,)), 16);
+    }
+
     #[test]
     fn test_defined_in_function() {
         global_block!(static MY_BLOCK = || -> i32 {
@@ -239,39 +276,6 @@ mod tests {
         assert_eq!(MY_BLOCK.call(()), 42);
     }
 
-    #[cfg(target_vendor = "apple")]
-    const DEBUG_BLOCKFLAGS: &str = r#"BlockFlags {
-        value: "00110000000000000000000000000000",
-        deallocating: false,
-        inline_layout_string: false,
-        small_descriptor: false,
-        is_noescape: false,
-        needs_free: false,
-        has_copy_dispose: false,
-        has_ctor: false,
-        is_gc: false,
-        is_global: true,
-        use_stret: true,
-        has_signature: false,
-        has_extended_layout: false,
-        over_referenced: false,
-        reference_count: 0,
-        ..
-    }"#;
-
-    #[cfg(not(target_vendor = "apple"))]
-    const DEBUG_BLOCKFLAGS: &str = r#"BlockFlags {
-        value: "00110000000000000000000000000000",
-        has_copy_dispose: false,
-        has_ctor: false,
-        is_global: true,
-        use_stret: true,
-        has_signature: false,
-        over_referenced: false,
-        reference_count: 0,
-        ..
-    }"#;
-
     #[test]
     fn test_debug() {
         let invoke = NOOP_BLOCK.header.invoke.unwrap();
@@ -280,7 +284,7 @@ mod tests {
         let expected = format!(
             "GlobalBlock {{
     isa: _NSConcreteGlobalBlock,
-    flags: {DEBUG_BLOCKFLAGS},
+    flags: BLOCK_IS_GLOBAL | BLOCK_USE_STRET,
     reserved: {maybeuninit:?},
     invoke: Some(
         {invoke:#?},
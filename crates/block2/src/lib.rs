@@ -423,16 +423,24 @@ extern crate objc2 as _;
 
 mod abi;
 mod block;
+mod byref;
+mod cancel;
 mod debug;
 mod encoding;
 pub mod ffi;
 mod global;
+#[cfg(feature = "std")]
+mod oneshot;
 mod rc_block;
 mod stack;
 mod traits;
 
 pub use self::block::Block;
+pub use self::byref::ByRef;
+pub use self::cancel::CancelToken;
 pub use self::global::GlobalBlock;
+#[cfg(feature = "std")]
+pub use self::oneshot::{oneshot_block, BlockDropped, Oneshot};
 pub use self::rc_block::RcBlock;
 pub use self::stack::StackBlock;
 pub use self::traits::{BlockFn, IntoBlock, ManualBlockEncoding};
@@ -441,15 +449,6 @@ pub use self::traits::{BlockFn, IntoBlock, ManualBlockEncoding};
 #[deprecated = "renamed to `StackBlock`"]
 pub type ConcreteBlock<A, R, Closure> = StackBlock<'static, A, R, Closure>;
 
-// Note: We could use `_Block_object_assign` and `_Block_object_dispose` to
-// implement a `ByRef<T>` wrapper, which would behave like `__block` marked
-// variables and have roughly the same memory management scheme as blocks.
-//
-// But I've yet to see the value in such a wrapper in Rust code compared to
-// just using `Box`, `Rc` or `Arc`, and since `__block` variables are
-// basically never exposed as part of a (public) function's API, we won't
-// implement such a thing yet.
-
 /// Helper type to allow changing [`Block`] in the future without affecting
 /// framework crates.
 ///
@@ -0,0 +1,243 @@
+use alloc::sync::Arc;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::Mutex;
+
+use objc2::encode::EncodeArgument;
+
+use crate::RcBlock;
+
+struct State<T> {
+    value: Option<T>,
+    /// Whether the sending side has been dropped.
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+struct Sender<T> {
+    state: Arc<Mutex<State<T>>>,
+}
+
+impl<T> Sender<T> {
+    fn send(self, value: T) {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        state.value = Some(value);
+        // Wakes the receiver in `Drop`.
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        state.closed = true;
+        if let Some(waker) = state.waker.take() {
+            drop(state);
+            waker.wake();
+        }
+    }
+}
+
+/// Create a block that fulfills a future when it is called.
+///
+/// This is useful for bridging Objective-C completion handlers, i.e. blocks
+/// that are called exactly once when some work has finished, to Rust's
+/// `async`.
+///
+/// The given closure is called with the argument to the block, and its
+/// return value is what the returned [`Oneshot`] future resolves to.
+///
+///
+/// # Multiple invocations
+///
+/// Only the first invocation of the block is used; if the block is called
+/// again, the argument is dropped and the closure is not run.
+///
+///
+/// # Dropping the block
+///
+/// If every reference to the block is released without it ever having been
+/// called, the future resolves to an error [`BlockDropped`], instead of
+/// never completing.
+///
+///
+/// # Thread safety
+///
+/// Completion handlers are commonly called on a different thread than the
+/// one that created them, so the closure, its argument and its output are
+/// required to be [`Send`]. The internal state is protected by a mutex, so
+/// it is fine if an Objective-C API calls the block on any thread.
+///
+/// The returned future is [`Send`], but note that the returned block is
+/// not, since `block2` does not yet support `Send` blocks. This only
+/// restricts moving the [`RcBlock`] itself across threads in Rust; it can
+/// still be passed to Objective-C APIs that call it on another thread.
+///
+///
+/// # Example
+///
+/// ```
+/// use block2::{oneshot_block, Block};
+///
+/// // Some API that calls the completion handler when it is done.
+/// fn do_work(completion_handler: &Block<dyn Fn(i32)>) {
+///     completion_handler.call((42,));
+/// }
+///
+/// async fn do_work_async() -> i32 {
+///     let (block, future) = oneshot_block(|x: i32| x);
+///     do_work(&block);
+///     future.await.expect("completion handler was not called")
+/// }
+/// # let _ = do_work_async();
+/// ```
+pub fn oneshot_block<A, T, F>(f: F) -> (RcBlock<dyn Fn(A)>, Oneshot<T>)
+where
+    A: EncodeArgument + Send,
+    F: FnOnce(A) -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let state = Arc::new(Mutex::new(State {
+        value: None,
+        closed: false,
+        waker: None,
+    }));
+    let sender = Sender {
+        state: state.clone(),
+    };
+
+    let inner = Mutex::new(Some((f, sender)));
+    let block = RcBlock::new(move |arg: A| {
+        // Release the lock before running the closure, in case it ends up
+        // calling the block again.
+        let inner = inner.lock().unwrap_or_else(|err| err.into_inner()).take();
+        if let Some((f, sender)) = inner {
+            sender.send(f(arg));
+        }
+    });
+
+    (block, Oneshot { state })
+}
+
+/// The future returned from [`oneshot_block`].
+///
+/// Resolves when the associated block is called, or when it is dropped
+/// without having been called.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Oneshot<T> {
+    state: Arc<Mutex<State<T>>>,
+}
+
+impl<T> Future for Oneshot<T> {
+    type Output = Result<T, BlockDropped>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(value) = state.value.take() {
+            Poll::Ready(Ok(value))
+        } else if state.closed {
+            Poll::Ready(Err(BlockDropped))
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> fmt::Debug for Oneshot<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Oneshot").finish_non_exhaustive()
+    }
+}
+
+/// The error returned from [`Oneshot`] when the block was dropped without
+/// being called.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BlockDropped;
+
+impl fmt::Display for BlockDropped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("block was dropped without being called")
+    }
+}
+
+impl std::error::Error for BlockDropped {}
+
+#[cfg(test)]
+mod tests {
+    use core::pin::pin;
+    use std::task::Wake;
+
+    use super::*;
+
+    struct CountWakes(Mutex<usize>);
+
+    impl Wake for CountWakes {
+        fn wake(self: Arc<Self>) {
+            *self.0.lock().unwrap() += 1;
+        }
+    }
+
+    fn poll<F: Future>(future: Pin<&mut F>, wakes: &Arc<CountWakes>) -> Poll<F::Output> {
+        let waker = Waker::from(wakes.clone());
+        future.poll(&mut Context::from_waker(&waker))
+    }
+
+    #[test]
+    fn called() {
+        let wakes = Arc::new(CountWakes(Mutex::new(0)));
+        let (block, future) = oneshot_block(|x: i32| x + 1);
+        let mut future = pin!(future);
+
+        assert_eq!(poll(future.as_mut(), &wakes), Poll::Pending);
+        block.call((1,));
+        assert_eq!(*wakes.0.lock().unwrap(), 1);
+        assert_eq!(poll(future.as_mut(), &wakes), Poll::Ready(Ok(2)));
+    }
+
+    #[test]
+    fn called_twice() {
+        let wakes = Arc::new(CountWakes(Mutex::new(0)));
+        let (block, future) = oneshot_block(|x: i32| x);
+        block.call((1,));
+        block.call((2,));
+        assert_eq!(poll(pin!(future), &wakes), Poll::Ready(Ok(1)));
+    }
+
+    #[test]
+    fn called_from_other_thread() {
+        let wakes = Arc::new(CountWakes(Mutex::new(0)));
+        let (block, future) = oneshot_block(|x: i32| x * 2);
+        let mut future = pin!(future);
+        assert_eq!(poll(future.as_mut(), &wakes), Poll::Pending);
+
+        // Simulate an Objective-C API calling the block on another thread.
+        struct AssertSend<T>(T);
+        // SAFETY: The closure and its argument are `Send`, and the state is
+        // protected by a mutex.
+        unsafe impl<T> Send for AssertSend<T> {}
+        let block = AssertSend(block);
+        std::thread::spawn(move || {
+            let block = block;
+            block.0.call((21,));
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(*wakes.0.lock().unwrap(), 1);
+        assert_eq!(poll(future, &wakes), Poll::Ready(Ok(42)));
+    }
+
+    #[test]
+    fn dropped() {
+        let wakes = Arc::new(CountWakes(Mutex::new(0)));
+        let (block, future) = oneshot_block(|x: i32| x);
+        let mut future = pin!(future);
+
+        assert_eq!(poll(future.as_mut(), &wakes), Poll::Pending);
+        drop(block);
+        assert_eq!(*wakes.0.lock().unwrap(), 1);
+        assert_eq!(poll(future, &wakes), Poll::Ready(Err(BlockDropped)));
+    }
+}
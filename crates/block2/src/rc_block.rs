@@ -1,13 +1,20 @@
+use core::ffi::{c_int, c_void};
 use core::fmt;
 use core::mem::ManuallyDrop;
 use core::ops::Deref;
-use core::ptr::NonNull;
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicI32, Ordering};
 
 use objc2::encode::{EncodeArguments, EncodeReturn};
+use objc2::rc::Weak;
+use objc2::Message;
 
 use crate::abi::BlockHeader;
 use crate::debug::debug_block_header;
-use crate::traits::{ManualBlockEncoding, ManualBlockEncodingExt, NoBlockEncoding, UserSpecified};
+use crate::traits::{
+    ManualBlockEncoding, ManualBlockEncodingExt, MutClosure, NoBlockEncoding, OnceClosure,
+    UserSpecified, WeakClosure,
+};
 use crate::{ffi, Block, IntoBlock, StackBlock};
 
 /// A reference-counted Objective-C block that is stored on the heap.
@@ -33,7 +40,7 @@ use crate::{ffi, Block, IntoBlock, StackBlock};
 #[doc(alias = "MallocBlock")]
 #[cfg_attr(
     feature = "unstable-coerce-pointee",
-    derive(std::marker::CoercePointee)
+    derive(core::marker::CoercePointee)
 )]
 pub struct RcBlock<F: ?Sized> {
     // Covariant
@@ -44,6 +51,10 @@ impl<F: ?Sized> RcBlock<F> {
     /// A raw pointer to the underlying block.
     ///
     /// The pointer is valid for at least as long as the `RcBlock` is alive.
+    /// This does not change the retain count of the block, so if you need
+    /// the pointer to outlive the `RcBlock` (such as when passing it to C
+    /// code that stores it), you must copy it with [`ffi::_Block_copy`] (or
+    /// use [`RcBlock::into_raw`] on a clone).
     ///
     /// This is an associated method, and must be called as
     /// `RcBlock::as_ptr(&block)`.
@@ -52,6 +63,91 @@ impl<F: ?Sized> RcBlock<F> {
         this.ptr.as_ptr()
     }
 
+    /// The raw flags of the underlying block.
+    ///
+    /// This is useful for interoperating with runtime functions that are not
+    /// wrapped by this crate. See the [ABI specification][ABI] for the
+    /// meaning of the individual bits; note that on some runtimes, this also
+    /// contains the reference count of the block, and may thus be modified
+    /// concurrently by other threads.
+    ///
+    /// This is an associated method, and must be called as
+    /// `RcBlock::flags(&block)`.
+    ///
+    /// [ABI]: https://clang.llvm.org/docs/Block-ABI-Apple.html
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use block2::RcBlock;
+    ///
+    /// const BLOCK_IS_GLOBAL: i32 = 1 << 28;
+    ///
+    /// let block = RcBlock::new(|| {});
+    /// assert_eq!(RcBlock::flags(&block) & BLOCK_IS_GLOBAL, 0);
+    /// ```
+    #[inline]
+    #[doc(alias = "Block_flags")]
+    pub fn flags(this: &Self) -> c_int {
+        let header: *const BlockHeader = this.ptr.as_ptr().cast();
+        // SAFETY: The block is alive, and so is its header. The runtime may
+        // update the flags atomically (e.g. in `_Block_copy`), so we must
+        // read them atomically as well; `BlockFlags` is a transparent
+        // `c_int`, which has the same layout as `AtomicI32`.
+        let flags = unsafe { &*ptr::addr_of!((*header).flags).cast::<AtomicI32>() };
+        flags.load(Ordering::Relaxed)
+    }
+
+    /// A pointer to the descriptor of the underlying block.
+    ///
+    /// The layout of the descriptor depends on the [flags][Self::flags];
+    /// see the [ABI specification][ABI] for details. It lives at least as
+    /// long as the block.
+    ///
+    /// This is an associated method, and must be called as
+    /// `RcBlock::descriptor(&block)`.
+    ///
+    /// [ABI]: https://clang.llvm.org/docs/Block-ABI-Apple.html
+    #[inline]
+    pub fn descriptor(this: &Self) -> *const c_void {
+        let header: *const BlockHeader = this.ptr.as_ptr().cast();
+        // SAFETY: The block is alive, and the descriptor is never modified
+        // after the block has been created. All the variants are pointers,
+        // so reading the basic variant is always valid.
+        unsafe { ptr::addr_of!((*header).descriptor).read().basic }.cast()
+    }
+
+    /// Returns `true` if the two `RcBlock`s point to the same block.
+    ///
+    /// This compares the identity of the blocks, not their closures, similar
+    /// to [`Rc::ptr_eq`]. Clones of a `RcBlock` (and [copies][Block::copy] of
+    /// a heap block) point to the same block, while separately constructed
+    /// blocks never do.
+    ///
+    /// This is an associated method, and must be called as
+    /// `RcBlock::ptr_eq(&a, &b)`.
+    ///
+    /// [`Rc::ptr_eq`]: alloc::rc::Rc::ptr_eq
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use block2::RcBlock;
+    ///
+    /// let block = RcBlock::new(|| {});
+    /// let same_block = block.clone();
+    /// let other_block = RcBlock::new(|| {});
+    ///
+    /// assert!(RcBlock::ptr_eq(&block, &same_block));
+    /// assert!(!RcBlock::ptr_eq(&block, &other_block));
+    /// ```
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.ptr == other.ptr
+    }
+
     /// Consumes the `RcBlock`, passing ownership of the retain count to the
     /// caller.
     ///
@@ -84,6 +180,13 @@ impl<F: ?Sized> RcBlock<F> {
     /// Construct an `RcBlock` from the given block pointer by taking
     /// ownership.
     ///
+    /// This does not copy the block, nor change its retain count. Instead,
+    /// it takes ownership of one retain count, which is then released with
+    /// [`ffi::_Block_release`] when the `RcBlock` is dropped. This is the
+    /// inverse of [`RcBlock::into_raw`], and is useful for wrapping block
+    /// pointers that are returned from C functions with +1 retain count, or
+    /// that you've copied yourself with [`ffi::_Block_copy`].
+    ///
     /// This will return `None` if the pointer is NULL.
     ///
     ///
@@ -93,6 +196,10 @@ impl<F: ?Sized> RcBlock<F> {
     /// return types must be correct, and the block must have a +1 reference /
     /// retain count from somewhere else.
     ///
+    /// In particular, the block must be a heap block, since stack blocks
+    /// cannot be retained; use [`RcBlock::copy`] if the block may live on the
+    /// stack.
+    ///
     /// Additionally, the block must be safe to call (or, if it is not, then
     /// you must treat every call to the block as `unsafe`).
     #[inline]
@@ -137,6 +244,25 @@ impl<F: ?Sized> RcBlock<F> {
     ///
     /// When the block is called, it will return the value that results from
     /// calling the closure.
+    ///
+    /// The closure is allowed to capture non-`'static` references; the
+    /// lifetime of those is tracked in `F`, which is e.g.
+    /// `dyn Fn() + 'f`. This means that a block capturing a reference to a
+    /// local variable can be passed to functions taking a non-escaping
+    /// `&Block<dyn Fn() + '_>`, but fails to compile when passed somewhere
+    /// that requires the block to be `'static`.
+    ///
+    /// ```compile_fail,E0597
+    /// use block2::{Block, RcBlock};
+    ///
+    /// fn escaping(block: &Block<dyn Fn() + 'static>) {
+    ///     // ...
+    /// }
+    ///
+    /// let x = 5;
+    /// let r = &x;
+    /// escaping(&RcBlock::new(move || println!("{r}")));
+    /// ```
     // Note: Unsure if this should be #[inline], but I think it may be able to
     // benefit from not being completely so.
     #[inline]
@@ -149,6 +275,133 @@ impl<F: ?Sized> RcBlock<F> {
         Self::maybe_encoded::<_, _, _, NoBlockEncoding<A, R>>(closure)
     }
 
+    /// Construct a `RcBlock` with the given [`FnMut`] closure.
+    ///
+    /// This allows the closure to mutate its captured state without having
+    /// to wrap it in a [`Cell`] or [`RefCell`].
+    ///
+    /// [`Cell`]: core::cell::Cell
+    /// [`RefCell`]: core::cell::RefCell
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The block must not be called re-entrantly, i.e. it must not be called
+    /// again (or [copied][Block::copy], if the closure is [`Clone`]) while
+    /// it is already being called. This includes recursively from within the
+    /// closure, as well as concurrently from another thread.
+    ///
+    /// This is usually upheld if you pass the block to a function that calls
+    /// it synchronously, or that calls it at most once (e.g. a completion
+    /// handler). Be careful with functions that store the block, and may call
+    /// it again from within itself.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use block2::RcBlock;
+    ///
+    /// let mut count = 0;
+    /// // SAFETY: The block is not called re-entrantly.
+    /// let block = unsafe {
+    ///     RcBlock::with_mut(|| {
+    ///         count += 1;
+    ///     })
+    /// };
+    /// block.call(());
+    /// block.call(());
+    /// drop(block);
+    /// assert_eq!(count, 2);
+    /// ```
+    #[inline]
+    pub unsafe fn with_mut<'f, A, R, Closure>(closure: Closure) -> Self
+    where
+        A: EncodeArguments,
+        R: EncodeReturn,
+        MutClosure<Closure>: IntoBlock<'f, A, R, Dyn = F>,
+    {
+        Self::new(MutClosure::new(closure))
+    }
+
+    /// Construct a `RcBlock` with the given [`FnOnce`] closure.
+    ///
+    /// This is useful for completion handlers, which are generally only
+    /// called once. The closure is moved out of the block and called on the
+    /// first invocation.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// The returned block panics if it is called more than once.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use block2::RcBlock;
+    ///
+    /// let data = vec![1, 2, 3];
+    /// let block = RcBlock::once(move |x: i32| {
+    ///     // `data` is moved out of the closure.
+    ///     let mut data = data;
+    ///     data.push(x);
+    ///     data.len()
+    /// });
+    /// assert_eq!(block.call((4,)), 4);
+    /// ```
+    #[inline]
+    pub fn once<'f, A, R, Closure>(closure: Closure) -> Self
+    where
+        A: EncodeArguments,
+        R: EncodeReturn,
+        OnceClosure<Closure>: IntoBlock<'f, A, R, Dyn = F>,
+    {
+        Self::new(OnceClosure::new(closure))
+    }
+
+    /// Construct a `RcBlock` that only calls the closure while the given
+    /// object is alive.
+    ///
+    /// Blocks themselves cannot be weakly referenced, but it is common to
+    /// want a block to not keep an object alive, e.g. to avoid reference
+    /// cycles when the object itself holds on to the block.
+    ///
+    /// Each time the block is called, the weak reference is upgraded, and if
+    /// successful, the closure is called with a reference to the object
+    /// along with the block's arguments. If the object has been deallocated,
+    /// the closure is not called, and [`Default::default`] is returned
+    /// instead.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use block2::RcBlock;
+    /// use objc2::rc::Weak;
+    /// use objc2::runtime::NSObject;
+    ///
+    /// let obj = NSObject::new();
+    /// let block = RcBlock::new_weak(&Weak::from_retained(&obj), |obj: &NSObject, x: i32| {
+    ///     let _ = obj;
+    ///     x + 1
+    /// });
+    /// assert_eq!(block.call((1,)), 2);
+    ///
+    /// drop(obj);
+    /// assert_eq!(block.call((1,)), 0);
+    /// ```
+    #[inline]
+    pub fn new_weak<'f, T, A, R, Closure>(weak: &Weak<T>, closure: Closure) -> Self
+    where
+        T: Message,
+        A: EncodeArguments,
+        R: EncodeReturn,
+        WeakClosure<T, Closure>: IntoBlock<'f, A, R, Dyn = F>,
+    {
+        Self::new(WeakClosure::new(weak, closure))
+    }
+
     /// Constructs a new [`RcBlock`] with the given function and encoding
     /// information.
     ///
@@ -220,6 +473,13 @@ impl<F: ?Sized> RcBlock<F> {
 
 impl<F: ?Sized> Clone for RcBlock<F> {
     /// Increase the reference-count of the block.
+    ///
+    /// This does _not_ copy the underlying closure, the returned `RcBlock`
+    /// points to the same block (and thus the same closure) as the original,
+    /// similar to what `Rc::clone` does.
+    ///
+    /// If you need a separate instance of the closure, construct a new block
+    /// with [`RcBlock::new`] instead.
     #[doc(alias = "Block_copy")]
     #[doc(alias = "_Block_copy")]
     #[inline]
@@ -289,9 +549,120 @@ impl<F: ?Sized> fmt::Debug for RcBlock<F> {
 #[cfg(test)]
 mod tests {
     use alloc::rc::Rc;
-    use core::cell::OnceCell;
+    use core::cell::{Cell, OnceCell};
 
     use super::*;
+    use crate::abi::BlockFlags;
+
+    #[test]
+    fn with_mut() {
+        let mut count = 0;
+        let block = unsafe {
+            RcBlock::with_mut(|x: i32| {
+                count += x;
+                count
+            })
+        };
+        assert_eq!(block.call((1,)), 1);
+        assert_eq!(block.call((2,)), 3);
+        let copy = block.copy();
+        assert_eq!(copy.call((3,)), 6);
+        drop(copy);
+        drop(block);
+        assert_eq!(count, 6);
+    }
+
+    #[test]
+    fn new_weak() {
+        use objc2::rc::Weak;
+        use objc2::runtime::NSObject;
+
+        let obj = NSObject::new();
+        let weak = Weak::from_retained(&obj);
+        let calls = Cell::new(0);
+        let block = RcBlock::new_weak(&weak, |_obj: &NSObject, x: i32| {
+            calls.set(calls.get() + 1);
+            x * 2
+        });
+        assert_eq!(block.call((2,)), 4);
+        assert_eq!(calls.get(), 1);
+
+        // The block does not keep the object alive.
+        drop(obj);
+        assert!(weak.load().is_none());
+        assert_eq!(block.call((2,)), 0);
+        assert_eq!(calls.get(), 1);
+
+        // Blocks without parameters are supported too.
+        let obj = NSObject::new();
+        let block = RcBlock::new_weak(&Weak::from_retained(&obj), |_obj: &NSObject| {
+            calls.set(calls.get() + 1);
+        });
+        block.call(());
+        drop(obj);
+        block.call(());
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn flags_and_descriptor() {
+        // Capture something that needs to be dropped.
+        let captured = Rc::new(1);
+        let block = RcBlock::new(move |x: i32| x + *captured);
+        let flags = BlockFlags(RcBlock::flags(&block));
+        assert!(flags.has(BlockFlags::BLOCK_HAS_COPY_DISPOSE));
+        assert!(!flags.has(BlockFlags::BLOCK_HAS_SIGNATURE));
+        assert!(!flags.has(BlockFlags::BLOCK_IS_GLOBAL));
+        assert_eq!(flags, block.header().flags);
+        assert_eq!(
+            RcBlock::descriptor(&block),
+            unsafe { block.header().descriptor.basic }.cast()
+        );
+        assert!(!RcBlock::descriptor(&block).is_null());
+        assert_eq!(block.call((1,)), 2);
+    }
+
+    #[test]
+    fn ptr_eq() {
+        let block = RcBlock::new(|x: i32| x + 1);
+        let clone = block.clone();
+        assert!(RcBlock::ptr_eq(&block, &clone));
+        assert!(RcBlock::ptr_eq(&block, &block.copy()));
+
+        // Equal closures, but different blocks.
+        let other = RcBlock::new(|x: i32| x + 1);
+        assert!(!RcBlock::ptr_eq(&block, &other));
+        assert!(!RcBlock::ptr_eq(&clone, &other));
+    }
+
+    #[test]
+    fn once() {
+        let rc = Rc::new(());
+        let captured = rc.clone();
+        let block = RcBlock::once(move |x: i32| {
+            drop(captured);
+            x + 1
+        });
+        assert_eq!(Rc::strong_count(&rc), 2);
+        assert_eq!(block.call((1,)), 2);
+        // The closure was dropped after being called.
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn once_called_twice() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let block = RcBlock::once(|| 5);
+        assert_eq!(block.call(()), 5);
+
+        let err = catch_unwind(AssertUnwindSafe(|| block.call(()))).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<&str>(),
+            Some(&"block created with `RcBlock::once` was called more than once")
+        );
+    }
 
     #[test]
     fn return_rc_block() {
@@ -334,6 +705,65 @@ mod tests {
         let _ = return_entire(|| &5);
     }
 
+    #[test]
+    fn clone_shares_closure() {
+        let counter = Rc::new(Cell::new(0));
+        let captured_counter = counter.clone();
+        let block = RcBlock::new(move || {
+            captured_counter.set(captured_counter.get() + 1);
+            captured_counter.get()
+        });
+
+        let cloned = block.clone();
+        assert_eq!(RcBlock::as_ptr(&block), RcBlock::as_ptr(&cloned));
+
+        assert_eq!(block.call(()), 1);
+        assert_eq!(cloned.call(()), 2);
+        assert_eq!(block.call(()), 3);
+        assert_eq!(counter.get(), 3);
+
+        // The closure is only dropped once all clones are.
+        assert_eq!(Rc::strong_count(&counter), 2);
+        drop(block);
+        assert_eq!(Rc::strong_count(&counter), 2);
+        drop(cloned);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        let counter = Rc::new(());
+        let captured_counter = counter.clone();
+        let block = RcBlock::new(move || {
+            let _ = &captured_counter;
+        });
+        let ptr = RcBlock::as_ptr(&block);
+
+        // Copying a heap block retains it, and returns the same pointer.
+        let copied: *mut Block<dyn Fn()> = unsafe { ffi::_Block_copy(ptr.cast()) }.cast();
+        assert_eq!(copied, ptr);
+        // SAFETY: The block was copied above, so we own a retain count.
+        let other = unsafe { RcBlock::from_raw(copied) }.unwrap();
+        assert_eq!(RcBlock::as_ptr(&other), ptr);
+        other.call(());
+
+        // `from_raw` took over the retain count from `_Block_copy`, so the
+        // block is still alive after the original is dropped.
+        drop(block);
+        assert_eq!(Rc::strong_count(&counter), 2);
+
+        // Releasing the last retain count manually deallocates the block.
+        let ptr = RcBlock::into_raw(other);
+        unsafe { ffi::_Block_release(ptr.cast()) };
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn from_raw_null() {
+        let block = unsafe { RcBlock::<dyn Fn()>::from_raw(core::ptr::null_mut()) };
+        assert!(block.is_none());
+    }
+
     #[allow(dead_code)]
     fn covariant<'f>(b: RcBlock<dyn Fn() + 'static>) -> RcBlock<dyn Fn() + 'f> {
         b
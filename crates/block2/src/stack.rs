@@ -14,8 +14,10 @@ use crate::abi::{
     BlockDescriptorPtr, BlockDescriptorSignature, BlockFlags, BlockHeader,
 };
 use crate::debug::debug_block_header;
-use crate::traits::{ManualBlockEncoding, ManualBlockEncodingExt, NoBlockEncoding, UserSpecified};
-use crate::{ffi, Block, IntoBlock};
+use crate::traits::{
+    ManualBlockEncoding, ManualBlockEncodingExt, MutClosure, NoBlockEncoding, UserSpecified,
+};
+use crate::{ffi, Block, IntoBlock, RcBlock};
 
 /// An Objective-C block constructed on the stack.
 ///
@@ -78,6 +80,10 @@ where
     const ENCODING_REF: Encoding = Encoding::Block;
 }
 
+/// The byte that the closure is overwritten with after it has been disposed.
+#[cfg(debug_assertions)]
+const POISON: u8 = 0xAB;
+
 // Basic constants and helpers.
 impl<A, R, Closure> StackBlock<'_, A, R, Closure> {
     /// The size of the block header and the trailing closure.
@@ -107,6 +113,19 @@ impl<A, R, Closure> StackBlock<'_, A, R, Closure> {
         // part of some `_Block_copy` operation, and as such it is valid to
         // drop here.
         unsafe { ptr::drop_in_place(closure) };
+
+        // Overwrite the now dropped closure with a recognizable pattern, to
+        // make use-after-free of the captured state easier to spot.
+        //
+        // Global blocks are never disposed, so they are never poisoned.
+        //
+        // SAFETY: The block's memory is still valid until the runtime
+        // deallocates it after this function returns, and the closure has
+        // been dropped, so nothing will read it as a `Closure` again.
+        #[cfg(debug_assertions)]
+        unsafe {
+            ptr::write_bytes(closure.cast::<u8>(), POISON, mem::size_of::<Closure>())
+        };
     }
 
     const DESCRIPTOR_BASIC: BlockDescriptor = BlockDescriptor {
@@ -187,6 +206,33 @@ where
         Self::maybe_encoded::<NoBlockEncoding<A, R>>(closure)
     }
 
+    /// Copy the block to the heap, and return it as a [`RcBlock`].
+    ///
+    /// This is equivalent to calling [`Block::copy`] through [`Deref`], but
+    /// is more discoverable when you want to hand ownership of the block to
+    /// something that keeps it around.
+    ///
+    /// The block is copied with exactly one `_Block_copy`, which clones the
+    /// closure once into the heap allocation; the stack block itself is left
+    /// untouched, and may continue to be used.
+    ///
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use block2::{RcBlock, StackBlock};
+    ///
+    /// let stack_block = StackBlock::new(|a: i32, b: i32| a + b);
+    /// let rc_block: RcBlock<dyn Fn(i32, i32) -> i32> = stack_block.clone_into_rc();
+    /// assert_eq!(rc_block.call((5, 8)), 13);
+    /// ```
+    #[doc(alias = "Block_copy")]
+    #[doc(alias = "_Block_copy")]
+    #[inline]
+    pub fn clone_into_rc(&self) -> RcBlock<Closure::Dyn> {
+        (**self).copy()
+    }
+
     /// Constructs a new [`StackBlock`] with the given function and encoding
     /// information.
     ///
@@ -314,6 +360,47 @@ where
     }
 }
 
+impl<'f, A, R, Closure> StackBlock<'f, A, R, MutClosure<Closure>>
+where
+    A: EncodeArguments,
+    R: EncodeReturn,
+    MutClosure<Closure>: IntoBlock<'f, A, R> + Clone,
+{
+    /// Construct a `StackBlock` with the given [`FnMut`] closure.
+    ///
+    /// Similar to [`Self::new`], this requires the closure to be [`Clone`].
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The block must not be called re-entrantly. See [`RcBlock::with_mut`]
+    /// for details.
+    ///
+    /// [`RcBlock::with_mut`]: crate::RcBlock::with_mut
+    ///
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use block2::StackBlock;
+    ///
+    /// let mut count = 0;
+    /// // SAFETY: The block is not called re-entrantly.
+    /// let block = unsafe {
+    ///     StackBlock::with_mut(move || {
+    ///         count += 1;
+    ///         count
+    ///     })
+    /// };
+    /// assert_eq!(block.call(()), 1);
+    /// assert_eq!(block.call(()), 2);
+    /// ```
+    #[inline]
+    pub unsafe fn with_mut(closure: Closure) -> Self {
+        Self::new(MutClosure::new(closure))
+    }
+}
+
 // `RcBlock::with_encoding`
 impl<'f, A, R, Closure> StackBlock<'f, A, R, Closure> {
     unsafe extern "C-unwind" fn empty_clone_closure(_dst: *mut c_void, _src: *const c_void) {
@@ -515,6 +602,76 @@ impl<A, R, Closure> fmt::Debug for StackBlock<'_, A, R, Closure> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn with_mut() {
+        let mut count = 0;
+        let block = unsafe {
+            StackBlock::with_mut(move |n: i32| {
+                count += n;
+                count
+            })
+        };
+        assert_eq!(block.call((1,)), 1);
+        assert_eq!(block.call((1,)), 2);
+
+        // Copying the block clones the closure along with its state.
+        let copy = block.copy();
+        assert_eq!(copy.call((10,)), 12);
+        assert_eq!(block.call((1,)), 3);
+        assert_eq!(copy.call((10,)), 22);
+    }
+
+    #[test]
+    fn clone_into_rc() {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+
+        #[derive(Default)]
+        struct Counts {
+            clones: Cell<usize>,
+            drops: Cell<usize>,
+        }
+
+        struct Tracked(Rc<Counts>);
+
+        impl Clone for Tracked {
+            fn clone(&self) -> Self {
+                self.0.clones.set(self.0.clones.get() + 1);
+                Self(self.0.clone())
+            }
+        }
+
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                self.0.drops.set(self.0.drops.get() + 1);
+            }
+        }
+
+        let counts = Rc::new(Counts::default());
+        let tracked = Tracked(counts.clone());
+        let stack_block = StackBlock::new(move || {
+            let _ = &tracked;
+            5
+        });
+
+        let rc_block = stack_block.clone_into_rc();
+        assert_eq!(counts.clones.get(), 1);
+        assert_eq!(counts.drops.get(), 0);
+        assert_eq!(rc_block.call(()), 5);
+        assert_eq!(stack_block.call(()), 5);
+
+        // Cloning the `RcBlock` only bumps the reference count.
+        let rc_block2 = rc_block.clone();
+        assert_eq!(counts.clones.get(), 1);
+
+        drop(rc_block);
+        drop(rc_block2);
+        assert_eq!(counts.drops.get(), 1);
+        drop(stack_block);
+        assert_eq!(counts.clones.get(), 1);
+        assert_eq!(counts.drops.get(), 2);
+    }
+
     #[test]
     fn test_size() {
         assert_eq!(
@@ -527,6 +684,38 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(debug_assertions)]
+    fn poison_on_dispose() {
+        use alloc::rc::Rc;
+        use core::mem::ManuallyDrop;
+        use core::slice;
+
+        fn closure_bytes<'a, A, R, Closure>(block: &'a StackBlock<'_, A, R, Closure>) -> &'a [u8] {
+            let ptr: *const Closure = &block.closure;
+            unsafe { slice::from_raw_parts(ptr.cast::<u8>(), mem::size_of::<Closure>()) }
+        }
+
+        // Simulate the runtime disposing a copied block.
+        fn dispose<A, R, Closure>(block: &mut StackBlock<'_, A, R, Closure>) {
+            let ptr: *mut StackBlock<'_, A, R, Closure> = block;
+            unsafe { <StackBlock<'_, A, R, Closure>>::drop_closure(ptr.cast()) };
+        }
+
+        let rc = Rc::new(());
+        let captured = rc.clone();
+        let mut block = ManuallyDrop::new(StackBlock::new(move || {
+            let _ = &captured;
+        }));
+        assert_eq!(Rc::strong_count(&rc), 2);
+        assert!(closure_bytes(&block).iter().any(|b| *b != POISON));
+
+        dispose(&mut block);
+
+        assert_eq!(Rc::strong_count(&rc), 1);
+        assert!(closure_bytes(&block).iter().all(|b| *b == POISON));
+    }
+
     #[allow(dead_code)]
     fn covariant<'b, 'f>(
         b: StackBlock<'static, (), (), impl Fn() + 'static>,
@@ -1,10 +1,14 @@
+use core::cell::{Cell, UnsafeCell};
 use core::ffi::CStr;
+use core::fmt;
 use core::marker::PhantomData;
 use core::mem;
 use core::ptr;
 
 use objc2::encode::EncodeArguments;
 use objc2::encode::{EncodeArgument, EncodeReturn};
+use objc2::rc::Weak;
+use objc2::Message;
 
 use crate::{Block, StackBlock};
 
@@ -15,7 +19,7 @@ mod private {
 /// Types that represent closure parameters/arguments and return types in a
 /// block.
 ///
-/// This is implemented for [`dyn`] [`Fn`] closures with up to 12 parameters,
+/// This is implemented for [`dyn`] [`Fn`] closures with up to 16 parameters,
 /// where each parameter implements [`EncodeArgument`] and the return type
 /// implements [`EncodeReturn`].
 ///
@@ -44,15 +48,41 @@ pub unsafe trait BlockFn: private::Sealed<Self::Args, Self::Output> {
 
 /// Types that may be converted into a block.
 ///
-/// This is implemented for [`Fn`] closures of up to 12 parameters, where each
+/// This is implemented for [`Fn`] closures of up to 16 parameters, where each
 /// parameter implements [`EncodeArgument`] and the return type implements
 /// [`EncodeReturn`].
 ///
+/// It is also implemented for `extern "C" fn` pointers with the same
+/// restrictions, which allows using an existing function as a block without
+/// wrapping it in a closure. The block then only captures the function
+/// pointer.
+///
 ///
 /// # Safety
 ///
 /// This is a sealed trait, and should not need to be implemented. Open an
 /// issue if you know a use-case where this restrition should be lifted!
+///
+///
+/// # Example
+///
+/// Use an `extern "C"` function as a block.
+///
+/// ```
+/// use block2::{Block, RcBlock};
+///
+/// extern "C" fn add(a: i32, b: i32) -> i32 {
+///     a + b
+/// }
+///
+/// fn takes_block(block: &Block<dyn Fn(i32, i32) -> i32>) -> i32 {
+///     block.call((5, 8))
+/// }
+///
+/// // Function items have to be converted to function pointers first.
+/// let block = RcBlock::new(add as extern "C" fn(_, _) -> _);
+/// assert_eq!(takes_block(&block), 13);
+/// ```
 pub unsafe trait IntoBlock<'f, A, R>: private::Sealed<A, R>
 where
     A: EncodeArguments,
@@ -65,6 +95,105 @@ where
     fn __get_invoke_stack_block() -> unsafe extern "C-unwind" fn();
 }
 
+/// A wrapper that allows using [`FnMut`] closures as blocks.
+///
+/// Created by [`RcBlock::with_mut`] and [`StackBlock::with_mut`].
+///
+/// [`RcBlock::with_mut`]: crate::RcBlock::with_mut
+#[doc(hidden)]
+pub struct MutClosure<Closure>(UnsafeCell<Closure>);
+
+impl<Closure> MutClosure<Closure> {
+    #[inline]
+    pub(crate) fn new(closure: Closure) -> Self {
+        Self(UnsafeCell::new(closure))
+    }
+}
+
+impl<Closure> fmt::Debug for MutClosure<Closure> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MutClosure").finish_non_exhaustive()
+    }
+}
+
+impl<Closure: Clone> Clone for MutClosure<Closure> {
+    #[inline]
+    fn clone(&self) -> Self {
+        // SAFETY: The creator of the block has guaranteed that the closure
+        // is not currently being called, so there is no mutable reference
+        // to it.
+        Self::new(unsafe { (*self.0.get()).clone() })
+    }
+}
+
+/// A wrapper that allows using [`FnOnce`] closures as blocks.
+///
+/// Created by [`RcBlock::once`].
+///
+/// [`RcBlock::once`]: crate::RcBlock::once
+#[doc(hidden)]
+pub struct OnceClosure<Closure>(Cell<Option<Closure>>);
+
+impl<Closure> OnceClosure<Closure> {
+    #[inline]
+    pub(crate) fn new(closure: Closure) -> Self {
+        Self(Cell::new(Some(closure)))
+    }
+
+    #[inline]
+    fn take(&self) -> Closure {
+        self.0.take().unwrap_or_else(|| once_called_twice())
+    }
+}
+
+impl<Closure> fmt::Debug for OnceClosure<Closure> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OnceClosure").finish_non_exhaustive()
+    }
+}
+
+#[cold]
+fn once_called_twice() -> ! {
+    panic!("block created with `RcBlock::once` was called more than once")
+}
+
+/// A wrapper that only calls the closure if a weakly referenced object is
+/// still alive.
+///
+/// Created by [`RcBlock::new_weak`].
+///
+/// [`RcBlock::new_weak`]: crate::RcBlock::new_weak
+#[doc(hidden)]
+pub struct WeakClosure<T, Closure> {
+    weak: Weak<T>,
+    closure: Closure,
+}
+
+impl<T: Message, Closure> WeakClosure<T, Closure> {
+    #[inline]
+    pub(crate) fn new(weak: &Weak<T>, closure: Closure) -> Self {
+        Self {
+            weak: weak.clone(),
+            closure,
+        }
+    }
+}
+
+impl<T, Closure> fmt::Debug for WeakClosure<T, Closure> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakClosure")
+            .field("weak", &self.weak)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Message, Closure: Clone> Clone for WeakClosure<T, Closure> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(&self.weak, self.closure.clone())
+    }
+}
+
 macro_rules! impl_traits {
     ($($a:ident: $t:ident),*) => (
         impl<$($t: EncodeArgument,)* R: EncodeReturn, Closure> private::Sealed<($($t,)*), R> for Closure
@@ -121,6 +250,151 @@ macro_rules! impl_traits {
                 }
             }
         }
+
+        impl<$($t: EncodeArgument,)* R: EncodeReturn, Closure> private::Sealed<($($t,)*), R> for MutClosure<Closure>
+        where
+            Closure: FnMut($($t),*) -> R,
+        {}
+
+        unsafe impl<'f, $($t,)* R, Closure> IntoBlock<'f, ($($t,)*), R> for MutClosure<Closure>
+        where
+            $($t: EncodeArgument,)*
+            R: EncodeReturn,
+            Closure: FnMut($($t),*) -> R + 'f,
+        {
+            type Dyn = dyn Fn($($t),*) -> R + 'f;
+
+            #[inline]
+            fn __get_invoke_stack_block() -> unsafe extern "C-unwind" fn() {
+                unsafe extern "C-unwind" fn invoke<'f, $($t,)* R, Closure>(
+                    block: *mut StackBlock<'f, ($($t,)*), R, MutClosure<Closure>>,
+                    $($a: $t,)*
+                ) -> R
+                where
+                    Closure: FnMut($($t),*) -> R + 'f
+                {
+                    let cell = unsafe { &*ptr::addr_of!((*block).closure) };
+                    // SAFETY: The creator of the block has guaranteed that
+                    // it is not called re-entrantly, so this is the only
+                    // reference to the closure.
+                    let closure = unsafe { &mut *cell.0.get() };
+                    (closure)($($a),*)
+                }
+
+                unsafe {
+                    mem::transmute::<
+                        unsafe extern "C-unwind" fn(*mut StackBlock<'f, ($($t,)*), R, MutClosure<Closure>>, $($t,)*) -> R,
+                        unsafe extern "C-unwind" fn(),
+                    >(invoke)
+                }
+            }
+        }
+
+        impl<$($t: EncodeArgument,)* R: EncodeReturn, Closure> private::Sealed<($($t,)*), R> for OnceClosure<Closure>
+        where
+            Closure: FnOnce($($t),*) -> R,
+        {}
+
+        unsafe impl<'f, $($t,)* R, Closure> IntoBlock<'f, ($($t,)*), R> for OnceClosure<Closure>
+        where
+            $($t: EncodeArgument,)*
+            R: EncodeReturn,
+            Closure: FnOnce($($t),*) -> R + 'f,
+        {
+            type Dyn = dyn Fn($($t),*) -> R + 'f;
+
+            #[inline]
+            fn __get_invoke_stack_block() -> unsafe extern "C-unwind" fn() {
+                unsafe extern "C-unwind" fn invoke<'f, $($t,)* R, Closure>(
+                    block: *mut StackBlock<'f, ($($t,)*), R, OnceClosure<Closure>>,
+                    $($a: $t,)*
+                ) -> R
+                where
+                    Closure: FnOnce($($t),*) -> R + 'f
+                {
+                    let closure = unsafe { &*ptr::addr_of!((*block).closure) };
+                    // Panics if the closure has already been taken.
+                    (closure.take())($($a),*)
+                }
+
+                unsafe {
+                    mem::transmute::<
+                        unsafe extern "C-unwind" fn(*mut StackBlock<'f, ($($t,)*), R, OnceClosure<Closure>>, $($t,)*) -> R,
+                        unsafe extern "C-unwind" fn(),
+                    >(invoke)
+                }
+            }
+        }
+
+        impl<$($t: EncodeArgument,)* R: EncodeReturn, T, Closure> private::Sealed<($($t,)*), R> for WeakClosure<T, Closure>
+        where
+            T: Message,
+            Closure: Fn(&T, $($t),*) -> R,
+        {}
+
+        unsafe impl<'f, $($t,)* R, T, Closure> IntoBlock<'f, ($($t,)*), R> for WeakClosure<T, Closure>
+        where
+            $($t: EncodeArgument,)*
+            R: EncodeReturn + Default,
+            T: Message + 'f,
+            Closure: Fn(&T, $($t),*) -> R + 'f,
+        {
+            type Dyn = dyn Fn($($t),*) -> R + 'f;
+
+            #[inline]
+            fn __get_invoke_stack_block() -> unsafe extern "C-unwind" fn() {
+                unsafe extern "C-unwind" fn invoke<'f, $($t,)* R, T, Closure>(
+                    block: *mut StackBlock<'f, ($($t,)*), R, WeakClosure<T, Closure>>,
+                    $($a: $t,)*
+                ) -> R
+                where
+                    R: Default,
+                    T: Message + 'f,
+                    Closure: Fn(&T, $($t),*) -> R + 'f
+                {
+                    let closure = unsafe { &*ptr::addr_of!((*block).closure) };
+                    match closure.weak.load() {
+                        Some(obj) => (closure.closure)(&obj $(, $a)*),
+                        None => R::default(),
+                    }
+                }
+
+                unsafe {
+                    mem::transmute::<
+                        unsafe extern "C-unwind" fn(*mut StackBlock<'f, ($($t,)*), R, WeakClosure<T, Closure>>, $($t,)*) -> R,
+                        unsafe extern "C-unwind" fn(),
+                    >(invoke)
+                }
+            }
+        }
+
+        impl<$($t: EncodeArgument,)* R: EncodeReturn> private::Sealed<($($t,)*), R> for extern "C" fn($($t),*) -> R {}
+
+        unsafe impl<'f, $($t,)* R> IntoBlock<'f, ($($t,)*), R> for extern "C" fn($($t),*) -> R
+        where
+            $($t: EncodeArgument,)*
+            R: EncodeReturn,
+        {
+            type Dyn = dyn Fn($($t),*) -> R + 'f;
+
+            #[inline]
+            fn __get_invoke_stack_block() -> unsafe extern "C-unwind" fn() {
+                unsafe extern "C-unwind" fn invoke<'f, $($t,)* R>(
+                    block: *mut StackBlock<'f, ($($t,)*), R, extern "C" fn($($t),*) -> R>,
+                    $($a: $t,)*
+                ) -> R {
+                    let f = unsafe { *ptr::addr_of!((*block).closure) };
+                    f($($a),*)
+                }
+
+                unsafe {
+                    mem::transmute::<
+                        unsafe extern "C-unwind" fn(*mut StackBlock<'f, ($($t,)*), R, extern "C" fn($($t),*) -> R>, $($t,)*) -> R,
+                        unsafe extern "C-unwind" fn(),
+                    >(invoke)
+                }
+            }
+        }
     );
 }
 
@@ -137,6 +411,10 @@ impl_traits!(t0: T0, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5, t6: T6, t7: T7, t8:
 impl_traits!(t0: T0, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5, t6: T6, t7: T7, t8: T8, t9: T9);
 impl_traits!(t0: T0, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5, t6: T6, t7: T7, t8: T8, t9: T9, t10: T10);
 impl_traits!(t0: T0, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5, t6: T6, t7: T7, t8: T8, t9: T9, t10: T10, t11: T11);
+impl_traits!(t0: T0, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5, t6: T6, t7: T7, t8: T8, t9: T9, t10: T10, t11: T11, t12: T12);
+impl_traits!(t0: T0, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5, t6: T6, t7: T7, t8: T8, t9: T9, t10: T10, t11: T11, t12: T12, t13: T13);
+impl_traits!(t0: T0, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5, t6: T6, t7: T7, t8: T8, t9: T9, t10: T10, t11: T11, t12: T12, t13: T13, t14: T14);
+impl_traits!(t0: T0, t1: T1, t2: T2, t3: T3, t4: T4, t5: T5, t6: T6, t7: T7, t8: T8, t9: T9, t10: T10, t11: T11, t12: T12, t13: T13, t14: T14, t15: T15);
 
 /// Interim abstraction to manually provide block encodings for use at compile
 /// time with [`StackBlock::with_encoding`] and [`RcBlock::with_encoding`].
@@ -339,6 +617,59 @@ mod tests {
     use core::ffi::c_char;
 
     use super::*;
+    use crate::RcBlock;
+
+    #[test]
+    fn extern_c_fn() {
+        extern "C" fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        extern "C" fn takes_block(block: &Block<dyn Fn(i32, i32) -> i32>) -> i32 {
+            block.call((5, 8))
+        }
+
+        let f: extern "C" fn(i32, i32) -> i32 = add;
+
+        let block = StackBlock::new(f);
+        assert_eq!(takes_block(&block), 13);
+        // Function pointers are `Copy`, so copying the block is trivial.
+        assert_eq!(takes_block(&block.copy()), 13);
+
+        assert_eq!(takes_block(&RcBlock::new(f)), 13);
+
+        extern "C" fn noop() {}
+        RcBlock::new(noop as extern "C" fn()).call(());
+    }
+
+    #[test]
+    fn many_parameters() {
+        #[allow(clippy::too_many_arguments)]
+        fn sum(
+            a: i32,
+            b: i32,
+            c: i32,
+            d: i32,
+            e: i32,
+            f: i32,
+            g: i32,
+            h: i32,
+            i: i32,
+            j: i32,
+            k: i32,
+            l: i32,
+            m: u8,
+            n: f64,
+        ) -> f64 {
+            f64::from(a + b + c + d + e + f + g + h + i + j + k + l + i32::from(m)) + n
+        }
+
+        type Sum =
+            dyn Fn(i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, u8, f64) -> f64;
+        let block: RcBlock<Sum> = RcBlock::new(sum);
+        let res = block.call((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 0.5));
+        assert_eq!(res, 91.5);
+    }
 
     #[test]
     fn test_manual_block_encoding_is_none() {
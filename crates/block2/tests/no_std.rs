@@ -0,0 +1,50 @@
+//! Test that blocks can be used from a `#![no_std]` crate, with only `alloc`.
+#![no_std]
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::cell::Cell;
+
+use block2::{global_block, Block, ByRef, RcBlock, StackBlock};
+
+global_block! {
+    static ADD = |a: i32, b: i32| -> i32 { a + b };
+}
+
+fn call_with_block(block: &Block<dyn Fn(i32, i32) -> i32>) -> i32 {
+    block.call((5, 8))
+}
+
+#[test]
+fn blocks() {
+    assert_eq!(call_with_block(&ADD), 13);
+
+    let stack_block = StackBlock::new(|a, b| a * b);
+    assert_eq!(call_with_block(&stack_block), 40);
+
+    let captured = Box::new(1);
+    let rc_block = RcBlock::new(move |a: i32, b: i32| a - b + *captured);
+    assert_eq!(call_with_block(&rc_block), -2);
+    assert_eq!(call_with_block(&rc_block.clone()), -2);
+
+    let copied = stack_block.copy();
+    assert_eq!(call_with_block(&copied), 40);
+}
+
+#[test]
+fn shared_state() {
+    let count = Cell::new(0);
+    let block = RcBlock::new(|| count.set(count.get() + 1));
+    block.call(());
+    block.call(());
+    drop(block);
+    assert_eq!(count.get(), 2);
+
+    let counter = ByRef::new(0);
+    let block = RcBlock::new({
+        let counter = counter.clone();
+        move || counter.set(counter.get() + 1)
+    });
+    block.call(());
+    assert_eq!(counter.get(), 1);
+}
@@ -8,6 +8,8 @@ use crate::generated::{
     _dispatch_main_q, _dispatch_queue_attr_concurrent, dispatch_get_global_queue,
     dispatch_queue_set_specific,
 };
+#[cfg(feature = "block2")]
+use crate::generated::{dispatch_block_create_with_qos_class, dispatch_block_flags_t};
 use crate::{
     DispatchObject, DispatchQoS, DispatchRetained, DispatchTime, QualityOfServiceClassFloorError,
 };
@@ -137,6 +139,49 @@ impl DispatchQueue {
         unsafe { Self::exec_async_f(self, work_boxed, function_wrapper::<F>) }
     }
 
+    /// Submit a closure as a block for asynchronous execution on the
+    /// [`DispatchQueue`], with the given quality-of-service class.
+    ///
+    /// Unlike [`exec_async`][Self::exec_async], this goes through
+    /// `dispatch_async` with a work item created by
+    /// `dispatch_block_create_with_qos_class`, which allows overriding the
+    /// QoS of the individual work item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `qos_class` is not one of the known QoS classes.
+    #[cfg(feature = "block2")]
+    #[doc(alias = "dispatch_async")]
+    #[doc(alias = "dispatch_block_create_with_qos_class")]
+    pub fn exec_block_async<F>(&self, qos_class: DispatchQoS, work: F)
+    where
+        // Dispatch copies the block, and may run and release it on another
+        // thread, so the closure must be `Send` and `'static`.
+        F: Send + FnOnce() + 'static,
+    {
+        // Dispatch only runs the work item once.
+        let block = block2::RcBlock::once(work);
+        let flags = dispatch_block_flags_t(0);
+
+        // SAFETY: The block pointer is valid, and no flags are passed.
+        let work_item = unsafe {
+            dispatch_block_create_with_qos_class(
+                flags,
+                qos_class,
+                0,
+                block2::RcBlock::as_ptr(&block),
+            )
+        };
+        // SAFETY: `dispatch_block_create*` returns a new block that must be
+        // released with `Block_release`. It returns NULL if the QoS class
+        // is invalid.
+        let work_item = unsafe { block2::RcBlock::from_raw(work_item) }.expect("invalid QoS class");
+
+        // SAFETY: object cannot be null, and the block is valid. Dispatch
+        // copies the block, so it's fine for us to release it afterwards.
+        unsafe { Self::exec_async_with_block(self, block2::RcBlock::as_ptr(&work_item)) }
+    }
+
     /// Enqueue a function for execution at the specified time on the [`DispatchQueue`].
     pub fn after<F>(&self, when: DispatchTime, work: F) -> Result<(), QueueAfterError>
     where
@@ -317,6 +362,25 @@ mod tests {
         rx.recv().unwrap();
     }
 
+    #[test]
+    #[cfg(feature = "block2")]
+    #[ignore = "depends on the scheduling of the shared global queues"]
+    fn test_block_global_queue() {
+        use crate::{DispatchSemaphore, DispatchTime};
+
+        let queue = DispatchQueue::global_queue(GlobalQueueIdentifier::QualityOfService(
+            DispatchQoS::Default,
+        ));
+        let semaphore = DispatchSemaphore::new(0);
+        let cloned_semaphore = semaphore.clone();
+        queue.exec_block_async(DispatchQoS::UserInitiated, move || {
+            // Moves the semaphore out, so the closure is only `FnOnce`.
+            let semaphore = cloned_semaphore;
+            semaphore.signal();
+        });
+        let _guard = semaphore.try_acquire(DispatchTime::FOREVER).unwrap();
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn test_share_queue_across_threads() {
@@ -242,17 +242,7 @@ impl Availability {
 
     /// Available and non-deprecated enum cases.
     pub fn is_available_non_deprecated(&self) -> bool {
-        !matches!(
-            self.unavailable,
-            Unavailable {
-                ios: true,
-                macos: true,
-                maccatalyst: true,
-                watchos: true,
-                tvos: true,
-                visionos: true,
-            }
-        ) && !self.is_deprecated()
+        self.is_available_anywhere() && !self.is_deprecated()
     }
 
     pub fn is_deprecated(&self) -> bool {
@@ -325,12 +315,71 @@ impl Availability {
 
             write!(f, "..)")?;
 
-            // TODO: Add cfg!(not(...)) based on self.unavailable
-
             Ok(())
         }))
     }
 
+    /// The platforms that the item is explicitly marked unavailable on,
+    /// such as with `API_UNAVAILABLE(tvos)`, as `target_os` values and
+    /// display names.
+    ///
+    /// Returns nothing if the item is unavailable everywhere (in which case
+    /// we leave it as-is).
+    fn unavailable_platforms(&self) -> Vec<(&'static str, &'static str)> {
+        let unavailable = &self.unavailable;
+        if !self.is_available_anywhere() {
+            return vec![];
+        }
+
+        let mut platforms = Vec::new();
+        if unavailable.macos {
+            platforms.push(("macos", "macOS"));
+        }
+        // `target_os = "ios"` also matches Mac Catalyst, so only include it
+        // if the item is unavailable there too.
+        if unavailable.ios && unavailable.maccatalyst {
+            platforms.push(("ios", "iOS"));
+        }
+        if unavailable.tvos {
+            platforms.push(("tvos", "tvOS"));
+        }
+        if unavailable.watchos {
+            platforms.push(("watchos", "watchOS"));
+        }
+        if unavailable.visionos {
+            platforms.push(("visionos", "visionOS"));
+        }
+        platforms
+    }
+
+    fn is_available_anywhere(&self) -> bool {
+        !matches!(
+            self.unavailable,
+            Unavailable {
+                ios: true,
+                macos: true,
+                maccatalyst: true,
+                watchos: true,
+                tvos: true,
+                visionos: true,
+            }
+        )
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_unavailable(mut self, platform: &str) -> Self {
+        match platform {
+            "macos" => self.unavailable.macos = true,
+            "maccatalyst" => self.unavailable.maccatalyst = true,
+            "ios" => self.unavailable.ios = true,
+            "tvos" => self.unavailable.tvos = true,
+            "watchos" => self.unavailable.watchos = true,
+            "xros" => self.unavailable.visionos = true,
+            platform => panic!("unknown platform {platform:?}"),
+        }
+        self
+    }
+
     // Used when testing
     pub fn is_available_host(&self) -> bool {
         if self.unavailable.macos {
@@ -369,7 +418,17 @@ impl fmt::Display for Availability {
                 }
             }
         }
-        // TODO: Emit `cfg` attributes based on `self.unavailable`
+        if !self.is_deprecated() {
+            // Deprecate the item on the platforms it is unavailable on,
+            // instead of removing it there, since that would also require
+            // removing everything that references it.
+            for (target_os, name) in self.unavailable_platforms() {
+                writeln!(
+                    f,
+                    "#[cfg_attr(target_os = {target_os:?}, deprecated = \"unavailable on {name}\")]"
+                )?;
+            }
+        }
         // TODO: Emit availability checks based on `self.introduced`
         Ok(())
     }
@@ -381,3 +440,48 @@ pub const HOST_MACOS: u32 = if option_env!("CI").is_some() {
     // @madsmtm's development machine's current OS version.
     14
 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unavailable_deprecated() {
+        let availability = Availability::default();
+        assert_eq!(availability.to_string(), "");
+
+        let availability = availability.with_unavailable("tvos");
+        assert_eq!(
+            availability.to_string(),
+            "#[cfg_attr(target_os = \"tvos\", deprecated = \"unavailable on tvOS\")]\n"
+        );
+
+        let availability = availability.with_unavailable("watchos");
+        assert_eq!(
+            availability.to_string(),
+            "#[cfg_attr(target_os = \"tvos\", deprecated = \"unavailable on tvOS\")]\n\
+             #[cfg_attr(target_os = \"watchos\", deprecated = \"unavailable on watchOS\")]\n"
+        );
+
+        // iOS is only included if Mac Catalyst is too.
+        let availability = availability.with_unavailable("ios");
+        assert!(!availability.to_string().contains("iOS"));
+        let availability = availability.with_unavailable("maccatalyst");
+        assert!(availability
+            .to_string()
+            .contains("#[cfg_attr(target_os = \"ios\", deprecated = \"unavailable on iOS\")]\n"));
+
+        // Already deprecated everywhere.
+        let deprecated = Availability {
+            unavailable: availability.unavailable.clone(),
+            ..Availability::new_deprecated("foo")
+        };
+        assert_eq!(deprecated.to_string(), "#[deprecated = \"foo\"]\n");
+
+        // Unavailable everywhere.
+        let availability = availability
+            .with_unavailable("macos")
+            .with_unavailable("xros");
+        assert_eq!(availability.to_string(), "");
+    }
+}
@@ -422,6 +422,11 @@ impl LibraryConfig {
             assert_eq!(data.use_value, Default::default());
         }
 
+        let allowed_in = self.enum_data.values();
+        for data in all.clone().filter(filter_ptr(allowed_in)) {
+            assert!(!data.closed);
+        }
+
         let allowed_in = self.class_data.values();
         for data in all.clone().filter(filter_ptr(allowed_in)) {
             assert_eq!(data.derives, Default::default());
@@ -535,6 +540,14 @@ pub struct StmtData {
     #[serde(default)]
     pub use_value: Option<bool>,
 
+    // Enums only.
+    /// Emit an `NS_ENUM` as a Rust `enum` with a `#[repr(...)]` matching the
+    /// underlying type, the same way that `NS_CLOSED_ENUM` is emitted.
+    ///
+    /// This is only sound if the enum is guaranteed to never gain new cases.
+    #[serde(default)]
+    pub closed: bool,
+
     // Class only.
     #[serde(default)]
     pub derives: Derives,
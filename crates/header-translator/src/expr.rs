@@ -3,16 +3,17 @@ use std::fmt;
 
 use clang::source::SourceRange;
 use clang::token::TokenKind;
-use clang::{Entity, EntityKind, EntityVisitResult, EvaluationResult};
+use clang::{Entity, EntityKind, EntityVisitResult, EvaluationResult, TypeKind};
 use four_char_code::FourCharCode;
 
 use crate::availability::Availability;
 use crate::context::MacroLocation;
 use crate::id::ItemTree;
+use crate::method::handle_reserved;
 use crate::name_translation::enum_prefix;
 use crate::rust_type::{Primitive, Ty};
 use crate::unexposed_attr::UnexposedAttr;
-use crate::{immediate_children, Context, ItemIdentifier, Location};
+use crate::{immediate_children, to_snake_case, Context, ItemIdentifier, Location};
 
 #[derive(Clone, Debug, PartialEq)]
 #[allow(clippy::upper_case_acronyms)]
@@ -141,6 +142,163 @@ impl Expr {
         Self::parse(entity, context)
     }
 
+    /// Translate the body of a `static inline` function.
+    ///
+    /// Only bodies consisting of a single `return` of simple arithmetic on
+    /// literals, parameters and struct fields are supported, `None` is
+    /// returned if anything else is encountered.
+    pub fn parse_inline_fn_body(body: &Entity<'_>, context: &Context<'_>) -> Option<Self> {
+        let [stmt] = &body.get_children()[..] else {
+            return None;
+        };
+        if stmt.get_kind() != EntityKind::ReturnStmt {
+            return None;
+        }
+        let [expr] = &stmt.get_children()[..] else {
+            return None;
+        };
+        let mut res = vec![];
+        Self::parse_inline_expr(expr, &mut res, context)?;
+        Some(Self::Tokens(res))
+    }
+
+    fn parse_inline_expr(
+        entity: &Entity<'_>,
+        res: &mut Vec<Token>,
+        context: &Context<'_>,
+    ) -> Option<()> {
+        // We use the tokens to find the operators (which are not otherwise
+        // exposed), so bail if they don't line up with the children (e.g.
+        // because of macros).
+        let num_tokens = |entity: &Entity<'_>| Some(entity.get_range()?.tokenize().len());
+        let tokens = entity.get_range()?.tokenize();
+
+        match (entity.get_kind(), &*entity.get_children()) {
+            (EntityKind::ParenExpr, [child]) => {
+                res.push(Token::Punctuation("(".into()));
+                Self::parse_inline_expr(child, res, context)?;
+                res.push(Token::Punctuation(")".into()));
+            }
+            // Implicit casts. Only allowed if they don't change the type,
+            // since Rust doesn't do integer promotion and such.
+            (EntityKind::UnexposedExpr, [child]) => {
+                if entity.get_type()?.get_canonical_type() != child.get_type()?.get_canonical_type()
+                {
+                    return None;
+                }
+                Self::parse_inline_expr(child, res, context)?;
+            }
+            (EntityKind::IntegerLiteral | EntityKind::FloatingLiteral, []) => {
+                if tokens.len() != 1 {
+                    return None;
+                }
+                let Self::Tokens(literal) = Self::from_tokens(&tokens, &BTreeMap::new(), context)?
+                else {
+                    return None;
+                };
+                res.extend(literal);
+            }
+            (EntityKind::DeclRefExpr, []) => {
+                let definition = entity.get_reference()?;
+                if definition.get_kind() != EntityKind::ParmDecl {
+                    return None;
+                }
+                let name = definition.get_name()?;
+                res.push(Token::Literal(handle_reserved(&to_snake_case(name))));
+            }
+            (EntityKind::UnaryOperator, [child]) => {
+                let op = match &*tokens.first()?.get_spelling() {
+                    "-" => "-",
+                    // Bitwise not
+                    "~" => "!",
+                    _ => return None,
+                };
+                if tokens.len() != 1 + num_tokens(child)? {
+                    return None;
+                }
+                if op == "-" {
+                    if let Some(ty) = unsigned_integer_ty(entity)? {
+                        // Negating unsigned integers is not allowed in Rust,
+                        // so we use the wrapping equivalent of C's behaviour.
+                        res.push(Token::Literal(format!("core::ffi::{ty}::wrapping_neg")));
+                        res.push(Token::Punctuation("(".into()));
+                        Self::parse_inline_fn_arg(child, res, context)?;
+                        res.push(Token::Punctuation(")".into()));
+                        return Some(());
+                    }
+                }
+                res.push(Token::Punctuation(op.into()));
+                Self::parse_inline_expr(child, res, context)?;
+            }
+            (EntityKind::BinaryOperator, [lhs, rhs]) => {
+                let lhs_len = num_tokens(lhs)?;
+                if tokens.len() != lhs_len + 1 + num_tokens(rhs)? {
+                    return None;
+                }
+                let op = tokens[lhs_len].get_spelling();
+                // These have the same semantics and precedence in C and Rust
+                // (bar overflow).
+                if !["+", "-", "*", "/", "%", "<<", ">>", "&", "|", "^"].contains(&&*op) {
+                    return None;
+                }
+                // Unsigned arithmetic wraps on overflow in C, whereas it
+                // panics (in debug mode) in Rust.
+                let wrapping_method = match &*op {
+                    "+" => Some("wrapping_add"),
+                    "-" => Some("wrapping_sub"),
+                    "*" => Some("wrapping_mul"),
+                    _ => None,
+                };
+                if let Some(method) = wrapping_method {
+                    if let Some(ty) = unsigned_integer_ty(entity)? {
+                        // Use the fully qualified function, since a method
+                        // call would be ambiguous on integer literals.
+                        res.push(Token::Literal(format!("core::ffi::{ty}::{method}")));
+                        res.push(Token::Punctuation("(".into()));
+                        Self::parse_inline_fn_arg(lhs, res, context)?;
+                        res.push(Token::Punctuation(", ".into()));
+                        Self::parse_inline_fn_arg(rhs, res, context)?;
+                        res.push(Token::Punctuation(")".into()));
+                        return Some(());
+                    }
+                }
+                Self::parse_inline_expr(lhs, res, context)?;
+                res.push(Token::Punctuation(op));
+                Self::parse_inline_expr(rhs, res, context)?;
+            }
+            (EntityKind::MemberRefExpr, [base]) => {
+                let field = entity.get_reference()?;
+                // Bitfields are stored in private byte arrays.
+                if field.is_bit_field() {
+                    return None;
+                }
+                // Don't handle `->`.
+                if tokens.get(num_tokens(base)?)?.get_spelling() != "." {
+                    return None;
+                }
+                Self::parse_inline_expr(base, res, context)?;
+                let name = handle_reserved(&field.get_name()?);
+                res.push(Token::Literal(format!(".{name}")));
+            }
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    /// Parse an expression that is used as a function argument, where
+    /// parentheses would be redundant.
+    fn parse_inline_fn_arg(
+        entity: &Entity<'_>,
+        res: &mut Vec<Token>,
+        context: &Context<'_>,
+    ) -> Option<()> {
+        if let (EntityKind::ParenExpr, [child]) = (entity.get_kind(), &*entity.get_children()) {
+            return Self::parse_inline_fn_arg(child, res, context);
+        }
+        Self::parse_inline_expr(entity, res, context)
+    }
+
     pub fn parse_macro_definition(entity: &Entity<'_>, context: &Context<'_>) -> Option<Self> {
         let declaration_references = BTreeMap::new();
 
@@ -458,6 +616,13 @@ impl Expr {
                         _ => {}
                     });
 
+                    // Keep in sync with the `closed` handling in `stmt.rs`.
+                    if context.library(&parent_id).get(&parent).closed
+                        && attrs.remove(&UnexposedAttr::Enum)
+                    {
+                        attrs.insert(UnexposedAttr::ClosedEnum);
+                    }
+
                     let mut relevant_enum_cases = variants
                         .iter()
                         .filter(|(_, availability)| availability.is_available_non_deprecated())
@@ -531,6 +696,22 @@ impl Expr {
     }
 }
 
+/// The name of the unsigned integer type of the expression, if it has one.
+///
+/// Returns `None` for unsigned types that we do not know how to handle.
+fn unsigned_integer_ty(entity: &Entity<'_>) -> Option<Option<&'static str>> {
+    let ty = entity.get_type()?.get_canonical_type();
+    Some(match ty.get_kind() {
+        TypeKind::UChar => Some("c_uchar"),
+        TypeKind::UShort => Some("c_ushort"),
+        TypeKind::UInt => Some("c_uint"),
+        TypeKind::ULong => Some("c_ulong"),
+        TypeKind::ULongLong => Some("c_ulonglong"),
+        _ if ty.is_unsigned_integer() => return None,
+        _ => None,
+    })
+}
+
 impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -9,7 +9,7 @@ use crate::availability::Availability;
 use crate::documentation::Documentation;
 use crate::expr::Expr;
 use crate::id::ItemTree;
-use crate::method::Method;
+use crate::method::{resolve_variadic_methods, Method};
 use crate::module::Module;
 use crate::name_translation::{cf_fn_name, find_fn_implementor};
 use crate::stmt::Stmt;
@@ -43,11 +43,142 @@ pub fn global_analysis(library: &mut Library, config: &Config) {
         &mut expected_bridged_types,
     );
 
+    resolve_variadic_methods_across_declarations(&mut library.module);
+
+    disambiguate_method_names(&mut library.module);
+
     if !expected_bridged_types.is_empty() {
         warn!("too many bridged-to in config: {expected_bridged_types:?}");
     }
 }
 
+/// Collect the methods in the module and its submodules, along with the
+/// Rust name of the class or protocol that they're defined on.
+fn collect_methods<'a>(module: &'a mut Module, out: &mut Vec<(&'a str, &'a mut Method)>) {
+    for stmt in module.stmts.iter_mut() {
+        match stmt {
+            Stmt::ExternMethods {
+                cls: id, methods, ..
+            }
+            | Stmt::ExternCategory {
+                cls: id, methods, ..
+            }
+            | Stmt::ProtocolDecl { id, methods, .. } => {
+                let name: &'a str = &id.name;
+                out.extend(methods.iter_mut().map(move |method| (name, method)));
+            }
+            _ => {}
+        }
+    }
+    for submodule in module.submodules.values_mut() {
+        collect_methods(submodule, out);
+    }
+}
+
+/// Collect the methods declared directly on each class (i.e. not those
+/// copied from a superclass), along with the variadic methods that couldn't
+/// be resolved within their own declaration.
+fn collect_class_methods<'a>(
+    module: &'a mut Module,
+    methods: &mut BTreeMap<&'a str, Vec<&'a mut Method>>,
+    variadic: &mut Vec<(&'a str, Vec<(String, bool, String)>)>,
+) {
+    for stmt in module.stmts.iter_mut() {
+        if let Stmt::ExternMethods {
+            cls,
+            source_superclass: None,
+            methods: cls_methods,
+            variadic_methods,
+            ..
+        } = stmt
+        {
+            let name: &'a str = &cls.name;
+            methods.entry(name).or_default().extend(cls_methods);
+            if !variadic_methods.is_empty() {
+                variadic.push((name, mem::take(variadic_methods)));
+            }
+        }
+    }
+    for submodule in module.submodules.values_mut() {
+        collect_class_methods(submodule, methods, variadic);
+    }
+}
+
+/// Resolve variadic methods against the counterparts declared in the class'
+/// other interfaces and categories, such as `initWithObjects:` from the
+/// `NSArrayCreation` category, which forwards to `initWithObjects:count:` on
+/// the `NSArray` interface itself.
+fn resolve_variadic_methods_across_declarations(module: &mut Module) {
+    let mut methods = BTreeMap::new();
+    let mut variadic = vec![];
+    collect_class_methods(module, &mut methods, &mut variadic);
+
+    for (cls, variadic) in variadic {
+        let methods = methods.entry(cls).or_default();
+        for (selector, _, _) in resolve_variadic_methods(methods, variadic) {
+            debug!(cls, ?selector, "can't handle variadic method");
+        }
+    }
+}
+
+/// Disambiguate methods whose selectors map to the same Rust name.
+///
+/// This is done across the entire library, since a class' methods may be
+/// spread out across several files in categories.
+///
+/// If one of the methods is a class method, we assume that they clashed
+/// because of that, and suffix it with `_class`. Otherwise we assume that
+/// they clashed because one of them was `myMethod:` while the other was
+/// `myMethod`, and use the full selector for both (unless they were renamed
+/// in the config). Any remaining collisions are resolved with a numeric
+/// suffix.
+fn disambiguate_method_names(module: &mut Module) {
+    let mut methods = vec![];
+    collect_methods(module, &mut methods);
+
+    let mut groups = BTreeMap::<(&str, String), Vec<&mut Method>>::new();
+    for (cls, method) in methods {
+        groups
+            .entry((cls, method.fn_name.clone()))
+            .or_default()
+            .push(method);
+    }
+
+    let mut used = BTreeSet::new();
+    for ((cls, name), mut group) in groups {
+        if group.len() > 1 {
+            let has_instance_method = group.iter().any(|method| !method.is_class);
+            let has_class_method = group.iter().any(|method| method.is_class);
+            let mixed = has_instance_method && has_class_method;
+
+            for is_class in [false, true] {
+                let same_kind = group.iter().filter(|m| m.is_class == is_class).count();
+                for method in group.iter_mut().filter(|m| m.is_class == is_class) {
+                    if same_kind > 1 && !method.is_renamed() {
+                        method.fn_name = method.selector.replace(':', "_");
+                    }
+                    if mixed && is_class {
+                        method.fn_name += "_class";
+                    }
+                }
+            }
+        }
+
+        for method in group {
+            if !used.insert((cls, method.fn_name.clone())) {
+                let base = method.fn_name.clone();
+                let fn_name = (2..)
+                    .map(|i| format!("{base}_{i}"))
+                    .find(|fn_name| !used.contains(&(cls, fn_name.clone())))
+                    .unwrap();
+                warn!("method {cls}::{name} collided, renamed to {fn_name}");
+                used.insert((cls, fn_name.clone()));
+                method.fn_name = fn_name;
+            }
+        }
+    }
+}
+
 fn create_implementable_mapping(module: &Module) -> BTreeSet<ItemTree> {
     let mut types = BTreeSet::new();
     for stmt in &module.stmts {
@@ -92,6 +223,7 @@ fn update_module(
             link_name,
             availability,
             arguments,
+            variadic,
             first_arg_is_self,
             result_type,
             body,
@@ -104,7 +236,9 @@ fn update_module(
             custom_implementor,
         } = stmt
         {
-            if *no_implementor {
+            // Variadic functions cannot be wrapped as methods, since we
+            // cannot forward the variadic arguments.
+            if *no_implementor || *variadic {
                 continue;
             }
 
@@ -155,9 +289,10 @@ fn update_module(
                             name,
                         )),
                         arguments: arguments.clone(),
+                        variadic: false,
                         result_type: result_type.clone(),
                         first_arg_is_self: *first_arg_is_self,
-                        body: *body,
+                        body: body.clone(),
                         safe: *safe,
                         must_use: *must_use,
                         abi: abi.clone(),
@@ -218,47 +353,6 @@ fn update_module(
 
     module.stmts.extend(deprecated_fns);
 
-    // disambiguate duplicate names
-    // NOTE: this only works within single files
-    let mut names = BTreeMap::<(String, String), &mut Method>::new();
-    for stmt in module.stmts.iter_mut() {
-        match stmt {
-            Stmt::ExternMethods {
-                cls: id, methods, ..
-            }
-            | Stmt::ExternCategory {
-                cls: id, methods, ..
-            }
-            | Stmt::ProtocolDecl { id, methods, .. } => {
-                for method in methods.iter_mut() {
-                    let key = (id.clone().name, method.fn_name.clone());
-                    if let Some(other) = names.get_mut(&key) {
-                        match (method.is_class, other.is_class) {
-                            // Assume that the methods clashed because one
-                            // of them was a class method
-                            (true, false) => {
-                                method.fn_name += "_class";
-                            }
-                            (false, true) => {
-                                other.fn_name += "_class";
-                            }
-                            // Otherwise assume that they clashed because
-                            // one of them were `myMethod:`, while the
-                            // other were `myMethod`.
-                            (true, true) | (false, false) => {
-                                other.fn_name = other.selector.replace(':', "_");
-                                method.fn_name = method.selector.replace(':', "_");
-                            }
-                        }
-                    } else {
-                        names.insert(key, method);
-                    }
-                }
-            }
-            _ => {}
-        }
-    }
-
     let mut iter = mem::take(&mut module.stmts).into_iter().peekable();
     while let Some(mut stmt) = iter.next() {
         // Fix up a few enum + typedef declarations. Example:
@@ -377,3 +471,152 @@ fn update_module(
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::method::default_fn_name;
+
+    fn extern_methods(category_name: Option<&str>, methods: Vec<Method>) -> Stmt {
+        let cls = ItemIdentifier::dummy(0);
+        Stmt::ExternMethods {
+            location: cls.location().clone(),
+            availability: Availability::default(),
+            cls,
+            cls_superclasses: vec![],
+            source_superclass: None,
+            cls_generics: vec![],
+            category_name: category_name.map(Into::into),
+            methods,
+            variadic_methods: vec![],
+            documentation: None,
+        }
+    }
+
+    fn method(selector: &str, is_class: bool) -> Method {
+        let mut method = Method::dummy(selector);
+        method.fn_name = default_fn_name(selector);
+        method.is_class = is_class;
+        method
+    }
+
+    fn module(files: Vec<Vec<Stmt>>) -> Module {
+        let mut module = Module::default();
+        for (i, stmts) in files.into_iter().enumerate() {
+            let submodule = module.submodules.entry(format!("File{i}")).or_default();
+            submodule.stmts = stmts;
+        }
+        module
+    }
+
+    fn fn_names(module: &mut Module) -> Vec<String> {
+        let mut methods = vec![];
+        collect_methods(module, &mut methods);
+        methods
+            .into_iter()
+            .map(|(_, method)| method.fn_name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn collision_across_category() {
+        let mut module = module(vec![
+            vec![extern_methods(None, vec![method("setFoo:", false)])],
+            vec![extern_methods(
+                Some("MyCategory"),
+                vec![method("setFoo", false)],
+            )],
+        ]);
+        disambiguate_method_names(&mut module);
+        assert_eq!(fn_names(&mut module), ["setFoo_", "setFoo"]);
+    }
+
+    #[test]
+    fn collision_class_method() {
+        let mut module = module(vec![
+            vec![extern_methods(None, vec![method("foo", false)])],
+            vec![extern_methods(
+                Some("MyCategory"),
+                vec![method("foo", true)],
+            )],
+        ]);
+        disambiguate_method_names(&mut module);
+        assert_eq!(fn_names(&mut module), ["foo", "foo_class"]);
+    }
+
+    #[test]
+    fn collision_class_property() {
+        // `@property BOOL enabled;` and `@property (class) BOOL enabled;`
+        let mut module = module(vec![vec![extern_methods(
+            None,
+            vec![
+                method("enabled", false),
+                method("setEnabled:", false),
+                method("enabled", true),
+                method("setEnabled:", true),
+            ],
+        )]]);
+        disambiguate_method_names(&mut module);
+        assert_eq!(
+            fn_names(&mut module),
+            ["enabled", "setEnabled", "enabled_class", "setEnabled_class"]
+        );
+    }
+
+    #[test]
+    fn collision_with_renamed() {
+        let mut renamed = method("fooWithBar:", false);
+        renamed.fn_name = "foo".into();
+        let mut module = module(vec![
+            vec![extern_methods(None, vec![renamed])],
+            vec![extern_methods(
+                Some("MyCategory"),
+                vec![method("foo", false)],
+            )],
+        ]);
+        disambiguate_method_names(&mut module);
+        // The renamed method keeps the name given in the config.
+        assert_eq!(fn_names(&mut module), ["foo", "foo_2"]);
+    }
+
+    #[test]
+    fn variadic_across_category() {
+        let mut category = extern_methods(Some("NSArrayCreation"), vec![]);
+        if let Stmt::ExternMethods {
+            variadic_methods, ..
+        } = &mut category
+        {
+            variadic_methods.push(("arrayWithObjects:".into(), true, "arrayWithObjects".into()));
+        }
+        let mut module = module(vec![
+            vec![extern_methods(
+                None,
+                vec![Method::dummy_array_with_objects_count()],
+            )],
+            vec![category],
+        ]);
+        resolve_variadic_methods_across_declarations(&mut module);
+
+        let mut methods = vec![];
+        collect_methods(&mut module, &mut methods);
+        let s = methods[0].1.variadic_convenience().unwrap().to_string();
+        assert!(
+            s.contains("unsafe fn arrayWithObjects(objects: &[&ObjectType], ) -> Retained<Self> {"),
+            "{s}"
+        );
+    }
+
+    #[test]
+    fn no_collision_across_classes() {
+        let mut other = extern_methods(None, vec![method("foo", false)]);
+        if let Stmt::ExternMethods { cls, .. } = &mut other {
+            *cls = ItemIdentifier::dummy(1);
+        }
+        let mut module = module(vec![
+            vec![extern_methods(None, vec![method("foo", false)])],
+            vec![other],
+        ]);
+        disambiguate_method_names(&mut module);
+        assert_eq!(fn_names(&mut module), ["foo", "foo"]);
+    }
+}
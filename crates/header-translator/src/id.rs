@@ -222,6 +222,33 @@ impl Location {
         }
     }
 
+    /// Resolve items in frameworks nested inside umbrella frameworks to the
+    /// innermost framework that we know of.
+    ///
+    /// An umbrella framework like `ApplicationServices` re-exports headers
+    /// from sub-frameworks like `ColorSync` that we emit as separate crates,
+    /// so the item should be imported from there, and not from the umbrella.
+    ///
+    /// Sub-frameworks that aren't libraries by themselves (e.g. private
+    /// sub-frameworks) are left as a module in the umbrella framework.
+    ///
+    /// This looks at the path itself, so even if frameworks re-export each
+    /// other in a circular fashion, the resolution always terminates.
+    pub fn resolve_nested_framework(self, is_library: impl Fn(&str) -> bool) -> Self {
+        let components: Vec<&str> = self.module_path.split('.').collect();
+        // Only consider the frameworks between the umbrella framework and the
+        // file name itself.
+        let Some(frameworks) = components.get(1..components.len() - 1) else {
+            return self;
+        };
+        if let Some(i) = frameworks.iter().rposition(|name| is_library(name)) {
+            let module_path = components[i + 1..].join(".");
+            Self::new(module_path)
+        } else {
+            self
+        }
+    }
+
     pub fn add_module(&self, module_name: &str) -> Self {
         Self {
             module_path: format!("{}.{}", self.module_path, module_name).into(),
@@ -348,6 +375,10 @@ impl<N: ToOptionString> ItemIdentifier<N> {
             location = Location::new("CoreFoundation");
         }
 
+        // Import items re-exported through umbrella frameworks from the
+        // library that actually defines them.
+        location = location.resolve_nested_framework(|name| context.try_library(name).is_some());
+
         // Replace module from external data if it exists, such that all
         // subsequent usage of the location, including in other configuration
         // lookups, is done in the external library.
@@ -1190,6 +1221,40 @@ impl AsRef<Self> for ItemTree {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_resolve_nested_framework() {
+        #[track_caller]
+        fn check(module_path: &str, expected: &str) {
+            let is_library = |name: &str| {
+                matches!(
+                    name,
+                    "ApplicationServices" | "ColorSync" | "CoreServices" | "Foo" | "Bar"
+                )
+            };
+            let location = Location::new(module_path).resolve_nested_framework(is_library);
+            assert_eq!(location, Location::new(expected));
+        }
+
+        // Public sub-framework with its own crate.
+        check(
+            "ApplicationServices.ColorSync.ColorSyncProfile",
+            "ColorSync.ColorSyncProfile",
+        );
+        // Private sub-framework without a crate.
+        check(
+            "CoreServices.CarbonCore.Files",
+            "CoreServices.CarbonCore.Files",
+        );
+        // Not nested.
+        check("ColorSync.ColorSyncProfile", "ColorSync.ColorSyncProfile");
+        check("ColorSync", "ColorSync");
+        // File that happens to be named like a library.
+        check("Foo.Bar", "Foo.Bar");
+        // Circular re-exports resolve to the innermost framework.
+        check("Foo.Bar.Foo.Baz", "Foo.Baz");
+        check("Foo.Bar.Qux.Baz", "Bar.Qux.Baz");
+    }
+
     #[test]
     fn test_visit() {
         let tree = ItemTree::new(
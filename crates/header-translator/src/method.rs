@@ -1,4 +1,5 @@
 use core::panic;
+use std::borrow::BorrowMut;
 use std::fmt;
 
 use clang::{Entity, EntityKind, ObjCAttributes, ObjCQualifiers};
@@ -11,7 +12,7 @@ use crate::documentation::Documentation;
 use crate::id::ItemTree;
 use crate::immediate_children;
 use crate::objc2_utils::in_selector_family;
-use crate::rust_type::{MethodArgumentQualifier, Ty};
+use crate::rust_type::{MethodArgumentQualifier, Primitive, Ty};
 use crate::unexposed_attr::UnexposedAttr;
 
 impl MethodArgumentQualifier {
@@ -300,6 +301,11 @@ pub struct Method {
     must_use: bool,
     encoding: String,
     documentation: Documentation,
+    /// The selector and Rust name of a variadic method, for which a
+    /// slice-taking convenience that forwards to this method is emitted.
+    ///
+    /// See [`resolve_variadic_methods`].
+    variadic_convenience: Option<(String, String)>,
 }
 
 #[derive(Debug)]
@@ -362,6 +368,11 @@ impl Method {
         (self.is_class, self.selector.clone())
     }
 
+    /// Whether the method was given a non-default name in the config.
+    pub(crate) fn is_renamed(&self) -> bool {
+        self.fn_name != default_fn_name(&self.selector)
+    }
+
     pub(crate) fn usable_in_default_retained(&self) -> bool {
         self.selector == "new"
             && self.is_class
@@ -529,7 +540,10 @@ impl Method {
             result_type.try_fix_related_result_type();
         }
 
-        let fn_name = selector.trim_end_matches(':').replace(':', "_");
+        let fn_name = data
+            .renamed
+            .clone()
+            .unwrap_or_else(|| default_fn_name(&selector));
 
         let mainthreadonly = mainthreadonly_override(
             &result_type,
@@ -564,6 +578,7 @@ impl Method {
                 must_use: modifiers.must_use,
                 encoding,
                 documentation: Documentation::from_entity(&entity, context),
+                variadic_convenience: None,
             },
         ))
     }
@@ -640,7 +655,10 @@ impl Method {
 
             Some(Method {
                 selector: getter_sel.clone(),
-                fn_name: getter_sel.clone(),
+                fn_name: getter_data
+                    .renamed
+                    .clone()
+                    .unwrap_or_else(|| default_fn_name(&getter_sel)),
                 availability: availability.clone(),
                 is_class,
                 is_optional: entity.is_objc_optional(),
@@ -656,6 +674,7 @@ impl Method {
                 must_use: modifiers.must_use,
                 encoding: encoding.clone(),
                 documentation: Documentation::from_entity(&entity, context),
+                variadic_convenience: None,
             })
         } else {
             None
@@ -672,7 +691,10 @@ impl Method {
                     context,
                 );
 
-                let fn_name = selector.strip_suffix(':').unwrap().to_string();
+                let fn_name = setter_data
+                    .renamed
+                    .clone()
+                    .unwrap_or_else(|| default_fn_name(&selector));
                 let memory_management =
                     MemoryManagement::new(is_class, &selector, &result_type, modifiers);
 
@@ -705,6 +727,7 @@ impl Method {
                     must_use: modifiers.must_use,
                     encoding,
                     documentation: Documentation::property_setter(&getter_sel),
+                    variadic_convenience: None,
                 })
             } else {
                 None
@@ -772,6 +795,93 @@ impl Method {
     }
 }
 
+impl Method {
+    /// Whether this method can be used to implement a variadic method with a
+    /// nil-terminated list of objects, i.e. whether it looks like
+    /// `arrayWithObjects:count:`.
+    fn can_forward_variadic(&self) -> bool {
+        let [(_, objects), (_, count)] = &*self.arguments else {
+            return false;
+        };
+        objects.object_array_from_slice().is_some()
+            && *count == Ty::Primitive(Primitive::NSUInteger)
+            && self.memory_management != MemoryManagement::InnerPointer
+    }
+
+    /// The slice-taking convenience for a variadic method that forwards to
+    /// this method, if any.
+    ///
+    /// Emitted in a normal `impl` block, since it has a body.
+    pub(crate) fn variadic_convenience(&self) -> Option<impl fmt::Display + '_> {
+        let (selector, fn_name) = self.variadic_convenience.as_ref()?;
+        let (element, converter) = self.arguments[0].1.object_array_from_slice()?;
+
+        Some(FormatterFn(move |f| {
+            let _span = debug_span!("variadic convenience", fn_name).entered();
+
+            writeln!(f, "    /// Convenience for the variadic `{selector}`.")?;
+            writeln!(f, "    ///")?;
+            writeln!(
+                f,
+                "    /// This forwards to [`{0}`][Self::{0}] with the given objects.",
+                self.fn_name
+            )?;
+            write!(f, "{}", self.availability)?;
+            if self.must_use {
+                writeln!(f, "    #[must_use]")?;
+            }
+            writeln!(f, "    #[inline]")?;
+
+            write!(f, "    ")?;
+            if self.is_pub {
+                write!(f, "pub ")?;
+            }
+            if !self.safe {
+                write!(f, "unsafe ")?;
+            }
+            write!(f, "fn {}(", handle_reserved(fn_name))?;
+
+            let receiver = if let MemoryManagement::RetainedInit = self.memory_management {
+                write!(f, "this: Allocated<Self>, ")?;
+                "Self::"
+            } else if self.is_class {
+                "Self::"
+            } else {
+                write!(f, "&self, ")?;
+                "self."
+            };
+            write!(f, "objects: &[{}], ", element.method_argument())?;
+            if self.mainthreadonly {
+                write!(f, "mtm: MainThreadMarker")?;
+            }
+            writeln!(f, "){} {{", self.result_type.method_return())?;
+
+            writeln!(f, "        let count = objects.len();")?;
+            writeln!(f, "        let objects = {converter};")?;
+            write!(f, "        ")?;
+            if !self.safe {
+                write!(f, "unsafe {{ ")?;
+            }
+            write!(f, "{receiver}{}(", handle_reserved(&self.fn_name))?;
+            if let MemoryManagement::RetainedInit = self.memory_management {
+                write!(f, "this, ")?;
+            }
+            write!(f, "objects, count")?;
+            if self.mainthreadonly {
+                write!(f, ", mtm")?;
+            }
+            write!(f, ")")?;
+            if !self.safe {
+                write!(f, " }}")?;
+            }
+            writeln!(f)?;
+            writeln!(f, "    }}")?;
+
+            Ok(())
+        }))
+    }
+}
+
 impl fmt::Display for Method {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let _span = debug_span!("method", self.fn_name).entered();
@@ -936,8 +1046,227 @@ pub(crate) fn handle_reserved(name: &str) -> String {
     }
 }
 
+/// The name that a method with the given selector gets in Rust, unless it is
+/// renamed in the config or disambiguated because of a collision.
+pub(crate) fn default_fn_name(selector: &str) -> String {
+    selector.trim_end_matches(':').replace(':', "_")
+}
+
+/// Find a counterpart taking an array and a count for each variadic method
+/// with a nil-terminated list of objects, such as `arrayWithObjects:count:`
+/// for `arrayWithObjects:`, and mark it to emit a slice-taking convenience.
+///
+/// The variadic methods are given as `(selector, is_class, fn_name)`, and the
+/// ones for which no counterpart was found in `methods` are returned, such
+/// that they can be resolved against the class' other declarations later on.
+pub(crate) fn resolve_variadic_methods(
+    methods: &mut [impl BorrowMut<Method>],
+    variadic: impl IntoIterator<Item = (String, bool, String)>,
+) -> Vec<(String, bool, String)> {
+    let mut unresolved = Vec::new();
+    for (selector, is_class, fn_name) in variadic {
+        let counterpart_sel = format!("{selector}count:");
+        let counterpart = methods
+            .iter_mut()
+            .map(BorrowMut::borrow_mut)
+            .find(|method| {
+                method.selector == counterpart_sel
+                    && method.is_class == is_class
+                    && method.variadic_convenience.is_none()
+            });
+        match counterpart {
+            Some(method) if method.can_forward_variadic() => {
+                method.variadic_convenience = Some((selector, fn_name));
+            }
+            Some(_) => debug!(?selector, "can't handle variadic method"),
+            None => unresolved.push((selector, is_class, fn_name)),
+        }
+    }
+    unresolved
+}
+
 pub(crate) fn apply_type_override(ty: &mut Ty, or: &TypeOverride) {
     if let Some(nullability) = or.nullability {
         ty.change_nullability(nullability.into());
     }
 }
+
+#[cfg(test)]
+impl Method {
+    /// A simple instance method with no arguments and no return value.
+    pub(crate) fn dummy(selector: &str) -> Self {
+        Self {
+            selector: selector.into(),
+            fn_name: selector.into(),
+            availability: Availability::default(),
+            is_class: false,
+            is_optional: false,
+            memory_management: MemoryManagement::Normal,
+            arguments: vec![],
+            result_type: Ty::VOID_RESULT,
+            safe: false,
+            is_pub: false,
+            non_isolated: false,
+            mainthreadonly: false,
+            weak_property: false,
+            must_use: false,
+            encoding: String::new(),
+            documentation: Documentation::empty(),
+            variadic_convenience: None,
+        }
+    }
+
+    /// `+ (instancetype)arrayWithObjects:(const ObjectType _Nonnull [_Nonnull])objects count:(NSUInteger)cnt;`
+    pub(crate) fn dummy_array_with_objects_count() -> Self {
+        use crate::rust_type::{Lifetime, PointeeTy};
+
+        let nonnull_ptr = |pointee: Ty, is_const: bool| Ty::Pointer {
+            nullability: clang::Nullability::NonNull,
+            is_const,
+            lifetime: Lifetime::Unspecified,
+            pointee: Box::new(pointee),
+        };
+        let object = nonnull_ptr(
+            Ty::Pointee(PointeeTy::GenericParam {
+                name: "ObjectType".into(),
+            }),
+            false,
+        );
+        Self {
+            fn_name: "arrayWithObjects_count".into(),
+            is_class: true,
+            arguments: vec![
+                ("objects".into(), nonnull_ptr(object, true)),
+                ("cnt".into(), Ty::Primitive(Primitive::NSUInteger)),
+            ],
+            result_type: nonnull_ptr(Ty::Pointee(PointeeTy::Self_), false),
+            ..Self::dummy("arrayWithObjects:count:")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn method(is_optional: bool) -> Method {
+        Method {
+            is_optional,
+            ..Method::dummy("doSomething")
+        }
+    }
+
+    #[test]
+    fn test_protocol_optional() {
+        // Optional and required protocol methods both get a default
+        // implementation from `extern_protocol!`, but only optional ones are
+        // marked as such.
+        let optional = method(true).to_string();
+        assert!(optional.contains("#[optional]"), "{optional}");
+        assert!(optional.contains("unsafe fn doSomething(&self, )"));
+
+        let required = method(false).to_string();
+        assert!(!required.contains("#[optional]"), "{required}");
+        assert!(required.contains("unsafe fn doSomething(&self, )"));
+    }
+
+    #[test]
+    fn test_api_unavailable() {
+        let mut unavailable = method(false);
+        unavailable.availability = Availability::default().with_unavailable("tvos");
+        let s = unavailable.to_string();
+        assert!(
+            s.contains("#[cfg_attr(target_os = \"tvos\", deprecated = \"unavailable on tvOS\")]"),
+            "{s}"
+        );
+        assert!(!s.contains("#[cfg("), "{s}");
+
+        let s = method(false).to_string();
+        assert!(!s.contains("deprecated"), "{s}");
+    }
+
+    #[test]
+    fn test_class_property() {
+        // `@property (class) BOOL enabled;`
+        let bool_ty = Ty::Primitive(Primitive::ObjcBool);
+        let getter = Method {
+            is_class: true,
+            result_type: bool_ty.clone(),
+            ..Method::dummy("enabled")
+        };
+        let s = getter.to_string();
+        assert!(s.contains("unsafe fn enabled() -> bool;"), "{s}");
+
+        let setter = Method {
+            fn_name: "setEnabled".into(),
+            is_class: true,
+            arguments: vec![("enabled".into(), bool_ty.clone())],
+            ..Method::dummy("setEnabled:")
+        };
+        let s = setter.to_string();
+        assert!(s.contains("unsafe fn setEnabled(enabled: bool, );"), "{s}");
+
+        // Instance properties still take `&self`.
+        let getter = Method {
+            result_type: bool_ty,
+            ..Method::dummy("enabled")
+        };
+        let s = getter.to_string();
+        assert!(s.contains("unsafe fn enabled(&self, ) -> bool;"), "{s}");
+    }
+
+    #[test]
+    fn test_variadic_convenience() {
+        let mut methods = [Method::dummy_array_with_objects_count()];
+        assert!(methods[0].variadic_convenience().is_none());
+
+        // `+ (instancetype)arrayWithObjects:(ObjectType)firstObj, ... NS_REQUIRES_NIL_TERMINATION;`
+        let variadic = ("arrayWithObjects:".into(), true, "arrayWithObjects".into());
+        assert!(resolve_variadic_methods(&mut methods, [variadic]).is_empty());
+
+        let s = methods[0].variadic_convenience().unwrap().to_string();
+        assert!(
+            s.contains("/// Convenience for the variadic `arrayWithObjects:`."),
+            "{s}"
+        );
+        assert!(
+            s.contains("unsafe fn arrayWithObjects(objects: &[&ObjectType], ) -> Retained<Self> {"),
+            "{s}"
+        );
+        assert!(s.contains("let count = objects.len();"), "{s}");
+        assert!(
+            s.contains("let objects = NonNull::from(objects).cast();"),
+            "{s}"
+        );
+        assert!(
+            s.contains("unsafe { Self::arrayWithObjects_count(objects, count) }"),
+            "{s}"
+        );
+
+        // The counterpart itself is still emitted as usual.
+        let s = methods[0].to_string();
+        assert!(
+            s.contains("objects: NonNull<NonNull<ObjectType>>, cnt: NSUInteger, )"),
+            "{s}"
+        );
+    }
+
+    #[test]
+    fn test_variadic_without_counterpart() {
+        let mut methods = [Method::dummy_array_with_objects_count()];
+
+        // `+ (instancetype)stringWithFormat:(NSString *)format, ...;`
+        let variadic: (String, bool, String) =
+            ("stringWithFormat:".into(), true, "stringWithFormat".into());
+        let unresolved = resolve_variadic_methods(&mut methods, [variadic.clone()]);
+        // Returned, such that it can be looked up in other declarations.
+        assert_eq!(unresolved, [variadic]);
+        // The counterpart must be of the same kind.
+        let variadic: (String, bool, String) =
+            ("arrayWithObjects:".into(), false, "arrayWithObjects".into());
+        let unresolved = resolve_variadic_methods(&mut methods, [variadic.clone()]);
+        assert_eq!(unresolved, [variadic]);
+
+        assert!(methods[0].variadic_convenience().is_none());
+    }
+}
@@ -10,7 +10,7 @@ use crate::display_helper::FormatterFn;
 use crate::id::{ItemIdentifier, ItemTree};
 use crate::name_translation::cf_no_ref;
 use crate::protocol::ProtocolRef;
-use crate::stmt::{anonymous_record_name, bridged_to};
+use crate::stmt::{anonymous_enum_name, anonymous_record_name, bridged_to};
 use crate::stmt::{parse_superclasses, superclasses_required_items};
 use crate::thread_safety::ThreadSafety;
 use crate::unexposed_attr::UnexposedAttr;
@@ -920,8 +920,23 @@ impl Ty {
             }
             TypeKind::Enum => {
                 let declaration = ty.get_declaration().expect("enum declaration");
+                // Typed enums may be forward-declared, with the body defined
+                // elsewhere (possibly in a different header), e.g.:
+                //
+                //     typedef enum Foo : NSInteger Foo;
+                //     enum Foo : NSInteger { FooA, FooB };
+                //
+                // Resolve to the definition so that the enum is placed where
+                // the actual body is emitted.
+                let declaration = declaration.get_definition().unwrap_or(declaration);
                 Self::Enum {
-                    id: ItemIdentifier::new(&declaration, context),
+                    // Anonymous enums may be named through a typedef, e.g.:
+                    //
+                    //     typedef enum { FooA, FooB } Foo;
+                    id: ItemIdentifier::new_optional(&declaration, context).map_name(|name| {
+                        name.or_else(|| anonymous_enum_name(&declaration))
+                            .unwrap_or_else(|| "UnknownEnum".into())
+                    }),
                     ty: Box::new(Ty::parse(
                         declaration
                             .get_enum_underlying_type()
@@ -2049,6 +2064,33 @@ impl Ty {
         })
     }
 
+    /// Like `behind_pointer`, but additionally marks protocol objects whose
+    /// protocol is sendable as `Send + Sync`, which allows the returned
+    /// object to be moved across threads.
+    ///
+    /// Thread-safe classes already implement `Send` and `Sync`, so nothing
+    /// needs to be done for those. Note though that we always use the
+    /// declared type, so a thread-safe object returned as a thread-unsafe
+    /// superclass (or as `id`) will not be `Send`.
+    ///
+    /// This is not done for arguments, since that would make the method
+    /// more difficult to call.
+    fn behind_pointer_return(&self) -> impl fmt::Display + '_ {
+        FormatterFn(move |f| match self {
+            Self::Pointee(PointeeTy::AnyObject { protocols }) => match &**protocols {
+                [(protocol, thread_safety)] if thread_safety.inferred_sendable() => {
+                    write!(
+                        f,
+                        "ProtocolObject<dyn {} + Send + Sync>",
+                        protocol.id.path()
+                    )
+                }
+                _ => write!(f, "{}", self.behind_pointer()),
+            },
+            _ => write!(f, "{}", self.behind_pointer()),
+        })
+    }
+
     pub(crate) fn method_return(&self) -> impl fmt::Display + '_ {
         FormatterFn(move |f| match self {
             // Don't output anything here.
@@ -2074,9 +2116,13 @@ impl Ty {
                 // NOTE: We return CF types as `Retained` for now, since we
                 // don't have support for the CF wrapper in msg_send! yet.
                 if *nullability == Nullability::NonNull {
-                    write!(f, " -> Retained<{}>", pointee.behind_pointer())
+                    write!(f, " -> Retained<{}>", pointee.behind_pointer_return())
                 } else {
-                    write!(f, " -> Option<Retained<{}>>", pointee.behind_pointer())
+                    write!(
+                        f,
+                        " -> Option<Retained<{}>>",
+                        pointee.behind_pointer_return()
+                    )
                 }
             }
             Self::Primitive(Primitive::C99Bool) => {
@@ -2561,8 +2607,41 @@ impl Ty {
         FormatterFn(move |f| match self {
             Self::Primitive(Primitive::NSInteger) => write!(f, "#[repr(isize)] // NSInteger"),
             Self::Primitive(Primitive::NSUInteger) => write!(f, "#[repr(usize)] // NSUInteger"),
-            Self::Primitive(Primitive::U32) => write!(f, "#[repr(u32)]"),
+            Self::Primitive(Primitive::SChar) => write!(f, "#[repr(i8)] // c_schar"),
+            Self::Primitive(Primitive::UChar) => write!(f, "#[repr(u8)] // c_uchar"),
+            Self::Primitive(Primitive::Short) => write!(f, "#[repr(i16)] // c_short"),
+            Self::Primitive(Primitive::UShort) => write!(f, "#[repr(u16)] // c_ushort"),
             Self::Primitive(Primitive::Int) => write!(f, "#[repr(i32)] // c_int"),
+            Self::Primitive(Primitive::UInt) => write!(f, "#[repr(u32)] // c_uint"),
+            // `#[repr(c_long)]` is not possible, so we match the definition
+            // of `c_long` instead (it is 32-bit on LLP64 targets).
+            Self::Primitive(prim @ (Primitive::Long | Primitive::ULong)) => {
+                let (small, large) = if matches!(prim, Primitive::Long) {
+                    ("i32", "i64")
+                } else {
+                    ("u32", "u64")
+                };
+                let cfg = "any(target_pointer_width = \"32\", windows)";
+                writeln!(f, "#[cfg_attr({cfg}, repr({small}))]")?;
+                write!(f, "#[cfg_attr(not({cfg}), repr({large}))] // {prim}")
+            }
+            Self::Primitive(Primitive::LongLong) => write!(f, "#[repr(i64)] // c_longlong"),
+            Self::Primitive(Primitive::ULongLong) => write!(f, "#[repr(u64)] // c_ulonglong"),
+            Self::Primitive(
+                prim @ (Primitive::I8
+                | Primitive::U8
+                | Primitive::I16
+                | Primitive::U16
+                | Primitive::I32
+                | Primitive::U32
+                | Primitive::I64
+                | Primitive::U64
+                | Primitive::ISize
+                | Primitive::USize),
+            ) => write!(f, "#[repr({})]", prim.as_str()),
+            Self::TypeDef { to, .. } => write!(f, "{}", to.closed_enum_repr()),
+            // `char`'s signedness depends on the target, so we can't know
+            // which repr to use.
             _ => panic!("invalid closed enum repr: {self:?}"),
         })
     }
@@ -2646,9 +2725,13 @@ impl Ty {
         attr: Option<UnexposedAttr>,
         context: &Context<'_>,
     ) -> Self {
+        let mut no_escape = false;
         match attr {
             Some(UnexposedAttr::NoEscape) => {
-                // TODO: Use this if mapping `fn + context ptr` to closure.
+                // Allows blocks to be non-`'static`.
+                //
+                // TODO: Also use this if mapping `fn + context ptr` to closure.
+                no_escape = true;
             }
             Some(UnexposedAttr::ReturnsRetained | UnexposedAttr::ReturnsNotRetained) => {
                 // TODO: Massage this into a lifetime
@@ -2658,7 +2741,8 @@ impl Ty {
             }
             None => {}
         }
-        Self::parse_method_argument(ty, None, None, false, context)
+
+        Self::parse_method_argument(ty, None, None, no_escape, context)
     }
 
     pub(crate) fn parse_function_return(ty: Type<'_>, context: &Context<'_>) -> Self {
@@ -2772,6 +2856,56 @@ impl Ty {
         Self::parse(ty, Lifetime::Unspecified, context)
     }
 
+    /// If this is a pointer to (or C array of) objects, such as the
+    /// `objects` parameter of `arrayWithObjects:count:`, return the type of
+    /// the elements in an equivalent Rust slice, along with an expression
+    /// that converts a slice named `objects` to this type.
+    ///
+    /// The element is always non-null, since `&T` is valid to pass in both
+    /// cases.
+    pub(crate) fn object_array_from_slice(&self) -> Option<(Self, &'static str)> {
+        let (nullability, is_const, pointee) = match self {
+            Self::Pointer {
+                nullability,
+                is_const,
+                lifetime: _,
+                pointee,
+            }
+            | Self::IncompleteArray {
+                nullability,
+                is_const,
+                pointee,
+            } => (nullability, is_const, pointee),
+            _ => return None,
+        };
+        let Self::Pointer {
+            is_const: element_is_const,
+            pointee: element,
+            ..
+        } = &**pointee
+        else {
+            return None;
+        };
+        if !element.is_objc_type() {
+            return None;
+        }
+
+        let element = Self::Pointer {
+            nullability: Nullability::NonNull,
+            is_const: *element_is_const,
+            lifetime: Lifetime::Unspecified,
+            pointee: element.clone(),
+        };
+        let converter = if *nullability == Nullability::NonNull {
+            "NonNull::from(objects).cast()"
+        } else if *is_const {
+            "objects.as_ptr().cast()"
+        } else {
+            "objects.as_ptr().cast_mut().cast()"
+        };
+        Some((element, converter))
+    }
+
     pub(crate) fn is_simple_uint(&self) -> bool {
         matches!(self, Self::Primitive(Primitive::UInt))
     }
@@ -2850,6 +2984,13 @@ impl Ty {
         matches!(self, Self::Struct { id, .. } | Self::Union { id, .. } if id.name == s)
     }
 
+    /// Whether a typedef with the given name to this type would just point
+    /// to a type of the same name (e.g. `typedef enum Foo : NSInteger Foo;`
+    /// or `typedef struct Foo Foo;`), and can thus be omitted.
+    pub(crate) fn is_same_name_as_typedef(&self, typedef_name: &str) -> bool {
+        self.is_enum(typedef_name) || self.is_record(typedef_name)
+    }
+
     pub(crate) fn is_enum_through_typedef(&self) -> bool {
         match self {
             Self::Enum { .. } => true,
@@ -2868,6 +3009,30 @@ impl Ty {
         }
     }
 
+    pub(crate) fn is_number_through_typedef(&self) -> bool {
+        match self {
+            Self::Primitive(
+                Primitive::Void
+                | Primitive::C99Bool
+                | Primitive::F128
+                | Primitive::VaList
+                | Primitive::ObjcBool
+                | Primitive::Imp,
+            ) => false,
+            Self::Primitive(_) => true,
+            Self::TypeDef { to, .. } => to.is_number_through_typedef(),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn is_struct_through_typedef(&self) -> bool {
+        match self {
+            Self::Struct { .. } => true,
+            Self::TypeDef { to, .. } => to.is_struct_through_typedef(),
+            _ => false,
+        }
+    }
+
     /// SIMD is not yet possible in FFI, see:
     /// <https://github.com/rust-lang/rust/issues/63068>
     pub(crate) fn needs_simd(&self) -> bool {
@@ -3128,4 +3293,134 @@ mod tests {
 
         assert_eq!(ty.required_items().collect::<Vec<_>>(), required_items);
     }
+
+    #[test]
+    fn test_forward_declared_typed_enum() {
+        // typedef enum DUMMY0 : NSInteger DUMMY0;
+        let ty = Ty::Enum {
+            id: ItemIdentifier::dummy(0),
+            ty: Box::new(Ty::Primitive(Primitive::NSInteger)),
+        };
+        assert!(ty.is_same_name_as_typedef("DUMMY0"));
+
+        // typedef enum DUMMY0 : NSInteger DUMMY1;
+        assert!(!ty.is_same_name_as_typedef("DUMMY1"));
+
+        // typedef struct DUMMY0 DUMMY0;
+        let ty = Ty::Struct {
+            id: ItemIdentifier::dummy(0),
+            fields: vec![],
+            is_bridged: false,
+        };
+        assert!(ty.is_same_name_as_typedef("DUMMY0"));
+    }
+
+    #[test]
+    fn test_no_escape_block_argument() {
+        let block = |no_escape| Ty::Pointer {
+            nullability: Nullability::NonNull,
+            is_const: false,
+            lifetime: Lifetime::Unspecified,
+            pointee: Box::new(Ty::Pointee(PointeeTy::Block {
+                sendable: None,
+                no_escape,
+                arguments: vec![],
+                result_type: Box::new(Ty::VOID_RESULT),
+            })),
+        };
+
+        assert_eq!(
+            block(true).fn_argument().to_string(),
+            "&block2::DynBlock<dyn Fn() + '_>",
+        );
+        assert_eq!(
+            block(false).fn_argument().to_string(),
+            "&block2::DynBlock<dyn Fn()>",
+        );
+    }
+
+    #[test]
+    fn test_sendable_return() {
+        let sendable = ThreadSafety::from_string("Sendable");
+        let ptr = |pointee| Ty::Pointer {
+            nullability: Nullability::NonNull,
+            is_const: false,
+            lifetime: Lifetime::Unspecified,
+            pointee: Box::new(Ty::Pointee(pointee)),
+        };
+        let protocol = |thread_safety| {
+            ptr(PointeeTy::AnyObject {
+                protocols: vec![(
+                    ProtocolRef {
+                        id: ItemIdentifier::dummy(0),
+                        super_protocols: vec![],
+                    },
+                    thread_safety,
+                )],
+            })
+        };
+
+        // `id<SendableProtocol>` is `Send + Sync` when returned.
+        assert_eq!(
+            protocol(sendable).method_return().to_string(),
+            " -> Retained<ProtocolObject<dyn DUMMY0 + Send + Sync>>",
+        );
+        assert_eq!(
+            protocol(ThreadSafety::dummy()).method_return().to_string(),
+            " -> Retained<ProtocolObject<dyn DUMMY0>>",
+        );
+        // But arguments stay as-is.
+        assert_eq!(
+            protocol(sendable).method_argument().to_string(),
+            "&ProtocolObject<dyn DUMMY0>",
+        );
+
+        // Factory methods on thread-safe classes return the class itself,
+        // which implements `Send` and `Sync` directly.
+        let class = ptr(PointeeTy::Class {
+            id: ItemIdentifier::dummy(1),
+            thread_safety: sendable,
+            superclasses: vec![],
+            generics: vec![],
+            protocols: vec![],
+        });
+        assert_eq!(class.method_return().to_string(), " -> Retained<DUMMY1>");
+    }
+
+    #[test]
+    fn test_closed_enum_repr() {
+        let repr = |ty: Ty| ty.closed_enum_repr().to_string();
+
+        // NS_ENUM(int32_t, ...)
+        assert_eq!(repr(Ty::Primitive(Primitive::I32)), "#[repr(i32)]");
+        // NS_ENUM(uint8_t, ...)
+        assert_eq!(repr(Ty::Primitive(Primitive::U8)), "#[repr(u8)]");
+        assert_eq!(repr(Ty::Primitive(Primitive::Int)), "#[repr(i32)] // c_int");
+        assert_eq!(
+            repr(Ty::Primitive(Primitive::NSInteger)),
+            "#[repr(isize)] // NSInteger",
+        );
+        // `long` is 32-bit on LLP64 targets.
+        assert_eq!(
+            repr(Ty::Primitive(Primitive::Long)),
+            "#[cfg_attr(any(target_pointer_width = \"32\", windows), repr(i32))]\n\
+             #[cfg_attr(not(any(target_pointer_width = \"32\", windows)), repr(i64))] // c_long",
+        );
+        // typedef int32_t DUMMY0;
+        // NS_ENUM(DUMMY0, ...)
+        let ty = Ty::TypeDef {
+            id: ItemIdentifier::dummy(0),
+            to: Box::new(Ty::Primitive(Primitive::I32)),
+        };
+        assert_eq!(repr(ty), "#[repr(i32)]");
+    }
+
+    #[test]
+    #[should_panic = "invalid closed enum repr"]
+    fn test_closed_enum_repr_invalid() {
+        // `char` has target-dependent signedness.
+        let _ = Ty::Primitive(Primitive::Char)
+            .closed_enum_repr()
+            .to_string();
+    }
 }
@@ -24,11 +24,13 @@ use crate::id::ItemIdentifier;
 use crate::id::ItemTree;
 use crate::id::Location;
 use crate::immediate_children;
-use crate::method::{apply_type_override, handle_reserved, Method};
+use crate::method::{
+    apply_type_override, default_fn_name, handle_reserved, resolve_variadic_methods, Method,
+};
 use crate::name_translation::{enum_prefix, split_words};
 use crate::protocol::parse_direct_protocols;
 use crate::protocol::ProtocolRef;
-use crate::rust_type::Ty;
+use crate::rust_type::{Primitive, Ty};
 use crate::thread_safety::ThreadSafety;
 use crate::unexposed_attr::UnexposedAttr;
 
@@ -118,16 +120,84 @@ pub(crate) fn parse_superclasses<'ty>(
     }
 }
 
-fn parse_class_generics(entity: &Entity<'_>, _context: &Context<'_>) -> Vec<String> {
+/// A generic parameter on an Objective-C class, along with its bound.
+///
+/// E.g. `UnitType` in `NSMeasurement<UnitType: NSUnit *>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenericType {
+    pub name: String,
+    pub bound: Option<GenericBound>,
+}
+
+/// The bound on a generic parameter.
+///
+/// The parameter defaults to the bound on the class declaration, so that the
+/// unspecialized class is still usable with the (bounded) methods.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GenericBound {
+    /// `UnitType: NSUnit *`.
+    Class(ItemIdentifier),
+    /// `ObjectType: id<NSCopying>`.
+    Protocol(ItemIdentifier),
+}
+
+impl GenericType {
+    fn required_items(&self) -> impl Iterator<Item = ItemTree> + '_ {
+        self.bound.iter().map(|bound| match bound {
+            GenericBound::Class(id) | GenericBound::Protocol(id) => ItemTree::from_id(id.clone()),
+        })
+    }
+
+    /// The type that the parameter defaults to.
+    fn default_ty(&self) -> impl Display + '_ {
+        FormatterFn(move |f| match &self.bound {
+            Some(GenericBound::Class(id)) => write!(f, "{}", id.path()),
+            Some(GenericBound::Protocol(id)) => write!(f, "ProtocolObject<dyn {}>", id.path()),
+            None => write!(f, "AnyObject"),
+        })
+    }
+}
+
+fn parse_class_generics(entity: &Entity<'_>, context: &Context<'_>) -> Vec<GenericType> {
     let mut generics = Vec::new();
 
     #[allow(clippy::single_match)]
     immediate_children(entity, |entity, _span| match entity.get_kind() {
         EntityKind::TemplateTypeParameter => {
-            // TODO: Generics with bounds (like NSMeasurement<UnitType: NSUnit *>)
-            // let ty = entity.get_type().expect("template type");
             let name = entity.get_name().expect("template name");
-            generics.push(name);
+            let mut superclass = None;
+            let mut protocols = Vec::new();
+
+            // Bounds like `UnitType: NSUnit *` or `ObjectType: id<NSCopying>`.
+            immediate_children(&entity, |entity, _span| match entity.get_kind() {
+                EntityKind::ObjCClassRef => {
+                    let entity = entity
+                        .get_reference()
+                        .expect("ObjCClassRef to reference entity");
+                    superclass = Some(ItemIdentifier::new(&entity, context));
+                }
+                EntityKind::ObjCProtocolRef => {
+                    let entity = entity
+                        .get_reference()
+                        .expect("ObjCProtocolRef to reference entity");
+                    protocols.push(ItemIdentifier::new(&entity, context));
+                }
+                _ => {}
+            });
+
+            // We need a single type to default to that satisfies the bound,
+            // which isn't possible for bounds like `NSUnit<NSCopying> *`.
+            let bound = match (superclass, &*protocols) {
+                (Some(superclass), []) => Some(GenericBound::Class(superclass)),
+                (None, [protocol]) => Some(GenericBound::Protocol(protocol.clone())),
+                (None, []) => None,
+                (superclass, protocols) => {
+                    warn!(?name, ?superclass, ?protocols, "unsupported generic bound");
+                    None
+                }
+            };
+
+            generics.push(GenericType { name, bound });
         }
         _ => {}
     });
@@ -135,6 +205,94 @@ fn parse_class_generics(entity: &Entity<'_>, _context: &Context<'_>) -> Vec<Stri
     generics
 }
 
+/// Find the `NS_TYPED_ENUM` or `NS_TYPED_EXTENSIBLE_ENUM` attribute on the
+/// typedef that the given type refers to, if any.
+fn typed_enum_kind(ty: clang::Type<'_>, context: &Context<'_>) -> Option<UnexposedAttr> {
+    let ty = if let Some(true) = ty.is_elaborated() {
+        ty.get_elaborated_type().expect("elaborated")
+    } else {
+        ty
+    };
+    if ty.get_kind() != clang::TypeKind::Typedef {
+        return None;
+    }
+    let declaration = ty.get_declaration().expect("typedef declaration");
+
+    let mut kind = None;
+    immediate_children(&declaration, |entity, _span| {
+        if entity.get_kind() == EntityKind::UnexposedAttr {
+            if let Some(attr @ (UnexposedAttr::TypedEnum | UnexposedAttr::TypedExtensibleEnum)) =
+                UnexposedAttr::parse(&entity, context)
+            {
+                kind = Some(attr);
+            }
+        }
+    });
+    kind
+}
+
+/// Typed enums over numbers are emitted as a newtype, while typed enums over
+/// objects (such as `NSString`) are still emitted as a type alias, since the
+/// constants for those are `static` references to the object.
+fn typed_enum_is_newtype(kind: Option<&UnexposedAttr>, ty: &Ty) -> bool {
+    matches!(
+        kind,
+        Some(UnexposedAttr::TypedEnum | UnexposedAttr::TypedExtensibleEnum)
+    ) && ty.is_number_through_typedef()
+}
+
+/// Matches up properties with the accessor methods that the compiler
+/// declares for them.
+#[derive(Debug, Default)]
+struct PropertyAccessors {
+    /// Accessors of seen properties, which the compiler will declare an
+    /// implicit method for.
+    pending: HashSet<(bool, String)>,
+    /// Methods that have been declared without a property.
+    declared: HashSet<(bool, String)>,
+}
+
+impl PropertyAccessors {
+    /// Whether the method is an accessor of a previously seen property, and
+    /// should thus be emitted from that instead.
+    fn is_accessor(&mut self, is_class: bool, selector: String) -> bool {
+        let key = (is_class, selector);
+        if self.pending.remove(&key) {
+            true
+        } else {
+            self.declared.insert(key);
+            false
+        }
+    }
+
+    /// Register the accessors of a property.
+    ///
+    /// The setter must only be given if the property's attributes say that
+    /// it has one (i.e. it isn't `readonly`), since otherwise, a setter
+    /// method declared alongside it is not generated from the property.
+    ///
+    /// Returns the accessors that have already been declared explicitly, in
+    /// which case the compiler doesn't declare them again; the property
+    /// supersedes those methods.
+    fn add_property(
+        &mut self,
+        is_class: bool,
+        getter: Option<&str>,
+        setter: Option<&str>,
+    ) -> Vec<String> {
+        let mut superseded = Vec::new();
+        for selector in getter.into_iter().chain(setter) {
+            let key = (is_class, selector.to_string());
+            if self.declared.remove(&key) {
+                superseded.push(key.1);
+            } else if !self.pending.insert(key) {
+                error!(is_class, selector, "already existing property accessor");
+            }
+        }
+        superseded
+    }
+}
+
 /// Deduplicate methods that are autogenerated from properties.
 ///
 /// Guaranteed to only contain `ObjCInstanceMethodDecl`, `ObjCClassMethodDecl`
@@ -147,7 +305,7 @@ pub(crate) fn method_or_property_entities<'tu>(
 
     // Track seen properties, so that when methods are autogenerated by the
     // compiler from them, we can skip them
-    let mut properties = HashSet::new();
+    let mut accessors = PropertyAccessors::default();
 
     immediate_children(entity, |entity, _span| match entity.get_kind() {
         EntityKind::ObjCInstanceMethodDecl | EntityKind::ObjCClassMethodDecl => {
@@ -158,39 +316,42 @@ pub(crate) fn method_or_property_entities<'tu>(
                 return;
             }
 
-            if !properties.remove(&(is_class, selector)) {
+            if !accessors.is_accessor(is_class, selector) {
                 entities.push(entity);
             }
         }
         EntityKind::ObjCPropertyDecl => {
-            entities.push(entity);
-
             let partial = Method::partial_property(entity);
 
             // TODO: Use `get_overridden_methods` to deduplicate property
             // getters (when declared on both immutable and mutable class).
 
-            if !get_data(&partial.getter_sel).skipped
-                && !properties.insert((partial.is_class, partial.getter_sel.clone()))
-            {
-                error!(?partial, ?entity, "already existing getter property");
-            }
+            let getter = Some(&*partial.getter_sel).filter(|sel| !get_data(*sel).skipped);
+            let setter = partial
+                .setter_sel
+                .as_deref()
+                .filter(|sel| !get_data(*sel).skipped);
+
+            let superseded = accessors.add_property(partial.is_class, getter, setter);
+            entities.retain(|entity| {
+                let is_class = match entity.get_kind() {
+                    EntityKind::ObjCInstanceMethodDecl => false,
+                    EntityKind::ObjCClassMethodDecl => true,
+                    _ => return true,
+                };
+                let selector = entity.get_name().expect("method selector");
+                !(is_class == partial.is_class && superseded.contains(&selector))
+            });
 
-            if let Some(setter_sel) = &partial.setter_sel {
-                if !get_data(setter_sel).skipped
-                    && !properties.insert((partial.is_class, setter_sel.clone()))
-                {
-                    error!(?partial, ?entity, "already existing setter property");
-                }
-            }
+            entities.push(entity);
         }
         _ => {}
     });
 
-    if !properties.is_empty() {
+    if !accessors.pending.is_empty() {
         error!(
             ?entities,
-            ?properties,
+            properties = ?accessors.pending,
             "did not properly add methods to properties"
         );
     }
@@ -204,9 +365,10 @@ fn parse_methods(
     thread_safety: &ThreadSafety,
     is_pub: bool,
     context: &Context<'_>,
-) -> (Vec<Method>, Vec<String>) {
+) -> (Vec<Method>, Vec<String>, Vec<(String, bool, String)>) {
     let mut methods = Vec::new();
     let mut designated_initializers = Vec::new();
+    let mut variadic = Vec::new();
 
     for entity in method_or_property_entities(entity, &get_data) {
         match entity.get_kind() {
@@ -215,6 +377,19 @@ fn parse_methods(
 
                 let data = get_data(&selector);
 
+                // Variadic methods taking a nil-terminated list of objects,
+                // such as `arrayWithObjects:`, are emitted as a convenience
+                // that forwards to a counterpart taking an array and a count.
+                if entity.is_variadic() && !data.skipped && selector.matches(':').count() == 1 {
+                    let is_class = entity.get_kind() == EntityKind::ObjCClassMethodDecl;
+                    let fn_name = data
+                        .renamed
+                        .clone()
+                        .unwrap_or_else(|| default_fn_name(&selector));
+                    variadic.push((selector, is_class, fn_name));
+                    continue;
+                }
+
                 if let Some((designated_initializer, method)) = Method::parse_method(
                     entity,
                     data,
@@ -259,7 +434,9 @@ fn parse_methods(
         }
     }
 
-    (methods, designated_initializers)
+    let variadic = resolve_variadic_methods(&mut methods, variadic);
+
+    (methods, designated_initializers, variadic)
 }
 
 /// Takes one of:
@@ -272,7 +449,7 @@ fn verify_objc_decl(entity: &Entity<'_>, _context: &Context<'_>) {
     immediate_children(entity, |entity, _span| {
         match (entity.get_kind(), parent_kind) {
             (EntityKind::ObjCExplicitProtocolImpl, EntityKind::ObjCProtocolDecl) => {
-                // TODO NS_PROTOCOL_REQUIRES_EXPLICIT_IMPLEMENTATION
+                // Parsed in `Stmt::ProtocolDecl`.
             }
             (
                 EntityKind::ObjCIvarDecl | EntityKind::StructDecl | EntityKind::UnionDecl,
@@ -319,7 +496,7 @@ fn verify_objc_decl(entity: &Entity<'_>, _context: &Context<'_>) {
                 // Already exposed as entity.get_visibility()
             }
             (EntityKind::ObjCException, EntityKind::ObjCInterfaceDecl) => {
-                // Maybe useful for knowing when to implement `Error` for the type
+                // Parsed in `Stmt::ClassDecl`.
             }
             (EntityKind::UnexposedAttr, _) => {
                 // Parsed in parse_attributes
@@ -396,6 +573,75 @@ pub(crate) fn anonymous_record_name(entity: &Entity<'_>, context: &Context<'_>)
     Some(format!("{}_{}", parent_id.name, field_name))
 }
 
+/// Bitfields cannot be represented in Rust, so instead we store each run of
+/// consecutive bitfields in a private byte array that spans until the next
+/// field (or the end of the record).
+///
+/// This ensures that the size and field offsets match C. Byte arrays have an
+/// alignment of 1, but if the bitfields' underlying type raised the
+/// alignment of the record, that is preserved with `#[repr(align(...))]`.
+fn flush_bitfields(
+    pending: &mut Vec<(usize, u8)>,
+    bitfields: &mut BTreeMap<String, Vec<u8>>,
+    fields: &mut Vec<(String, Documentation, Ty)>,
+    end_byte: usize,
+) {
+    let Some(&(first_offset, _)) = pending.first() else {
+        return;
+    };
+    let name = format!("_bitfield_{}", bitfields.len() + 1);
+    let start_byte = first_offset / 8;
+    let ty = Ty::Array {
+        element_type: Box::new(Ty::Primitive(Primitive::U8)),
+        num_elements: end_byte - start_byte,
+    };
+    let widths = pending.drain(..).map(|(_, width)| width).collect();
+    bitfields.insert(name.clone(), widths);
+    fields.push((name, Documentation::empty(), ty));
+}
+
+/// The traits to derive on a struct or union.
+///
+/// Unlike for classes (see [`Derives`]), we never derive `Eq` or `Hash` on
+/// records, since they may contain floating-point fields, either directly
+/// (such as `CGPoint`) or through nested records (such as `CGRect`).
+fn record_derives(is_union: bool, fields: &[(String, Documentation, Ty)]) -> &'static str {
+    if is_union || fields.iter().any(|(_, _, field)| field.contains_union()) {
+        // Unions cannot be compared or debug-printed without knowing which
+        // field is active.
+        "Clone, Copy"
+    } else {
+        "Clone, Copy, Debug, PartialEq"
+    }
+}
+
+/// The name of an anonymous enum that is declared inside a typedef.
+///
+/// ```c
+/// typedef enum { MyEnumA, MyEnumB } MyEnum;
+/// ```
+///
+/// Clang doesn't give such enums a name, so we use the name of the typedef.
+pub(crate) fn anonymous_enum_name(entity: &Entity<'_>) -> Option<String> {
+    // The typedef's name follows directly after the enum's closing brace, so
+    // look up the entity there (instead of searching through all of the
+    // enum's siblings, which is slow when done for every enum).
+    let end = entity.get_range()?.get_end().get_file_location();
+    let typedef = end.file?.get_offset_location(end.offset).get_entity()?;
+
+    if typedef.get_kind() != EntityKind::TypedefDecl {
+        return None;
+    }
+    let underlying = typedef
+        .get_typedef_underlying_type()?
+        .get_declaration()?
+        .get_canonical_entity();
+    if underlying != entity.get_canonical_entity() {
+        return None;
+    }
+    typedef.get_name()
+}
+
 pub(crate) fn superclasses_required_items<'a, I>(
     superclasses: I,
 ) -> impl Iterator<Item = ItemTree> + 'a
@@ -467,7 +713,7 @@ pub enum Stmt {
     /// extern_class!
     ClassDecl {
         id: ItemIdentifier,
-        generics: Vec<String>,
+        generics: Vec<GenericType>,
         objc_name: String,
         availability: Availability,
         /// Superclass + generics
@@ -479,6 +725,8 @@ pub enum Stmt {
         sendable: bool,
         documentation: Documentation,
         bridged_to: Option<ItemIdentifier>,
+        /// Marked with `__attribute__((objc_exception))`.
+        is_exception: bool,
     },
     /// @interface class_name (category_name) <protocols*>
     /// ->
@@ -489,9 +737,12 @@ pub enum Stmt {
         cls: ItemIdentifier,
         cls_superclasses: Vec<ItemIdentifier>,
         source_superclass: Option<ItemIdentifier>,
-        cls_generics: Vec<String>,
+        cls_generics: Vec<GenericType>,
         category_name: Option<String>,
         methods: Vec<Method>,
+        /// Variadic methods whose counterpart wasn't found in this
+        /// declaration, see [`resolve_variadic_methods`].
+        variadic_methods: Vec<(String, bool, String)>,
         documentation: Option<Documentation>,
     },
     /// @interface class_name (category_name)
@@ -506,7 +757,7 @@ pub enum Stmt {
         availability: Availability,
         cls: ItemIdentifier,
         cls_superclasses: Vec<ItemIdentifier>,
-        cls_generics: Vec<String>,
+        cls_generics: Vec<GenericType>,
         methods: Vec<Method>,
         documentation: Documentation,
     },
@@ -521,6 +772,8 @@ pub enum Stmt {
         methods: Vec<Method>,
         required_sendable: bool,
         required_mainthreadonly: bool,
+        /// `NS_PROTOCOL_REQUIRES_EXPLICIT_IMPLEMENTATION`.
+        requires_explicit_impl: bool,
         documentation: Documentation,
     },
     /// @interface ty: _ <protocols*>
@@ -532,7 +785,7 @@ pub enum Stmt {
         cls_counterpart: Counterpart,
         protocol: ItemIdentifier,
         protocol_super_protocols: Vec<ProtocolRef>,
-        generics: Vec<String>,
+        generics: Vec<GenericType>,
         availability: Availability,
     },
     /// struct name {
@@ -563,6 +816,8 @@ pub enum Stmt {
         natural_align: usize,
         documentation: Documentation,
         is_union: bool,
+        /// The widths of the bitfields that make up the `_bitfield_*` fields.
+        bitfields: BTreeMap<String, Vec<u8>>,
     },
     /// typedef NS_OPTIONS(type, name) {
     ///     variants*
@@ -610,10 +865,15 @@ pub enum Stmt {
         availability: Availability,
         ty: Ty,
         value: Option<Expr>,
+        /// Whether `ty` is a newtype emitted for `NS_TYPED_ENUM` or
+        /// `NS_TYPED_EXTENSIBLE_ENUM`.
+        typed_enum: bool,
         documentation: Documentation,
     },
     /// extern ret name(args*);
     ///
+    /// extern ret name(args*, ...);
+    ///
     /// static inline ret name(args*) {
     ///     body
     /// }
@@ -623,10 +883,14 @@ pub enum Stmt {
         link_name: String,
         availability: Availability,
         arguments: Vec<(String, Ty)>,
+        /// Whether the function takes a variable number of arguments after
+        /// the fixed `arguments`.
+        variadic: bool,
         first_arg_is_self: bool,
         result_type: Ty,
-        // Some -> inline function.
-        body: Option<()>,
+        /// `Some` for inline functions, with the translated body if it was
+        /// simple enough to be translated.
+        body: Option<Option<Expr>>,
         safe: bool,
         must_use: bool,
         abi: Abi,
@@ -748,9 +1012,16 @@ impl Stmt {
 
                 let counterpart = data.counterpart.clone();
 
+                let mut is_exception = false;
+                immediate_children(entity, |entity, _span| {
+                    if entity.get_kind() == EntityKind::ObjCException {
+                        is_exception = true;
+                    }
+                });
+
                 verify_objc_decl(entity, context);
                 let generics = parse_class_generics(entity, context);
-                let (methods, designated_initializers) = parse_methods(
+                let (methods, designated_initializers, variadic_methods) = parse_methods(
                     entity,
                     |name| data.methods.get(name).cloned().unwrap_or_default(),
                     &thread_safety,
@@ -791,7 +1062,7 @@ impl Stmt {
                         // Explicitly keep going, even if the class itself is skipped
                         // if superclass_data.skipped
 
-                        let (mut methods, _) = parse_methods(
+                        let (mut methods, _, _) = parse_methods(
                             entity,
                             |name| {
                                 let data = data.methods.get(name).cloned().unwrap_or_default();
@@ -822,6 +1093,7 @@ impl Stmt {
                                 cls_generics: generics.clone(),
                                 category_name: None,
                                 methods,
+                                variadic_methods: vec![],
                                 documentation: None,
                             })
                         }
@@ -837,6 +1109,7 @@ impl Stmt {
                     cls_generics: generics.clone(),
                     category_name: None,
                     methods,
+                    variadic_methods,
                     documentation: None,
                 };
 
@@ -855,6 +1128,7 @@ impl Stmt {
                     sendable: thread_safety.explicit_sendable(),
                     documentation,
                     bridged_to: data.bridged_to.clone(),
+                    is_exception,
                 })
                 .chain(protocols.into_iter().map(|(p, entity)| Self::ProtocolImpl {
                     location: id.location().clone(),
@@ -923,7 +1197,16 @@ impl Stmt {
                     .collect();
 
                 verify_objc_decl(entity, context);
-                let generics = parse_class_generics(entity, context);
+                // Categories don't repeat the bounds of the generics, so take
+                // those from the class (the names may differ).
+                let generics: Vec<_> = parse_class_generics(entity, context)
+                    .into_iter()
+                    .zip(parse_class_generics(&cls_entity, context))
+                    .map(|(generic, cls_generic)| GenericType {
+                        name: generic.name,
+                        bound: cls_generic.bound,
+                    })
+                    .collect();
 
                 let protocols = parse_direct_protocols(entity, context);
                 let protocols: BTreeMap<_, _> = protocols
@@ -953,7 +1236,7 @@ impl Stmt {
 
                     // extern_methods!
 
-                    let (methods, designated_initializers) = parse_methods(
+                    let (methods, designated_initializers, variadic_methods) = parse_methods(
                         entity,
                         |name| cls_data.methods.get(name).cloned().unwrap_or_default(),
                         &cls_thread_safety,
@@ -978,7 +1261,7 @@ impl Stmt {
                             .unwrap_or_else(|| StmtData::empty());
                         assert!(!subclass_data.skipped.unwrap_or(false));
 
-                        let (mut methods, _) = parse_methods(
+                        let (mut methods, _, _) = parse_methods(
                             entity,
                             |name| {
                                 let data = cls_data.methods.get(name).cloned().unwrap_or_default();
@@ -1007,6 +1290,7 @@ impl Stmt {
                                 cls_generics: generics.clone(),
                                 category_name: category.name.clone(),
                                 methods,
+                                variadic_methods: vec![],
                                 documentation: Some(documentation.clone()),
                             })
                         }
@@ -1023,6 +1307,7 @@ impl Stmt {
                         cls_generics: generics.clone(),
                         category_name: category.name.clone(),
                         methods,
+                        variadic_methods,
                         documentation: Some(documentation),
                     })
                     .chain(extra_methods)
@@ -1060,7 +1345,7 @@ impl Stmt {
                         }
                     }
 
-                    let (methods, designated_initializers) = parse_methods(
+                    let (methods, designated_initializers, variadic) = parse_methods(
                         entity,
                         |name| cls_data.methods.get(name).cloned().unwrap_or_default(),
                         &cls_thread_safety,
@@ -1068,6 +1353,12 @@ impl Stmt {
                         context,
                     );
 
+                    // The counterparts live in another crate, so we can't
+                    // emit conveniences for these.
+                    for (selector, _, _) in variadic {
+                        debug!(?selector, "can't handle variadic method");
+                    }
+
                     if !designated_initializers.is_empty() {
                         warn!(
                             ?designated_initializers,
@@ -1111,7 +1402,7 @@ impl Stmt {
                 let thread_safety = ThreadSafety::from_decl(entity, context);
 
                 verify_objc_decl(entity, context);
-                let (methods, designated_initializers) = parse_methods(
+                let (methods, designated_initializers, variadic) = parse_methods(
                     entity,
                     |name| data.methods.get(name).cloned().unwrap_or_default(),
                     &thread_safety,
@@ -1119,6 +1410,10 @@ impl Stmt {
                     context,
                 );
 
+                for (selector, _, _) in variadic {
+                    debug!(?selector, "can't handle variadic method");
+                }
+
                 if !designated_initializers.is_empty() {
                     warn!(
                         ?designated_initializers,
@@ -1126,6 +1421,13 @@ impl Stmt {
                     )
                 }
 
+                let mut requires_explicit_impl = false;
+                immediate_children(entity, |entity, _span| {
+                    if entity.get_kind() == EntityKind::ObjCExplicitProtocolImpl {
+                        requires_explicit_impl = true;
+                    }
+                });
+
                 vec![Self::ProtocolDecl {
                     id,
                     objc_name,
@@ -1134,6 +1436,7 @@ impl Stmt {
                     methods,
                     required_sendable: thread_safety.explicit_sendable(),
                     required_mainthreadonly: thread_safety.explicit_mainthreadonly(),
+                    requires_explicit_impl,
                     documentation,
                 }]
             }
@@ -1197,8 +1500,11 @@ impl Stmt {
 
                 // No need to output a typedef if it'll just point to the same thing.
                 //
+                // This is also the case for forward-declared typed enums,
+                // the body of those are emitted with the `EnumDecl`.
+                //
                 // TODO: We're discarding a slight bit of availability data this way.
-                if ty.is_enum(&c_name) || ty.is_record(&c_name) {
+                if ty.is_same_name_as_typedef(&c_name) {
                     return vec![];
                 }
 
@@ -1335,7 +1641,12 @@ impl Stmt {
                 let mut sendable = None;
                 let mut packed = false;
                 let align = ty.get_alignof().expect("alignment of record type");
+                let size = ty.get_sizeof().expect("size of record type");
                 let mut natural_align = 0;
+                let mut bitfields = BTreeMap::new();
+                // The bit offset and width of each bitfield in the current
+                // run of bitfields.
+                let mut pending_bitfields: Vec<(usize, u8)> = Vec::new();
 
                 let mut res = vec![];
 
@@ -1355,6 +1666,31 @@ impl Stmt {
                         let _span = debug_span!("field", name).entered();
 
                         let ty = entity.get_type().expect("struct/union field type");
+
+                        if entity.is_bit_field() {
+                            let width = entity.get_bit_field_width().expect("bitfield width");
+                            let offset = entity.get_offset_of_field().expect("bitfield offset");
+                            pending_bitfields.push((offset, width as u8));
+                            if is_union {
+                                // Union members all start at offset 0.
+                                flush_bitfields(
+                                    &mut pending_bitfields,
+                                    &mut bitfields,
+                                    &mut fields,
+                                    size,
+                                );
+                            }
+                            return;
+                        }
+
+                        let offset = entity.get_offset_of_field().expect("field offset");
+                        flush_bitfields(
+                            &mut pending_bitfields,
+                            &mut bitfields,
+                            &mut fields,
+                            offset / 8,
+                        );
+
                         let field_align = ty.get_alignof().unwrap();
                         if align < field_align {
                             // Similar to what bindgen does, we cannot detect
@@ -1368,10 +1704,6 @@ impl Stmt {
 
                         let ty = Ty::parse_record_field(ty, context);
 
-                        if entity.is_bit_field() {
-                            error!("unsound struct/union bitfield");
-                        }
-
                         let documentation = Documentation::from_entity(&entity, context);
                         fields.push((name, documentation, ty))
                     }
@@ -1392,6 +1724,7 @@ impl Stmt {
                     }
                     _ => error!(?entity, "unknown struct/union child"),
                 });
+                flush_bitfields(&mut pending_bitfields, &mut bitfields, &mut fields, size);
 
                 if fields.iter().any(|(_, _, field_ty)| field_ty.needs_simd()) {
                     debug!("simd types are not yet possible in struct/union");
@@ -1409,6 +1742,7 @@ impl Stmt {
                     natural_align,
                     documentation,
                     is_union,
+                    bitfields,
                 });
 
                 res
@@ -1420,6 +1754,12 @@ impl Stmt {
                     return vec![];
                 }
 
+                // Name anonymous enums declared in a typedef after the
+                // typedef, similar to what we do for anonymous records. The
+                // typedef itself is then omitted, since it'd point to an
+                // enum with the same name.
+                let id = id.map_name(|name| name.or_else(|| anonymous_enum_name(entity)));
+
                 let ty = entity.get_enum_underlying_type().expect("enum type");
                 let mut ty = Ty::parse_enum(ty, context);
                 let is_signed = ty.is_signed().unwrap_or_else(|| {
@@ -1523,6 +1863,14 @@ impl Stmt {
                     _ => error!("unknown"),
                 });
 
+                if data.closed {
+                    if kind == Some(UnexposedAttr::Enum) {
+                        kind = Some(UnexposedAttr::ClosedEnum);
+                    } else {
+                        error!(?kind, "only NS_ENUM can be marked as closed");
+                    }
+                }
+
                 if id.name.is_none() {
                     // Availability propagates to the variants automatically
                     let _ = availability;
@@ -1604,22 +1952,24 @@ impl Stmt {
                     _ => error!(?id, ?entity, "unknown vardecl child"),
                 });
 
+                let typed_enum = typed_enum_is_newtype(
+                    typed_enum_kind(entity.get_type().expect("var type"), context).as_ref(),
+                    &ty,
+                );
+
                 vec![Self::VarDecl {
                     id: id.require_name(),
                     link_name: c_name.unwrap(),
                     availability,
                     ty,
                     value,
+                    typed_enum,
                     documentation,
                 }]
             }
             EntityKind::FunctionDecl => {
                 let c_name = c_name.unwrap();
-
-                if entity.is_variadic() {
-                    debug!(?c_name, "can't handle variadic function");
-                    return vec![];
-                }
+                let variadic = entity.is_variadic();
 
                 let result_type = entity.get_result_type().expect("function result type");
                 let mut result_type = Ty::parse_function_return(result_type, context);
@@ -1635,6 +1985,7 @@ impl Stmt {
                 }
 
                 let mut returns_retained = follows_create_rule(&c_name);
+                let mut body_entity = None;
 
                 immediate_children(entity, |entity, _span| match entity.get_kind() {
                     EntityKind::UnexposedAttr => {
@@ -1694,6 +2045,9 @@ impl Stmt {
                     EntityKind::VisibilityAttr => {
                         // CG_EXTERN or UIKIT_EXTERN
                     }
+                    EntityKind::CompoundStmt => {
+                        body_entity = Some(entity);
+                    }
                     _ => error!("unknown"),
                 });
 
@@ -1734,7 +2088,17 @@ impl Stmt {
                 }
 
                 let body = if entity.is_inline_function() {
-                    Some(())
+                    // Only translate bodies that work on plain numbers and
+                    // structs, those are the ones we can express safely.
+                    let simple_types = result_type.is_number_through_typedef()
+                        && arguments.iter().all(|(_, arg_ty)| {
+                            arg_ty.is_number_through_typedef() || arg_ty.is_struct_through_typedef()
+                        });
+                    Some(
+                        body_entity
+                            .filter(|_| simple_types)
+                            .and_then(|body| Expr::parse_inline_fn_body(&body, context)),
+                    )
                 } else {
                     None
                 };
@@ -1745,6 +2109,7 @@ impl Stmt {
                     link_name,
                     availability,
                     arguments,
+                    variadic,
                     // May be changed by global analysis.
                     first_arg_is_self: false,
                     result_type,
@@ -1897,9 +2262,15 @@ impl Stmt {
             Self::EnumDecl { id, .. } => Some(id.clone()),
             Self::ConstDecl { id, .. } => Some(id.clone()),
             Self::VarDecl { id, .. } => Some(id.clone()),
-            Self::FnDecl { id, body: None, .. } => Some(id.clone()),
+            Self::FnDecl {
+                id,
+                body: None | Some(Some(_)),
+                ..
+            } => Some(id.clone()),
             // TODO
-            Self::FnDecl { body: Some(_), .. } => None,
+            Self::FnDecl {
+                body: Some(None), ..
+            } => None,
             Self::FnGetTypeId { .. } => None, // Emits a trait impl
             Self::AliasDecl { id, .. } => Some(id.clone()),
             Self::OpaqueDecl { id, .. } => Some(id.clone()),
@@ -1940,27 +2311,36 @@ impl Stmt {
     /// Items required by the statement at the top-level.
     pub(crate) fn required_items(&self) -> impl Iterator<Item = ItemTree> {
         let items: Vec<ItemTree> = match self {
-            Self::ClassDecl { superclasses, .. } => iter::once(ItemTree::objc("__macros__"))
+            Self::ClassDecl {
+                superclasses,
+                generics,
+                ..
+            } => iter::once(ItemTree::objc("__macros__"))
                 .chain(superclasses_required_items(
                     superclasses.iter().map(|(s, _)| s.clone()),
                 ))
+                .chain(generics.iter().flat_map(GenericType::required_items))
                 .collect(),
             Self::ExternMethods {
                 cls,
                 cls_superclasses,
+                cls_generics,
                 ..
-            } => vec![
+            } => [
                 ItemTree::objc("__macros__"),
                 ItemTree::new(
                     cls.clone(),
                     superclasses_required_items(cls_superclasses.iter().cloned()),
                 ),
-            ],
-            // Intentionally doesn't require anything, the impl itself is
+            ]
+            .into_iter()
+            .chain(cls_generics.iter().flat_map(GenericType::required_items))
+            .collect(),
+            // Intentionally doesn't require the class, the impl itself is
             // cfg-gated
-            Self::ExternCategory { .. } => {
-                vec![ItemTree::objc("__macros__")]
-            }
+            Self::ExternCategory { cls_generics, .. } => iter::once(ItemTree::objc("__macros__"))
+                .chain(cls_generics.iter().flat_map(GenericType::required_items))
+                .collect(),
             Self::ProtocolDecl {
                 super_protocols, ..
             } => iter::once(ItemTree::objc("__macros__"))
@@ -1971,8 +2351,9 @@ impl Stmt {
                 cls_superclasses,
                 protocol,
                 protocol_super_protocols,
+                generics,
                 ..
-            } => vec![
+            } => [
                 ItemTree::new(
                     cls.clone(),
                     superclasses_required_items(cls_superclasses.iter().cloned()),
@@ -1984,7 +2365,10 @@ impl Stmt {
                         .flat_map(|p| p.required_items()),
                 ),
                 ItemTree::objc("__macros__"),
-            ],
+            ]
+            .into_iter()
+            .chain(generics.iter().flat_map(GenericType::required_items))
+            .collect(),
             Self::RecordDecl { fields, .. } => fields
                 .iter()
                 .flat_map(|(_, _, field_ty)| field_ty.required_items())
@@ -2001,7 +2385,7 @@ impl Stmt {
             Self::FnDecl {
                 arguments,
                 result_type,
-                body: None,
+                body: None | Some(Some(_)),
                 ..
             } => {
                 let mut items = Vec::new();
@@ -2012,7 +2396,9 @@ impl Stmt {
                 items
             }
             // TODO
-            Self::FnDecl { body: Some(_), .. } => Vec::new(),
+            Self::FnDecl {
+                body: Some(None), ..
+            } => Vec::new(),
             Self::FnGetTypeId {
                 cf_item,
                 result_type,
@@ -2164,6 +2550,7 @@ impl Stmt {
                     sendable,
                     documentation,
                     bridged_to,
+                    is_exception,
                 } => {
                     if *skipped {
                         return Ok(());
@@ -2199,13 +2586,30 @@ impl Stmt {
                     if !generics.is_empty() {
                         write!(f, "<")?;
                         for generic in generics {
-                            write!(f, "{generic}: ?Sized = AnyObject, ")?;
+                            // Default to the bound (if any), such that the
+                            // unspecialized class satisfies it.
+                            write!(f, "{}: ?Sized = {}, ", generic.name, generic.default_ty())?;
                         }
                         write!(f, ">")?;
                     };
                     writeln!(f, ";")?;
                     writeln!(f, ");")?;
 
+                    // Expose the Objective-C name, which may differ from
+                    // the Rust name if the class was renamed.
+                    writeln!(f)?;
+                    write!(f, "{}", self.cfg_gate_ln(config))?;
+                    writeln!(
+                        f,
+                        "impl{} {}{} {{",
+                        GenericParamsHelper(generics, "?Sized + Message"),
+                        id.name,
+                        GenericTyHelper(generics),
+                    )?;
+                    writeln!(f, "    /// The Objective-C name of the class.")?;
+                    writeln!(f, "    pub const CLASS_NAME: &'static str = {objc_name:?};")?;
+                    writeln!(f, "}}")?;
+
                     if *sendable && generics.is_empty() {
                         writeln!(f)?;
                         write!(f, "{}", self.cfg_gate_ln(config))?;
@@ -2283,6 +2687,30 @@ impl Stmt {
                         write!(f, "{}", self.cfg_gate_ln(config))?;
                         add_generic_cast_helpers(f, id, generics, false)?;
                     }
+
+                    // Allow exceptions to be used with `?`.
+                    if *is_exception && generics.is_empty() {
+                        writeln!(f)?;
+                        write!(f, "{}", self.cfg_gate_ln(config))?;
+                        writeln!(f, "impl core::fmt::Display for {} {{", id.name)?;
+                        writeln!(
+                            f,
+                            "    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{"
+                        )?;
+                        writeln!(f, "        // Delegate to `-description`.")?;
+                        writeln!(
+                            f,
+                            "        let obj: &ProtocolObject<dyn NSObjectProtocol> = ProtocolObject::from_ref(self);"
+                        )?;
+                        writeln!(f, "        core::fmt::Debug::fmt(obj, f)")?;
+                        writeln!(f, "    }}")?;
+                        writeln!(f, "}}")?;
+
+                        writeln!(f)?;
+                        writeln!(f, "#[cfg(feature = \"std\")]")?;
+                        write!(f, "{}", self.cfg_gate_ln(config))?;
+                        writeln!(f, "impl std::error::Error for {} {{}}", id.name)?;
+                    }
                 }
                 Self::ExternMethods {
                     location: _,
@@ -2293,6 +2721,7 @@ impl Stmt {
                     cls_generics,
                     category_name,
                     methods,
+                    variadic_methods: _,
                     documentation,
                 } => {
                     if let Some(source_superclass) = source_superclass {
@@ -2333,6 +2762,33 @@ impl Stmt {
                     writeln!(f, "    );")?;
                     writeln!(f, "}}")?;
 
+                    // Methods with a body cannot be inside `extern_methods!`,
+                    // so emit conveniences for variadic methods separately.
+                    let mut variadic_conveniences = methods
+                        .iter()
+                        .filter_map(|method| Some((method, method.variadic_convenience()?)))
+                        .peekable();
+                    if variadic_conveniences.peek().is_some() {
+                        writeln!(f)?;
+                        write!(f, "{}", self.cfg_gate_ln(config))?;
+                        writeln!(
+                            f,
+                            "impl{} {}{} {{",
+                            GenericParamsHelper(cls_generics, "Message"),
+                            cls.path(),
+                            GenericTyHelper(cls_generics),
+                        )?;
+                        for (method, convenience) in variadic_conveniences {
+                            write!(
+                                f,
+                                "{}",
+                                self.cfg_gate_ln_inner(method.required_items(), config)
+                            )?;
+                            writeln!(f, "{convenience}")?;
+                        }
+                        writeln!(f, "}}")?;
+                    }
+
                     if let Some(method) = methods
                         .iter()
                         .find(|method| method.usable_in_default_retained())
@@ -2396,10 +2852,11 @@ impl Stmt {
                     writeln!(f)?;
 
                     let impl_cfg = self.cfg_gate_ln_for(
-                        [ItemTree::new(
+                        iter::once(ItemTree::new(
                             cls.clone(),
                             superclasses_required_items(cls_superclasses.iter().cloned()),
-                        )],
+                        ))
+                        .chain(cls_generics.iter().flat_map(GenericType::required_items)),
                         config,
                     );
 
@@ -2548,6 +3005,7 @@ impl Stmt {
                     methods,
                     required_sendable: _,
                     required_mainthreadonly,
+                    requires_explicit_impl,
                     documentation,
                 } => {
                     let cfg = self.cfg_gate_ln_for([ItemTree::objc("extern_protocol")], config);
@@ -2555,6 +3013,31 @@ impl Stmt {
                     writeln!(f, "extern_protocol!(")?;
 
                     write!(f, "{}", documentation.fmt(Some(id)))?;
+                    if *requires_explicit_impl {
+                        // The methods still get a default implementation in
+                        // `extern_protocol!`, since that is what's used for
+                        // calling them.
+                        //
+                        // The runtime doesn't know about this requirement,
+                        // so `define_class!` can't check it either (it
+                        // accepts required methods implemented by the
+                        // superclass); documenting it is the best we can do.
+                        writeln!(f, "///")?;
+                        writeln!(f, "/// # Implementing")?;
+                        writeln!(f, "///")?;
+                        writeln!(
+                            f,
+                            "/// Classes that conform to this protocol must implement all of its"
+                        )?;
+                        writeln!(
+                            f,
+                            "/// required methods themselves, even if a superclass already"
+                        )?;
+                        writeln!(
+                            f,
+                            "/// implements them. Note that this is not checked by `define_class!`."
+                        )?;
+                    }
                     write!(f, "    {}", self.cfg_gate_ln(config))?;
                     write!(f, "    {availability}")?;
                     if *objc_name != id.name {
@@ -2613,6 +3096,7 @@ impl Stmt {
                     natural_align,
                     documentation,
                     is_union,
+                    bitfields,
                 } => {
                     write!(f, "{}", documentation.fmt(Some(id)))?;
                     write!(f, "{}", self.cfg_gate_ln(config))?;
@@ -2626,18 +3110,16 @@ impl Stmt {
                         Ordering::Greater => writeln!(f, "#[repr(C, align({align}))]")?,
                     }
 
-                    if *is_union || fields.iter().any(|(_, _, field)| field.contains_union()) {
-                        writeln!(f, "#[derive(Clone, Copy)]")?;
-                    } else {
-                        if fields
+                    let derives = record_derives(*is_union, fields);
+                    if derives.contains("PartialEq")
+                        && fields
                             .iter()
                             .any(|(_, _, field)| field.directly_contains_fn_ptr())
-                        {
-                            // TODO(breaking): Maybe remove the PartialEq implementation here?
-                            writeln!(f, "#[allow(unpredictable_function_pointer_comparisons)]")?;
-                        }
-                        writeln!(f, "#[derive(Clone, Copy, Debug, PartialEq)]")?;
+                    {
+                        // TODO(breaking): Maybe remove the PartialEq implementation here?
+                        writeln!(f, "#[allow(unpredictable_function_pointer_comparisons)]")?;
                     }
+                    writeln!(f, "#[derive({derives})]")?;
                     if *is_union {
                         writeln!(f, "pub union {} {{", id.name)?;
                     } else {
@@ -2669,8 +3151,14 @@ impl Stmt {
                             write!(f, "Encoding::Struct")?;
                         }
                         writeln!(f, "({encoding_name:?}, &[")?;
-                        for (_, _, ty) in fields {
-                            writeln!(f, "        {},", ty.record_encoding())?;
+                        for (name, _, ty) in fields {
+                            if let Some(widths) = bitfields.get(name) {
+                                for width in widths {
+                                    writeln!(f, "        Encoding::BitField({width}, None),")?;
+                                }
+                            } else {
+                                writeln!(f, "        {},", ty.record_encoding())?;
+                            }
                         }
                         write!(f, "    ])")?;
                         Ok(())
@@ -2885,6 +3373,7 @@ impl Stmt {
                     availability: _,
                     ty,
                     value: None,
+                    typed_enum: _,
                     documentation,
                 } => {
                     writeln!(f, "extern \"C\" {{")?;
@@ -2902,13 +3391,16 @@ impl Stmt {
                     availability: _,
                     ty,
                     value: Some(expr),
+                    typed_enum,
                     documentation,
                 } => {
                     write!(f, "{}", documentation.fmt(Some(id)))?;
                     write!(f, "{}", self.cfg_gate_ln(config))?;
                     write!(f, "pub static {}: {} = ", id.name, ty.var())?;
 
-                    if ty.is_floating_through_typedef() {
+                    if *typed_enum {
+                        write!(f, "{}({expr} as _)", ty.var())?;
+                    } else if ty.is_floating_through_typedef() {
                         write!(f, "{expr} as _")?;
                     } else if ty.is_enum_through_typedef() {
                         write!(f, "{}({expr})", ty.var())?;
@@ -2917,11 +3409,59 @@ impl Stmt {
                     }
                     writeln!(f, ";")?;
                 }
+                Self::FnDecl {
+                    id,
+                    availability,
+                    arguments,
+                    first_arg_is_self,
+                    result_type,
+                    body: Some(Some(body)),
+                    must_use,
+                    returns_retained,
+                    documentation,
+                    ..
+                } => {
+                    let (ret, _) = result_type.fn_return(*returns_retained);
+                    let vis = if id.name.starts_with("_") {
+                        "pub(crate)"
+                    } else {
+                        "pub"
+                    };
+
+                    write!(f, "{}", documentation.fmt(None))?;
+                    write!(f, "{}", self.cfg_gate_ln(config))?;
+                    write!(f, "{availability}")?;
+                    if *must_use {
+                        writeln!(f, "#[must_use]")?;
+                    }
+                    writeln!(f, "#[inline]")?;
+                    write!(f, "{vis} fn {}(", handle_reserved(&id.name))?;
+                    for (i, (param, arg_ty)) in arguments.iter().enumerate() {
+                        if i == 0 && *first_arg_is_self {
+                            write!(f, "self: ")?;
+                        } else {
+                            let param = handle_reserved(&crate::to_snake_case(param));
+                            write!(f, "{param}: ")?;
+                        }
+                        write!(f, "{},", arg_ty.fn_argument())?;
+                    }
+                    writeln!(f, "){ret} {{")?;
+                    // The body refers to the parameter by name.
+                    if *first_arg_is_self {
+                        if let Some((param, _)) = arguments.first() {
+                            let param = handle_reserved(&crate::to_snake_case(param));
+                            writeln!(f, "    let {param} = self;")?;
+                        }
+                    }
+                    writeln!(f, "    {body}")?;
+                    writeln!(f, "}}")?;
+                }
                 Self::FnDecl {
                     c_name,
                     arguments,
+                    variadic,
                     result_type,
-                    body: Some(_),
+                    body: Some(None),
                     returns_retained,
                     ..
                 } => {
@@ -2931,6 +3471,9 @@ impl Stmt {
                         let param = handle_reserved(&crate::to_snake_case(param));
                         write!(f, "{param}: {},", arg_ty.fn_argument())?;
                     }
+                    if *variadic {
+                        write!(f, "...")?;
+                    }
                     let (ret, _) = result_type.fn_return(*returns_retained);
                     writeln!(f, "){ret};")?;
                 }
@@ -2940,6 +3483,7 @@ impl Stmt {
                     link_name,
                     availability,
                     arguments,
+                    variadic,
                     first_arg_is_self,
                     result_type,
                     body: None,
@@ -2953,12 +3497,16 @@ impl Stmt {
                 } => {
                     let (ret, return_converter) = result_type.fn_return(*returns_retained);
 
-                    let needs_wrapper = *safe
-                        || return_converter.is_some()
-                        || arguments
-                            .iter()
-                            .any(|(_, arg)| arg.fn_argument_converter().is_some())
-                        || abi.rust_outer();
+                    // Variadic arguments cannot be forwarded from a Rust
+                    // function, so those are always emitted as the raw
+                    // (unsafe) declaration.
+                    let needs_wrapper = !*variadic
+                        && (*safe
+                            || return_converter.is_some()
+                            || arguments
+                                .iter()
+                                .any(|(_, arg)| arg.fn_argument_converter().is_some())
+                            || abi.rust_outer());
 
                     let raw_fn_decl = |f: &mut fmt::Formatter<'_>, vis| {
                         if c_name != link_name {
@@ -2981,6 +3529,9 @@ impl Stmt {
                             let param = handle_reserved(&crate::to_snake_case(param));
                             write!(f, "{param}: {},", arg_ty.fn_argument())?;
                         }
+                        if *variadic {
+                            write!(f, "...")?;
+                        }
                         writeln!(f, "){ret};")?;
 
                         Ok(())
@@ -3105,6 +3656,65 @@ impl Stmt {
                     writeln!(f, "}}")?;
                     return Ok(());
                 }
+                Self::AliasDecl {
+                    id,
+                    availability,
+                    ty,
+                    kind,
+                    documentation,
+                } if typed_enum_is_newtype(kind.as_ref(), ty) => {
+                    write!(f, "{}", documentation.fmt(Some(id)))?;
+                    let extensible = *kind == Some(UnexposedAttr::TypedExtensibleEnum);
+                    if extensible {
+                        writeln!(f, "// NS_TYPED_EXTENSIBLE_ENUM")?;
+                    } else {
+                        writeln!(f, "// NS_TYPED_ENUM")?;
+                    }
+
+                    write!(f, "{}", self.cfg_gate_ln(config))?;
+                    write!(f, "{availability}")?;
+                    writeln!(f, "#[repr(transparent)]")?;
+                    if ty.is_floating_through_typedef() {
+                        writeln!(f, "#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]")?;
+                    } else {
+                        writeln!(
+                            f,
+                            "#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]"
+                        )?;
+                    }
+                    // Extensible enums are intended to be extended by the
+                    // user, so the value can be constructed freely. The
+                    // values of other typed enums are only given by the
+                    // constants (which are declared in this crate).
+                    if extensible {
+                        writeln!(f, "pub struct {}(pub {});", id.name, ty.typedef())?;
+                    } else {
+                        writeln!(f, "pub struct {}(pub(crate) {});", id.name, ty.typedef())?;
+
+                        writeln!(f)?;
+                        write!(f, "{}", self.cfg_gate_ln(config))?;
+                        writeln!(f, "impl {} {{", id.name)?;
+                        writeln!(f, "    /// The underlying value.")?;
+                        writeln!(f, "    #[inline]")?;
+                        writeln!(f, "    pub const fn value(self) -> {} {{", ty.typedef())?;
+                        writeln!(f, "        self.0")?;
+                        writeln!(f, "    }}")?;
+                        writeln!(f, "}}")?;
+                    }
+
+                    let required_items = self
+                        .required_items()
+                        .chain(iter::once(ItemTree::objc("Encoding")));
+                    let cfg_encoding = self.cfg_gate_ln_for(required_items, config);
+
+                    writeln!(f)?;
+                    // SAFETY: The struct is a `#[repr(transparent)]` newtype
+                    // over the type.
+                    write!(f, "{cfg_encoding}")?;
+                    writeln!(f, "{}", unsafe_impl_encode(&id.name, ty.enum_encoding()))?;
+                    write!(f, "{cfg_encoding}")?;
+                    writeln!(f, "{}", unsafe_impl_refencode(&id.name))?;
+                }
                 Self::AliasDecl {
                     id,
                     availability: _,
@@ -3115,13 +3725,13 @@ impl Stmt {
                     write!(f, "{}", documentation.fmt(Some(id)))?;
                     match kind {
                         Some(UnexposedAttr::TypedEnum) => {
-                            // TODO: Handle this differently
+                            // TODO: Emit a newtype for object types too.
                             writeln!(f, "// NS_TYPED_ENUM")?;
                             write!(f, "{}", self.cfg_gate_ln(config))?;
                             writeln!(f, "pub type {} = {};", id.name, ty.typedef())?;
                         }
                         Some(UnexposedAttr::TypedExtensibleEnum) => {
-                            // TODO: Handle this differently
+                            // TODO: Emit a newtype for object types too.
                             writeln!(f, "// NS_TYPED_EXTENSIBLE_ENUM")?;
                             write!(f, "{}", self.cfg_gate_ln(config))?;
                             writeln!(f, "pub type {} = {};", id.name, ty.typedef())?;
@@ -3325,11 +3935,16 @@ impl Stmt {
             }
             writeln!(f, "    {{")?;
             for generic in cls_generics {
-                writeln!(f, "        type {generic} = AnyObject;")?;
+                writeln!(
+                    f,
+                    "        type {} = {};",
+                    generic.name,
+                    generic.default_ty()
+                )?;
             }
             write!(f, "        type This = {}<", cls.path())?;
             for generic in cls_generics {
-                write!(f, "{generic}, ")?;
+                write!(f, "{}, ", generic.name)?;
             }
             writeln!(f, ">;")?;
             writeln!(f, "        let cls = This::class();")?;
@@ -3410,15 +4025,40 @@ impl Stmt {
     }
 }
 
-struct GenericTyHelper<'a>(&'a [String]);
+/// A generic parameter, either just the name, or a class' [`GenericType`].
+trait GenericParam {
+    fn name(&self) -> &str;
+
+    fn bound(&self) -> Option<&GenericBound> {
+        None
+    }
+}
+
+impl GenericParam for String {
+    fn name(&self) -> &str {
+        self
+    }
+}
+
+impl GenericParam for GenericType {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn bound(&self) -> Option<&GenericBound> {
+        self.bound.as_ref()
+    }
+}
 
-impl fmt::Display for GenericTyHelper<'_> {
+struct GenericTyHelper<'a, G>(&'a [G]);
+
+impl<G: GenericParam> fmt::Display for GenericTyHelper<'_, G> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut iter = self.0.iter();
         if let Some(first) = iter.next() {
-            write!(f, "<{first}")?;
+            write!(f, "<{}", first.name())?;
             for generic in iter {
-                write!(f, ", {generic}")?;
+                write!(f, ", {}", generic.name())?;
             }
             write!(f, ">")?;
         }
@@ -3426,31 +4066,45 @@ impl fmt::Display for GenericTyHelper<'_> {
     }
 }
 
-struct GenericParamsHelper<'a>(&'a [String], &'a str);
+/// Generic parameters, with the given bound applied to all of them, along
+/// with the parameter's own bound (if any).
+struct GenericParamsHelper<'a, G>(&'a [G], &'a str);
 
-impl fmt::Display for GenericParamsHelper<'_> {
+impl<G: GenericParam> fmt::Display for GenericParamsHelper<'_, G> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut iter = self.0.iter();
-        if let Some(first) = iter.next() {
-            write!(f, "<{first}: {}", self.1)?;
-            for generic in iter {
-                write!(f, ", {generic}: {}", self.1)?;
+        for (i, generic) in self.0.iter().enumerate() {
+            if i == 0 {
+                write!(f, "<")?;
+            } else {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", generic.name(), self.1)?;
+            match generic.bound() {
+                Some(GenericBound::Class(superclass)) => {
+                    write!(f, " + AsRef<{}>", superclass.path())?;
+                }
+                Some(GenericBound::Protocol(protocol)) => {
+                    write!(f, " + {}", protocol.path())?;
+                }
+                None => {}
             }
+        }
+        if !self.0.is_empty() {
             write!(f, ">")?;
         }
         Ok(())
     }
 }
 
-struct WhereBoundHelper<'a>(&'a [String], Option<&'a str>);
+struct WhereBoundHelper<'a, G>(&'a [G], Option<&'a str>);
 
-impl fmt::Display for WhereBoundHelper<'_> {
+impl<G: GenericParam> fmt::Display for WhereBoundHelper<'_, G> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(bound) = self.1 {
             if !self.0.is_empty() {
                 writeln!(f, "where")?;
                 for generic in self.0 {
-                    writeln!(f, "{generic}{bound},")?;
+                    writeln!(f, "{}{bound},", generic.name())?;
                 }
             }
         }
@@ -3465,7 +4119,7 @@ impl fmt::Display for WhereBoundHelper<'_> {
 fn add_generic_cast_helpers(
     f: &mut fmt::Formatter<'_>,
     id: &ItemIdentifier,
-    generics: &[String],
+    generics: &[impl GenericParam],
     cf: bool,
 ) -> fmt::Result {
     let s = if generics.len() == 1 { "" } else { "s" };
@@ -3475,7 +4129,7 @@ fn add_generic_cast_helpers(
     let bound = if cf { "?Sized" } else { "?Sized + Message" };
     let casted_generics: Vec<_> = generics
         .iter()
-        .map(|generic| format!("New{generic}"))
+        .map(|generic| format!("New{}", generic.name()))
         .collect();
 
     writeln!(
@@ -3560,6 +4214,677 @@ fn simple_platform_gate(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_variadic_fn() {
+        let config = crate::load_config().unwrap();
+        let stmt = Stmt::FnDecl {
+            id: ItemIdentifier::dummy(0),
+            c_name: "DUMMY0".into(),
+            link_name: "DUMMY0".into(),
+            availability: Availability::default(),
+            arguments: vec![("count".into(), Ty::Primitive(Primitive::Int))],
+            variadic: true,
+            first_arg_is_self: false,
+            result_type: Ty::Primitive(Primitive::Void),
+            body: None,
+            // Ignored, variadic functions are always unsafe.
+            safe: true,
+            must_use: false,
+            abi: Abi::C,
+            returns_retained: false,
+            documentation: Documentation::empty(),
+            no_implementor: false,
+            custom_implementor: None,
+        };
+        let output = stmt.fmt(&config).to_string();
+        assert!(output.starts_with("extern \"C\" {"), "{output}");
+        assert!(
+            output.contains("pub fn DUMMY0(count: c_int,...);"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_inline_fn_body() {
+        use crate::expr::Token;
+        use core::ffi::{c_double, c_uint};
+
+        /// Define the given function, and check that its source code matches
+        /// the output (modulo whitespace and trailing commas). This makes
+        /// sure that the generated code compiles, and allows calling it to
+        /// check its behaviour.
+        macro_rules! assert_output_compiles {
+            ($output:expr, $item:item) => {
+                $item
+                let strip = |s: &str| s.split_whitespace().collect::<String>().replace(",)", ")");
+                assert!(
+                    strip(&$output).contains(&strip(stringify!($item))),
+                    "{}",
+                    $output,
+                );
+            };
+        }
+
+        let config = crate::load_config().unwrap();
+        let inline_fn = |ty: Primitive, body| Stmt::FnDecl {
+            id: ItemIdentifier::dummy(0),
+            c_name: "DUMMY0".into(),
+            link_name: "DUMMY0".into(),
+            availability: Availability::default(),
+            arguments: vec![
+                ("x".into(), Ty::Primitive(ty)),
+                ("y".into(), Ty::Primitive(ty)),
+            ],
+            variadic: false,
+            first_arg_is_self: false,
+            result_type: Ty::Primitive(ty),
+            body: Some(body),
+            safe: false,
+            must_use: false,
+            abi: Abi::C,
+            returns_retained: false,
+            documentation: Documentation::empty(),
+            no_implementor: false,
+            custom_implementor: None,
+        };
+        let punct = |p: &str| Token::Punctuation(p.into());
+        let lit = |l: &str| Token::Literal(l.into());
+
+        // static inline double DUMMY0(double x, double y) { return (x + y) * 2.0; }
+        let body = Expr::Tokens(vec![
+            punct("("),
+            lit("x"),
+            punct("+"),
+            lit("y"),
+            punct(")"),
+            punct("*"),
+            lit("2.0"),
+        ]);
+        let output = inline_fn(Primitive::Double, Some(body))
+            .fmt(&config)
+            .to_string();
+        {
+            assert_output_compiles!(
+                output,
+                #[inline]
+                pub fn DUMMY0(x: c_double, y: c_double) -> c_double {
+                    (x + y) * 2.0
+                }
+            );
+            assert_eq!(DUMMY0(1.0, 2.0), 6.0);
+        }
+
+        // static inline unsigned DUMMY0(unsigned x, unsigned y) { return -(x - y) * y; }
+        let body = Expr::Tokens(vec![
+            lit("core::ffi::c_uint::wrapping_mul"),
+            punct("("),
+            lit("core::ffi::c_uint::wrapping_neg"),
+            punct("("),
+            lit("core::ffi::c_uint::wrapping_sub"),
+            punct("("),
+            lit("x"),
+            punct(", "),
+            lit("y"),
+            punct(")"),
+            punct(")"),
+            punct(", "),
+            lit("y"),
+            punct(")"),
+        ]);
+        let output = inline_fn(Primitive::UInt, Some(body))
+            .fmt(&config)
+            .to_string();
+        {
+            assert_output_compiles!(
+                output,
+                #[inline]
+                pub fn DUMMY0(x: c_uint, y: c_uint) -> c_uint {
+                    core::ffi::c_uint::wrapping_mul(
+                        core::ffi::c_uint::wrapping_neg(core::ffi::c_uint::wrapping_sub(x, y)),
+                        y,
+                    )
+                }
+            );
+            // Wraps like in C.
+            assert_eq!(DUMMY0(2, 1), c_uint::MAX);
+            assert_eq!(DUMMY0(1, 2), 2);
+        }
+
+        // Falls back to a TODO if the body could not be translated.
+        let output = inline_fn(Primitive::Double, None).fmt(&config).to_string();
+        assert!(
+            output.starts_with("// TODO: pub fn DUMMY0(x: c_double,y: c_double,) -> c_double;"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_protocol_requires_explicit_impl() {
+        let config = crate::load_config().unwrap();
+        let protocol = |requires_explicit_impl| Stmt::ProtocolDecl {
+            id: ItemIdentifier::dummy(0),
+            objc_name: "DUMMY0".into(),
+            availability: Availability::default(),
+            super_protocols: vec![],
+            methods: vec![Method::dummy("doSomething")],
+            required_sendable: false,
+            required_mainthreadonly: false,
+            requires_explicit_impl,
+            documentation: Documentation::empty(),
+        };
+
+        // @protocol DUMMY0 NS_PROTOCOL_REQUIRES_EXPLICIT_IMPLEMENTATION
+        let output = protocol(true).fmt(&config).to_string();
+        assert!(output.contains("/// # Implementing"), "{output}");
+        assert!(
+            output.contains("required methods themselves, even if a superclass already"),
+            "{output}"
+        );
+        assert!(
+            output.contains("Note that this is not checked by `define_class!`."),
+            "{output}"
+        );
+        assert!(output.contains("pub unsafe trait DUMMY0 {"), "{output}");
+
+        let output = protocol(false).fmt(&config).to_string();
+        assert!(!output.contains("/// # Implementing"), "{output}");
+    }
+
+    #[test]
+    fn test_property_accessors() {
+        // @property int foo;
+        // (implicit) - (int)foo;
+        // (implicit) - (void)setFoo:(int)foo;
+        let mut accessors = PropertyAccessors::default();
+        let superseded = accessors.add_property(false, Some("foo"), Some("setFoo:"));
+        assert!(superseded.is_empty());
+        assert!(accessors.is_accessor(false, "foo".into()));
+        assert!(accessors.is_accessor(false, "setFoo:".into()));
+        assert!(accessors.pending.is_empty());
+
+        // @property(readonly) int foo;
+        // (implicit) - (int)foo;
+        // - (void)setFoo:(int)foo;
+        let mut accessors = PropertyAccessors::default();
+        accessors.add_property(false, Some("foo"), None);
+        assert!(accessors.is_accessor(false, "foo".into()));
+        assert!(!accessors.is_accessor(false, "setFoo:".into()));
+        assert!(accessors.pending.is_empty());
+
+        // Accessors are only matched against methods of the same kind.
+        // @property(class) int foo;
+        // - (int)foo;
+        // (implicit) + (int)foo;
+        let mut accessors = PropertyAccessors::default();
+        accessors.add_property(true, Some("foo"), None);
+        assert!(!accessors.is_accessor(false, "foo".into()));
+        assert!(accessors.is_accessor(true, "foo".into()));
+        assert!(accessors.pending.is_empty());
+
+        // Redeclared accessors: the compiler doesn't declare the method
+        // again, so the property supersedes it.
+        // - (int)foo;
+        // - (void)setFoo:(int)foo;
+        // @property int foo;
+        let mut accessors = PropertyAccessors::default();
+        assert!(!accessors.is_accessor(false, "foo".into()));
+        assert!(!accessors.is_accessor(false, "setFoo:".into()));
+        let superseded = accessors.add_property(false, Some("foo"), Some("setFoo:"));
+        assert_eq!(superseded, ["foo", "setFoo:"]);
+        assert!(accessors.pending.is_empty());
+
+        // Genuinely missing methods are still caught.
+        // @property int foo;
+        // (implicit) - (int)foo;
+        let mut accessors = PropertyAccessors::default();
+        accessors.add_property(false, Some("foo"), Some("setFoo:"));
+        assert!(accessors.is_accessor(false, "foo".into()));
+        assert_eq!(
+            accessors.pending,
+            HashSet::from([(false, "setFoo:".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_class_name() {
+        let config = crate::load_config().unwrap();
+        let class = |objc_name: &str| Stmt::ClassDecl {
+            id: ItemIdentifier::dummy(0),
+            generics: vec![],
+            objc_name: objc_name.into(),
+            availability: Availability::default(),
+            superclasses: vec![(ItemIdentifier::dummy(1), vec![])],
+            designated_initializers: vec![],
+            derives: Derives::default(),
+            main_thread_only: false,
+            skipped: false,
+            sendable: false,
+            documentation: Documentation::empty(),
+            bridged_to: None,
+            is_exception: false,
+        };
+
+        // The Rust name matches the Objective-C name.
+        let output = class("DUMMY0").fmt(&config).to_string();
+        assert!(!output.contains("#[name = "), "{output}");
+        assert!(
+            output.contains("pub const CLASS_NAME: &'static str = \"DUMMY0\";"),
+            "{output}"
+        );
+
+        // A class renamed in the config keeps its Objective-C name.
+        let output = class("_NSRenamedPrivateClass").fmt(&config).to_string();
+        assert!(
+            output.contains("    #[name = \"_NSRenamedPrivateClass\"]\n"),
+            "{output}"
+        );
+        assert!(output.contains("pub struct DUMMY0;"), "{output}");
+        assert!(
+            output.contains("pub const CLASS_NAME: &'static str = \"_NSRenamedPrivateClass\";"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_exception_class() {
+        let config = crate::load_config().unwrap();
+        let class = |is_exception| Stmt::ClassDecl {
+            id: ItemIdentifier::dummy(0),
+            generics: vec![],
+            objc_name: "DUMMY0".into(),
+            availability: Availability::default(),
+            superclasses: vec![(ItemIdentifier::dummy(1), vec![])],
+            designated_initializers: vec![],
+            derives: Derives::default(),
+            main_thread_only: false,
+            skipped: false,
+            sendable: false,
+            documentation: Documentation::empty(),
+            bridged_to: None,
+            is_exception,
+        };
+
+        // __attribute__((objc_exception)) @interface DUMMY0 : DUMMY1
+        let output = class(true).fmt(&config).to_string();
+        assert!(
+            output.contains("impl core::fmt::Display for DUMMY0 {"),
+            "{output}"
+        );
+        assert!(output.contains("#[cfg(feature = \"std\")]"), "{output}");
+        assert!(
+            output.contains("impl std::error::Error for DUMMY0 {}"),
+            "{output}"
+        );
+
+        let output = class(false).fmt(&config).to_string();
+        assert!(!output.contains("std::error::Error"), "{output}");
+    }
+
+    #[test]
+    fn test_record_derives() {
+        let field = |name: &str, ty| (name.to_string(), Documentation::empty(), ty);
+        let point = Ty::Struct {
+            id: ItemIdentifier::dummy(0),
+            fields: vec![
+                Ty::Primitive(Primitive::Double),
+                Ty::Primitive(Primitive::Double),
+            ],
+            is_bridged: false,
+        };
+
+        // struct { double x; double y; }
+        let fields = [
+            field("x", Ty::Primitive(Primitive::Double)),
+            field("y", Ty::Primitive(Primitive::Double)),
+        ];
+        assert_eq!(
+            record_derives(false, &fields),
+            "Clone, Copy, Debug, PartialEq"
+        );
+
+        // struct { Point origin; int32_t tag; }
+        let fields = [
+            field("origin", point),
+            field("tag", Ty::Primitive(Primitive::I32)),
+        ];
+        assert_eq!(
+            record_derives(false, &fields),
+            "Clone, Copy, Debug, PartialEq"
+        );
+
+        // union { float f; int32_t i; }
+        let fields = [
+            field("f", Ty::Primitive(Primitive::Float)),
+            field("i", Ty::Primitive(Primitive::I32)),
+        ];
+        assert_eq!(record_derives(true, &fields), "Clone, Copy");
+    }
+
+    #[test]
+    fn test_union_decl() {
+        let config = crate::load_config().unwrap();
+        let field = |name: &str, ty| (name.to_string(), Documentation::empty(), ty);
+        // union DUMMY0 { float f; int32_t i; int32_t _reserved; };
+        let stmt = Stmt::RecordDecl {
+            id: ItemIdentifier::dummy(0),
+            encoding_name: "DUMMY0".into(),
+            availability: Availability::default(),
+            boxable: false,
+            fields: vec![
+                field("f", Ty::Primitive(Primitive::Float)),
+                field("i", Ty::Primitive(Primitive::I32)),
+                field("_reserved", Ty::Primitive(Primitive::I32)),
+            ],
+            sendable: None,
+            align: 4,
+            natural_align: 4,
+            documentation: Documentation::empty(),
+            is_union: true,
+            bitfields: BTreeMap::new(),
+        };
+        let output = stmt.fmt(&config).to_string();
+        assert!(
+            output.contains("#[repr(C)]\n#[derive(Clone, Copy)]\npub union DUMMY0 {\n"),
+            "{output}"
+        );
+        assert!(output.contains("    pub f: c_float,\n"), "{output}");
+        assert!(output.contains("    pub i: i32,\n"), "{output}");
+        // Private fields stay private, like in structs.
+        assert!(
+            output.contains("    pub(crate) _reserved: i32,\n"),
+            "{output}"
+        );
+        assert!(
+            output.contains("Encoding::Union(\"DUMMY0\", &["),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_bitfields() {
+        use clang::{Clang, Index, Unsaved};
+
+        let config = crate::load_config().unwrap();
+        let context = Context::new(&config, "__builtin__");
+        let library = config.try_library("__builtin__").unwrap();
+
+        let clang = Clang::new().unwrap();
+        let index = Index::new(&clang, true, false);
+        let header = "struct DUMMY0 { unsigned a: 1; unsigned b: 3; int c; char d: 2; };\n\
+                      struct DUMMY1 { unsigned a: 1; unsigned b: 3; };\n";
+        let tu = index
+            .parser("test.h")
+            .arguments(&["-xobjective-c"])
+            .unsaved(&[Unsaved::new("test.h", header)])
+            .parse()
+            .unwrap();
+
+        let mut layouts = vec![];
+        let mut stmts = vec![];
+        immediate_children(&tu.get_entity(), |entity, _span| {
+            if entity.is_in_main_file() {
+                let ty = entity.get_type().unwrap();
+                layouts.push((ty.get_sizeof().unwrap(), ty.get_alignof().unwrap()));
+                stmts.extend(Stmt::parse(&entity, &context, library));
+            }
+        });
+        assert_eq!(stmts.len(), 2, "{stmts:?}");
+
+        // `a` and `b` share the first four bytes, `c` is at offset 4 and `d`
+        // is at offset 8, followed by padding up to the size of the record.
+        let output = stmts[0].fmt(&config).to_string();
+        assert!(
+            output.contains(
+                "pub struct DUMMY0 {\n    pub(crate) _bitfield_1: [u8; 4],\n    pub c: c_int,\n    pub(crate) _bitfield_2: [u8; 4],\n}"
+            ),
+            "{output}"
+        );
+        assert!(
+            output.contains(
+                "Encoding::BitField(1, None),\n        Encoding::BitField(3, None),\n        <c_int>::ENCODING,\n        Encoding::BitField(2, None),\n"
+            ),
+            "{output}"
+        );
+
+        // The emitted layout matches the one that Clang computed.
+        #[repr(C)]
+        #[allow(dead_code)]
+        struct Dummy0 {
+            _bitfield_1: [u8; 4],
+            c: std::ffi::c_int,
+            _bitfield_2: [u8; 4],
+        }
+        assert_eq!(
+            layouts[0],
+            (
+                std::mem::size_of::<Dummy0>(),
+                std::mem::align_of::<Dummy0>()
+            )
+        );
+
+        // The record is aligned to the bitfields' underlying type.
+        let output = stmts[1].fmt(&config).to_string();
+        assert!(output.contains("#[repr(C, align(4))]\n"), "{output}");
+        assert!(
+            output.contains("pub struct DUMMY1 {\n    pub(crate) _bitfield_1: [u8; 4],\n}"),
+            "{output}"
+        );
+        assert_eq!(layouts[1], (4, 4));
+    }
+
+    #[test]
+    fn test_generic_bounds() {
+        let config = crate::load_config().unwrap();
+        // @interface DUMMY0<UnitType: DUMMY1 *, ObjectType: id<DUMMY2>, KeyType> : NSObject
+        let generics = vec![
+            GenericType {
+                name: "UnitType".into(),
+                bound: Some(GenericBound::Class(ItemIdentifier::dummy(1))),
+            },
+            GenericType {
+                name: "ObjectType".into(),
+                bound: Some(GenericBound::Protocol(ItemIdentifier::dummy(2))),
+            },
+            GenericType {
+                name: "KeyType".into(),
+                bound: None,
+            },
+        ];
+        let cls = ItemIdentifier::dummy(0);
+        let params =
+            "<UnitType: Message + AsRef<DUMMY1>, ObjectType: Message + DUMMY2, KeyType: Message>";
+
+        // The unspecialized class defaults to types that satisfy the bounds.
+        let stmt = Stmt::ClassDecl {
+            id: cls.clone(),
+            generics: generics.clone(),
+            objc_name: "DUMMY0".into(),
+            availability: Availability::default(),
+            superclasses: vec![(ItemIdentifier::dummy(3), vec![])],
+            designated_initializers: vec![],
+            derives: Derives::default(),
+            main_thread_only: false,
+            skipped: false,
+            sendable: false,
+            documentation: Documentation::empty(),
+            bridged_to: None,
+            is_exception: false,
+        };
+        let output = stmt.fmt(&config).to_string();
+        assert!(
+            output.contains(
+                "pub struct DUMMY0<UnitType: ?Sized = DUMMY1, ObjectType: ?Sized = ProtocolObject<dyn DUMMY2>, KeyType: ?Sized = AnyObject, >;"
+            ),
+            "{output}"
+        );
+
+        let stmt = Stmt::ExternMethods {
+            location: cls.location().clone(),
+            availability: Availability::default(),
+            cls: cls.clone(),
+            cls_superclasses: vec![],
+            source_superclass: None,
+            cls_generics: generics.clone(),
+            category_name: None,
+            methods: vec![Method::dummy("foo")],
+            variadic_methods: vec![],
+            documentation: None,
+        };
+        let output = stmt.fmt(&config).to_string();
+        assert!(
+            output.contains(&format!(
+                "impl{params} DUMMY0<UnitType, ObjectType, KeyType> {{"
+            )),
+            "{output}"
+        );
+
+        // Categories in other libraries have the same bounds.
+        let stmt = Stmt::ExternCategory {
+            id: ItemIdentifier::dummy(4),
+            availability: Availability::default(),
+            cls,
+            cls_superclasses: vec![],
+            cls_generics: generics,
+            methods: vec![Method::dummy("foo")],
+            documentation: Documentation::empty(),
+        };
+        let output = stmt.fmt(&config).to_string();
+        assert!(
+            output.contains(&format!("pub unsafe trait DUMMY4{params}: ClassType")),
+            "{output}"
+        );
+        assert!(
+            output.contains(&format!(
+                "unsafe impl{params} DUMMY4<UnitType, ObjectType, KeyType> for DUMMY0<UnitType, ObjectType, KeyType> {{}}"
+            )),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_typed_enum() {
+        let config = crate::load_config().unwrap();
+        let alias = |n, kind| Stmt::AliasDecl {
+            id: ItemIdentifier::dummy(n),
+            availability: Availability::default(),
+            ty: Ty::Primitive(Primitive::Double),
+            kind: Some(kind),
+            documentation: Documentation::empty(),
+        };
+
+        // typedef double DUMMY0 NS_TYPED_ENUM;
+        let output = alias(0, UnexposedAttr::TypedEnum).fmt(&config).to_string();
+        assert!(output.contains("// NS_TYPED_ENUM"), "{output}");
+        assert!(
+            output.contains("pub struct DUMMY0(pub(crate) c_double);"),
+            "{output}"
+        );
+        assert!(
+            output.contains("pub const fn value(self) -> c_double {"),
+            "{output}"
+        );
+        assert!(
+            output.contains("unsafe impl Encode for DUMMY0 {"),
+            "{output}"
+        );
+
+        // typedef double DUMMY1 NS_TYPED_EXTENSIBLE_ENUM;
+        let output = alias(1, UnexposedAttr::TypedExtensibleEnum)
+            .fmt(&config)
+            .to_string();
+        assert!(output.contains("// NS_TYPED_EXTENSIBLE_ENUM"), "{output}");
+        assert!(
+            output.contains("pub struct DUMMY1(pub c_double);"),
+            "{output}"
+        );
+        assert!(
+            output.contains("unsafe impl Encode for DUMMY1 {"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_forward_declared_typed_enum() {
+        use clang::{Clang, Index, Unsaved};
+
+        let config = crate::load_config().unwrap();
+        let context = Context::new(&config, "__builtin__");
+        let library = config.try_library("__builtin__").unwrap();
+
+        let clang = Clang::new().unwrap();
+        let index = Index::new(&clang, true, false);
+        let header = "typedef enum Foo : long Foo;\n\
+                      enum Foo : long { FooA, FooB };\n";
+        let tu = index
+            .parser("test.h")
+            .arguments(&["-xobjective-c"])
+            .unsaved(&[Unsaved::new("test.h", header)])
+            .parse()
+            .unwrap();
+
+        let mut stmts = vec![];
+        immediate_children(&tu.get_entity(), |entity, _span| {
+            if entity.is_in_main_file() {
+                stmts.extend(Stmt::parse(&entity, &context, library));
+            }
+        });
+
+        // The forward declaration and the typedef are merged with the body,
+        // such that only a single enum is emitted.
+        let [Stmt::EnumDecl { id, .. }] = &stmts[..] else {
+            panic!("expected a single enum, got {stmts:?}");
+        };
+        assert_eq!(id.name, "Foo");
+    }
+
+    #[test]
+    fn test_typedef_anonymous_enum() {
+        use clang::{Clang, Index, Unsaved};
+
+        let config = crate::load_config().unwrap();
+        let context = Context::new(&config, "__builtin__");
+        let library = config.try_library("__builtin__").unwrap();
+
+        let clang = Clang::new().unwrap();
+        let index = Index::new(&clang, true, false);
+        let header = "typedef enum { MyEnumA, MyEnumB } MyEnum;\n\
+                      enum { MyConstA };\n\
+                      typedef int MyInt;\n";
+        let tu = index
+            .parser("test.h")
+            .arguments(&["-xobjective-c"])
+            .unsaved(&[Unsaved::new("test.h", header)])
+            .parse()
+            .unwrap();
+
+        let mut stmts = vec![];
+        immediate_children(&tu.get_entity(), |entity, _span| {
+            if entity.is_in_main_file() {
+                stmts.extend(Stmt::parse(&entity, &context, library));
+            }
+        });
+
+        // The enum in the typedef is named after it, while the enum followed
+        // by an unrelated typedef stays anonymous.
+        assert!(
+            matches!(
+                &stmts[..],
+                [
+                    Stmt::EnumDecl { .. },
+                    Stmt::ConstDecl { .. },
+                    Stmt::AliasDecl { .. }
+                ]
+            ),
+            "{stmts:?}"
+        );
+        let names: Vec<_> = stmts
+            .iter()
+            .map(|stmt| stmt.provided_item().unwrap().name)
+            .collect();
+        assert_eq!(names, ["MyEnum", "MyConstA", "MyInt"]);
+    }
+
     #[test]
     fn test_superclasses_required_items() {
         let superclasses = [
@@ -232,6 +232,10 @@ impl ThreadSafety {
         self.inferred == ThreadSafetyAttr::MainThreadOnly
     }
 
+    pub(crate) fn inferred_sendable(&self) -> bool {
+        self.inferred == ThreadSafetyAttr::Sendable
+    }
+
     pub(crate) fn explicit_mainthreadonly(&self) -> bool {
         self.explicit == Some(ThreadSafetyAttr::MainThreadOnly)
     }
@@ -7,12 +7,16 @@ use core::panic::{RefUnwindSafe, UnwindSafe};
 use std::collections::HashSet;
 
 use crate::encode::{Encode, Encoding};
+#[cfg(debug_assertions)]
+use crate::encode::{EncodeArguments, EncodeReturn};
 use crate::rc::{Allocated, Retained};
 use crate::runtime::{
     AnyClass, AnyObject, ClassBuilder, MessageReceiver, MethodImplementation, Sel,
 };
 #[cfg(debug_assertions)]
-use crate::runtime::{AnyProtocol, MethodDescription};
+use crate::runtime::{AnyProtocol, MethodDescription, MethodEncodingIter};
+#[cfg(debug_assertions)]
+use crate::sel;
 use crate::{AnyThread, ClassType, DefinedClass, Message, ProtocolType};
 
 use super::defined_ivars::{
@@ -369,6 +373,17 @@ impl<T: DefinedClass> ClassBuilderHelper<T> {
     where
         F: MethodImplementation<Callee = AnyClass>,
     {
+        // The runtime only calls `+load` on classes that are present in the
+        // binary when it is loaded, but classes defined with `define_class!`
+        // are created lazily, so it would never be called.
+        #[cfg(debug_assertions)]
+        if sel == sel!(load) {
+            panic!(
+                "+[{} load] will never be called on classes created at runtime, use +initialize instead",
+                self.builder.name().to_string_lossy(),
+            );
+        }
+
         // SAFETY: Checked by caller
         unsafe { self.builder.add_class_method(sel, func) }
     }
@@ -376,7 +391,7 @@ impl<T: DefinedClass> ClassBuilderHelper<T> {
 
 /// Helper for ensuring that:
 /// - Only methods on the protocol are overridden.
-/// - TODO: The methods have the correct signature.
+/// - The methods have the correct signature.
 /// - All required methods are overridden.
 #[derive(Debug)]
 pub struct ClassProtocolMethodsBuilder<'a, T: ?Sized> {
@@ -397,6 +412,33 @@ pub struct ClassProtocolMethodsBuilder<'a, T: ?Sized> {
     registered_class_methods: HashSet<Sel>,
 }
 
+/// Verify that the signature of the method matches the one declared on the
+/// protocol.
+#[cfg(debug_assertions)]
+fn verify_protocol_method<F: MethodImplementation>(
+    protocol: &AnyProtocol,
+    sel: Sel,
+    types: &CStr,
+    kind: &str,
+) {
+    if cfg!(feature = "disable-encoding-assertions") {
+        return;
+    }
+
+    let types = types
+        .to_str()
+        .expect("protocol method type encoding must be UTF-8");
+    let res = crate::verify::verify_method_types(
+        MethodEncodingIter::new(types),
+        sel,
+        F::Arguments::ENCODINGS,
+        &F::Return::ENCODING_RETURN,
+    );
+    if let Err(err) = res {
+        panic!("defined invalid protocol method {kind}[{protocol} {sel}]: {err}")
+    }
+}
+
 impl<T: DefinedClass> ClassProtocolMethodsBuilder<'_, T> {
     // Addition: This restricts to callee `T`
     #[inline]
@@ -406,7 +448,7 @@ impl<T: DefinedClass> ClassProtocolMethodsBuilder<'_, T> {
     {
         #[cfg(debug_assertions)]
         if let Some(protocol) = self.protocol {
-            let _types = self
+            let types = self
                 .required_instance_methods
                 .iter()
                 .chain(&self.optional_instance_methods)
@@ -417,6 +459,7 @@ impl<T: DefinedClass> ClassProtocolMethodsBuilder<'_, T> {
                         "failed overriding protocol method -[{protocol} {sel}]: method not found"
                     )
                 });
+            verify_protocol_method::<F>(protocol, sel, types, "-");
         }
 
         // SAFETY: Checked by caller
@@ -435,7 +478,7 @@ impl<T: DefinedClass> ClassProtocolMethodsBuilder<'_, T> {
     {
         #[cfg(debug_assertions)]
         if let Some(protocol) = self.protocol {
-            let _types = self
+            let types = self
                 .required_class_methods
                 .iter()
                 .chain(&self.optional_class_methods)
@@ -446,6 +489,7 @@ impl<T: DefinedClass> ClassProtocolMethodsBuilder<'_, T> {
                         "failed overriding protocol method +[{protocol} {sel}]: method not found"
                     )
                 });
+            verify_protocol_method::<F>(protocol, sel, types, "+");
         }
 
         // SAFETY: Checked by caller
@@ -609,6 +609,53 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_ivars_dropped_before_superclass_dealloc() {
+        std::thread_local! {
+            static IVAR_DROPS: Cell<usize> = const { Cell::new(0) };
+            static IVAR_DROPS_SEEN_BY_SUPERCLASS: Cell<Option<usize>> = const { Cell::new(None) };
+        }
+
+        struct DropCounter;
+
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                IVAR_DROPS.with(|drops| drops.set(drops.get() + 1));
+            }
+        }
+
+        define_class!(
+            #[unsafe(super(NSObject))]
+            struct DropOrderSuperclass;
+        );
+
+        impl Drop for DropOrderSuperclass {
+            fn drop(&mut self) {
+                let drops = IVAR_DROPS.with(Cell::get);
+                IVAR_DROPS_SEEN_BY_SUPERCLASS.with(|seen| seen.set(Some(drops)));
+            }
+        }
+
+        define_class!(
+            #[unsafe(super(DropOrderSuperclass))]
+            #[ivars = DropCounter]
+            struct DropOrderSubclass;
+        );
+
+        let obj = DropOrderSubclass::alloc().set_ivars(DropCounter);
+        let obj: Retained<DropOrderSubclass> = unsafe { msg_send![super(obj), init] };
+        let clone = obj.retain();
+
+        drop(obj);
+        assert_eq!(IVAR_DROPS.with(Cell::get), 0);
+        assert_eq!(IVAR_DROPS_SEEN_BY_SUPERCLASS.with(Cell::get), None);
+
+        drop(clone);
+        // Dropped exactly once, before the superclass was deallocated.
+        assert_eq!(IVAR_DROPS.with(Cell::get), 1);
+        assert_eq!(IVAR_DROPS_SEEN_BY_SUPERCLASS.with(Cell::get), Some(1));
+    }
+
     #[test]
     fn test_no_generated_dealloc_if_not_needed() {
         #[allow(unused)]
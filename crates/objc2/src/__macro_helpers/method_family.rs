@@ -333,6 +333,10 @@ mod tests {
         assert_types_eq::<AllocFamily, __method_family!(()(alloc))>();
         assert_types_eq::<AllocFamily, __method_family!(()(allocWithZone:))>();
         assert_types_eq::<CopyFamily, __method_family!(()(copyItemAtURL:toURL:error:))>();
+        assert_types_eq::<MutableCopyFamily, __method_family!(()(mutableCopy))>();
+        assert_types_eq::<MutableCopyFamily, __method_family!(()(mutableCopyWithZone:))>();
+        assert_types_eq::<MutableCopyFamily, __method_family!(()(_mutableCopyPrivate))>();
+        assert_types_eq::<NoneFamily, __method_family!(()(mutableCopying))>();
         assert_types_eq::<NewFamily, __method_family!(()(new))>();
         assert_types_eq::<InitFamily, __method_family!(()(initWithArray:))>();
         assert_types_eq::<NoneFamily, __method_family!(()(somethingElse:))>();
@@ -318,7 +318,9 @@ mod tests {
 
     use super::*;
 
-    use crate::rc::{autoreleasepool, Allocated, PartialInit, RcTestObject, ThreadTestData};
+    use crate::rc::{
+        assert_no_leaks, autoreleasepool, Allocated, PartialInit, RcTestObject, ThreadTestData,
+    };
     use crate::runtime::{AnyObject, NSObject, NSObjectProtocol, NSZone};
     use crate::{class, define_class, extern_methods, msg_send, test_utils, AnyThread};
 
@@ -643,6 +645,21 @@ mod tests {
         let _obj: Retained<RcTestObject> = unsafe { msg_send![obj, initReturningNull] };
     }
 
+    #[test]
+    fn test_init_with_null_optional() {
+        let mut expected = ThreadTestData::current();
+
+        // The allocation is consumed even though `init` failed.
+        let obj: Allocated<RcTestObject> = unsafe { msg_send![RcTestObject::class(), alloc] };
+        expected.alloc += 1;
+        let res: Option<Retained<RcTestObject>> = unsafe { msg_send![obj, initReturningNull] };
+        assert!(res.is_none());
+        expected.release += 1;
+        // Drop flag ensures uninitialized do not Drop
+        // expected.drop += 1;
+        expected.assert_current();
+    }
+
     #[test]
     #[cfg_attr(debug_assertions, should_panic = "messsaging init to nil")]
     #[cfg_attr(not(debug_assertions), ignore = "failed allocating object")]
@@ -737,6 +754,33 @@ mod tests {
         expected.assert_current();
     }
 
+    #[test]
+    fn test_mutable_copy_with_zone() {
+        let obj = RcTestObject::new();
+        let mut expected = ThreadTestData::current();
+
+        // `mutableCopyWithZone:` is also in the `mutableCopy` family, and
+        // thus returns +1.
+        let zone: *const NSZone = ptr::null();
+        let _: Retained<RcTestObject> = unsafe { msg_send![&obj, mutableCopyWithZone: zone] };
+        expected.mutable_copy += 1;
+        expected.alloc += 1;
+        expected.init += 1;
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+    }
+
+    #[test]
+    fn test_mutable_copy_balanced() {
+        let obj = RcTestObject::new();
+        assert_no_leaks(|| {
+            let copy: Retained<RcTestObject> = unsafe { msg_send![&obj, mutableCopy] };
+            let copy2: Option<Retained<RcTestObject>> = unsafe { msg_send![&copy, mutableCopy] };
+            drop(copy2);
+        });
+    }
+
     // No method family
 
     #[test]
@@ -3,11 +3,37 @@
 //! TODO: Upstream some of this to `std`?
 use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 
 #[cfg(target_vendor = "apple")]
 mod apple;
 
-/// The size of the fields here are limited by Mach-O's `LC_BUILD_VERSION`.
+/// An operating system version, such as macOS 14.2.1.
+///
+/// This can be compared against other versions, and is mostly useful for
+/// logging or for more complex version checks; for simply gating the use of
+/// newer APIs, prefer the [`available!`] macro, as that will be statically
+/// evaluated if the deployment target is high enough.
+///
+/// [`available!`]: crate::available
+///
+///
+/// # Examples
+///
+/// Check that the OS is at least macOS 14.0 (on macOS).
+///
+/// ```
+/// use objc2::OSVersion;
+///
+/// if let Some(version) = OSVersion::current() {
+///     println!("running on OS version {version}");
+///     if cfg!(target_os = "macos") && version.is_at_least(14, 0, 0) {
+///         // Use an API introduced in macOS 14.0.
+///     }
+/// }
+/// ```
+//
+// The size of the fields here are limited by Mach-O's `LC_BUILD_VERSION`.
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct OSVersion {
@@ -15,18 +41,18 @@ pub struct OSVersion {
     // representation as the `u32` returned from `to_u32`, allowing
     // comparisons to compile down to just between two `u32`s.
     #[cfg(target_endian = "little")]
-    pub patch: u8,
+    pub(crate) patch: u8,
     #[cfg(target_endian = "little")]
-    pub minor: u8,
+    pub(crate) minor: u8,
     #[cfg(target_endian = "little")]
-    pub major: u16,
+    pub(crate) major: u16,
 
     #[cfg(target_endian = "big")]
-    pub major: u16,
+    pub(crate) major: u16,
     #[cfg(target_endian = "big")]
-    pub minor: u8,
+    pub(crate) minor: u8,
     #[cfg(target_endian = "big")]
-    pub patch: u8,
+    pub(crate) patch: u8,
 }
 
 #[track_caller]
@@ -78,7 +104,81 @@ impl OSVersion {
         patch: u8::MAX,
     };
 
+    /// Create a new version from its components.
+    #[inline]
+    pub const fn new(major: u16, minor: u8, patch: u8) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// The version of the operating system that the program is currently
+    /// running on.
+    ///
+    /// This is looked up at runtime, and cached after the first call.
+    /// Returns `None` on non-Apple platforms, where the operating system
+    /// version isn't comparable to the versions that Apple's APIs are
+    /// annotated with.
+    ///
+    /// Note that this may differ from (and is usually greater than) the
+    /// [deployment target](Self::deployment_target).
+    #[inline]
+    #[doc(alias = "operatingSystemVersion")]
+    pub fn current() -> Option<Self> {
+        #[cfg(target_vendor = "apple")]
+        return Some(apple::current_version());
+
+        #[cfg(not(target_vendor = "apple"))]
+        return None;
+    }
+
+    /// The minimum operating system version that the program was compiled to
+    /// support.
+    ///
+    /// This is determined at compile-time from e.g. the
+    /// `MACOSX_DEPLOYMENT_TARGET` environment variable, see the table in
+    /// [`available!`] for details. Returns `None` on non-Apple platforms.
+    ///
+    /// [`available!`]: crate::available
+    #[inline]
+    pub const fn deployment_target() -> Option<Self> {
+        #[cfg(target_vendor = "apple")]
+        return Some(apple::DEPLOYMENT_TARGET);
+
+        #[cfg(not(target_vendor = "apple"))]
+        return None;
+    }
+
+    /// The major version, e.g. `14` in macOS 14.2.1.
+    #[inline]
+    pub const fn major(self) -> u16 {
+        self.major
+    }
+
+    /// The minor version, e.g. `2` in macOS 14.2.1.
+    #[inline]
+    pub const fn minor(self) -> u8 {
+        self.minor
+    }
+
+    /// The patch version, e.g. `1` in macOS 14.2.1.
+    #[inline]
+    pub const fn patch(self) -> u8 {
+        self.patch
+    }
+
+    /// Whether this version is greater than or equal to the given version.
+    #[inline]
+    pub fn is_at_least(self, major: u16, minor: u8, patch: u8) -> bool {
+        Self::new(major, minor, patch) <= self
+    }
+
     /// Parse the version from a string at `const` time.
+    ///
+    /// Only public for use in the `available!` macro.
+    #[doc(hidden)]
     #[track_caller]
     pub const fn from_str(version: &str) -> Self {
         Self::from_bytes(version.as_bytes())
@@ -145,6 +245,7 @@ impl OSVersion {
     ///
     /// This is used for faster comparisons.
     #[inline]
+    #[doc(hidden)]
     pub const fn to_u32(self) -> u32 {
         // See comments in `OSVersion`, this should compile down to nothing.
         let (major, minor, patch) = (self.major as u32, self.minor as u32, self.patch as u32);
@@ -153,6 +254,7 @@ impl OSVersion {
 
     /// Construct the version from a `u32`.
     #[inline]
+    #[doc(hidden)]
     pub const fn from_u32(version: u32) -> Self {
         // See comments in `OSVersion`, this should compile down to nothing.
         let major = (version >> 16) as u16;
@@ -173,10 +275,32 @@ impl PartialEq for OSVersion {
     }
 }
 
+impl Eq for OSVersion {}
+
 impl PartialOrd for OSVersion {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.to_u32().partial_cmp(&other.to_u32())
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OSVersion {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_u32().cmp(&other.to_u32())
+    }
+}
+
+impl Hash for OSVersion {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_u32().hash(state);
+    }
+}
+
+impl fmt::Display for OSVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
     }
 }
 
@@ -272,6 +396,8 @@ pub fn is_available(version: AvailableVersion) -> bool {
 mod tests {
     use super::*;
 
+    use alloc::string::ToString;
+
     use crate::{__available_version, available};
 
     #[test]
@@ -381,6 +507,32 @@ mod tests {
         let _ = __available_version!(1.1.1000);
     }
 
+    #[test]
+    fn test_current() {
+        let version = OSVersion::new(10, 15, 2);
+        assert!(version.is_at_least(10, 15, 2));
+        assert!(version.is_at_least(10, 9, 0));
+        assert!(!version.is_at_least(10, 15, 3));
+        assert!(!version.is_at_least(11, 0, 0));
+        assert_eq!(version.to_string(), "10.15.2");
+
+        match (OSVersion::current(), OSVersion::deployment_target()) {
+            (Some(current), Some(deployment_target)) => {
+                // The runtime version is never lower than the deployment
+                // target, since we wouldn't be able to run otherwise.
+                assert!(deployment_target <= current);
+                assert!(current.is_at_least(
+                    deployment_target.major,
+                    deployment_target.minor,
+                    deployment_target.patch,
+                ));
+                assert!(!current.is_at_least(u16::MAX, 0, 0));
+            }
+            (None, None) => assert!(cfg!(not(target_vendor = "apple"))),
+            versions => panic!("mismatched versions: {versions:?}"),
+        }
+    }
+
     #[test]
     fn test_general_available() {
         // Always available
@@ -510,7 +510,7 @@ where
             // will have run.
             //
             // Caller ensures that the pointer is valid.
-            unsafe { set_finalized(ret.as_nonnull_ptr()) };
+            unsafe { set_finalized(Retained::as_non_null(&ret)) };
 
             Some(ret)
         } else {
@@ -564,7 +564,7 @@ where
             // will have run.
             //
             // Caller ensures that the pointer is valid.
-            unsafe { set_finalized(ret.as_nonnull_ptr()) };
+            unsafe { set_finalized(Retained::as_non_null(&ret)) };
 
             ret
         } else {
@@ -100,11 +100,30 @@
 /// allow mutating your instance variables. See [the docs on interior
 /// mutability][interior_mutability] for further details.
 ///
+/// The instance variables can be any Rust type, and are dropped when the
+/// object is deallocated. This includes closures and blocks (such as
+/// `block2::RcBlock`), which is useful for storing completion handlers that
+/// are invoked at a later point.
+///
+/// When the object is deallocated, the class' [`Drop`] implementation (if
+/// any) is run first, then the instance variables are dropped, and only then
+/// is `dealloc` called on the superclass. This means that the superclass is
+/// still usable while the instance variables are being dropped. If either
+/// of these panic, the panic is propagated to the caller of `release`, and
+/// the superclass' `dealloc` is not run (so the object is leaked).
+///
 /// Beware that if you want to use the class' inherited initializers (such as
 /// `init`), you must override the subclass' designated initializers, and
 /// initialize your ivars properly in there.
 ///
+/// If the class needs a variable amount of inline storage (such as the
+/// character data of a custom string class), you can allocate the instance
+/// with [`runtime::alloc_with_extra_bytes`] instead of `alloc`, and access
+/// the trailing storage with [`runtime::extra_bytes`].
+///
 /// [the instance variables]: crate::DefinedClass::Ivars
+/// [`runtime::alloc_with_extra_bytes`]: crate::runtime::alloc_with_extra_bytes
+/// [`runtime::extra_bytes`]: crate::runtime::extra_bytes
 /// [`Cell`]: core::cell::Cell
 /// [`RefCell`]: core::cell::RefCell
 /// [interior_mutability]: crate::topics::interior_mutability
@@ -182,6 +201,16 @@
 /// Note that `&mut Retained<_>` and other such out parameters are not yet
 /// supported, and may generate a panic at runtime.
 ///
+/// You can implement the `+initialize` class method to run code once before
+/// the class receives its first message. Note though that (same as in
+/// Objective-C) the runtime will also send `+initialize` to the superclass
+/// implementation for subclasses that do not implement it themselves.
+///
+/// `+load` is not supported, since the class is created lazily at runtime
+/// when [`ClassType::class`] is first called, and the runtime only sends
+/// `+load` to classes that are present when a binary is loaded. Trying to
+/// implement it will panic if debug assertions are enabled.
+///
 /// ["associated functions"]: https://doc.rust-lang.org/reference/items/associated-items.html#methods
 /// ["methods"]: https://doc.rust-lang.org/reference/items/associated-items.html#methods
 /// [`Cell`]: core::cell::Cell
@@ -198,7 +227,11 @@
 /// The protocol must have been previously defined with [`extern_protocol!`].
 ///
 /// The methods work exactly as normal, they're only put "under" the protocol
-/// definition to make things easier to read.
+/// definition to make things easier to read. This is also useful when
+/// implementing delegates, since with debug assertions enabled, the method
+/// signatures are checked against the ones declared on the protocol when the
+/// class is registered, which catches mistakes in argument and return types
+/// early.
 ///
 /// Putting attributes on the `impl` item such as `cfg`, `allow`, `doc`,
 /// `deprecated` and so on is supported.
@@ -215,6 +248,8 @@
 ///   equal to the one on the superclass.
 /// - Debug assertions are enabled, and the protocol's required methods are not
 ///   implemented.
+/// - Debug assertions are enabled, and a protocol method's signature is not
+///   equal to the one declared on the protocol.
 ///
 /// And possibly more similar cases in the future.
 ///
@@ -550,6 +585,12 @@ macro_rules! __define_class_inner {
             ($($superclass, $($superclasses,)*)? $crate::runtime::AnyObject)
         }
 
+        $crate::__extern_class_impl_as_ref_protocol! {
+            ($($attr_impl)*)
+            ()
+            ($class)
+        }
+
         $crate::__define_class_derives! {
             ($($attr_impl)*)
             ($class)
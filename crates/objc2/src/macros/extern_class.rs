@@ -24,6 +24,8 @@
 /// - [`ClassType`][crate::ClassType]
 /// - [`DowncastTarget`][$crate::DowncastTarget]
 /// - [`AsRef<$inheritance_chain>`][AsRef]
+/// - [`AsRef<ProtocolObject<P>>`][AsRef] for all protocols `P` that the class
+///   implements.
 /// - [`Borrow<$inheritance_chain>`][core::borrow::Borrow]
 ///
 /// If generics are specified, these will be placed in a [`PhantomData`].
@@ -268,6 +270,12 @@ macro_rules! __extern_class_inner {
             ($($superclass, $($superclasses,)*)? $crate::runtime::AnyObject)
         }
 
+        $crate::__extern_class_impl_as_ref_protocol! {
+            ($($attr_impl)*)
+            ($($($generic: $($($bounds)+ +)? $crate::Message,)+)?)
+            ($class $(<$($generic),*>)?)
+        }
+
         $crate::__extern_class_derives! {
             ($($attr_impl)*)
             (impl $(<$($generic: $($($bounds)+)?),+>)?)
@@ -505,6 +513,33 @@ macro_rules! __extern_class_impl_as_ref_borrow {
     };
 }
 
+/// Implement `AsRef<ProtocolObject<P>>` for all protocols `P` that the class
+/// implements.
+///
+/// This cannot be a blanket impl, since that would conflict with the other
+/// `AsRef` impls.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __extern_class_impl_as_ref_protocol {
+    (
+        ($($attr_impl:tt)*)
+        ($($generics:tt)*)
+        ($($for:tt)*)
+    ) => {
+        $($attr_impl)*
+        impl<$($generics)* __P: ?$crate::__macro_helpers::Sized>
+            $crate::__macro_helpers::AsRef<$crate::runtime::ProtocolObject<__P>> for $($for)*
+        where
+            __P: $crate::runtime::ImplementedBy<$($for)*>,
+        {
+            #[inline]
+            fn as_ref(&self) -> &$crate::runtime::ProtocolObject<__P> {
+                $crate::runtime::ProtocolObject::from_ref(self)
+            }
+        }
+    };
+}
+
 /// Note: We intentionally don't add e.g. `T: PartialEq`, as generic objects
 /// are always comparable, hashable and debuggable, regardless of their
 /// generic parameters.
@@ -22,6 +22,16 @@
 /// user will use to access the functionality, but is otherwise not used by
 /// the macro.
 ///
+/// The parameter types become part of the function's signature, and are
+/// thus checked by the compiler at every call site; passing e.g. an `f32` to
+/// a method declared as taking `f64` fails to compile, instead of sending
+/// the wrong bits to the method (which a bare [`msg_send!`] cannot catch,
+/// since it doesn't know the method's signature until runtime). Each type
+/// must implement [`Encode`], and the same conversions as in [`msg_send!`]
+/// apply, so that e.g. `bool` can be used for a `BOOL` parameter.
+///
+/// [`Encode`]: crate::Encode
+///
 /// If you use `objc2::MainThreadMarker` as a parameter type, the macro will
 /// ignore it, allowing you to neatly specify "this method must be run on the
 /// main thread". Note that due to type-system limitations, this is currently
@@ -48,6 +48,13 @@
 /// This currently doesn't have any effect, but probably will have one in the
 /// future when implementing protocols in [`define_class!`].
 ///
+/// Note that both required and optional methods get a default
+/// implementation, since the trait is used for calling methods on objects
+/// that conform to the protocol. Implementing a protocol is instead done in
+/// [`define_class!`], where you only need to provide the methods that you
+/// actually want to implement (though you should make sure to implement all
+/// required methods, as the compiler won't check that for you).
+///
 /// This macro otherwise shares similarities with [`extern_class!`] and
 /// [`extern_methods!`].
 ///
@@ -220,8 +227,15 @@ macro_rules! __inner_extern_protocol {
             const __INNER: () = ();
         }
 
-        // TODO: Should we also implement `ImplementedBy` for `Send + Sync`
-        // types, as is done for `NSObjectProtocol`?
+        // SAFETY: Anything that implements the protocol and is `Send + Sync`
+        // is valid to convert to `ProtocolObject<dyn [PROTO] + Send + Sync>`.
+        $($attr_impl)*
+        unsafe impl<T> $crate::runtime::ImplementedBy<T> for dyn $protocol + $crate::__macro_helpers::Send + $crate::__macro_helpers::Sync
+        where
+            T: ?$crate::__macro_helpers::Sized + $crate::Message + $protocol + $crate::__macro_helpers::Send + $crate::__macro_helpers::Sync
+        {
+            const __INNER: () = ();
+        }
 
         $crate::__extern_protocol_check_no_super!($($superclasses)*);
 
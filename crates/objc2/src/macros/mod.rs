@@ -963,6 +963,17 @@ macro_rules! __class_inner {
 /// [`Vec`]: std::vec::Vec
 ///
 ///
+/// ## Tracing
+///
+/// If the `"tracing"` Cargo feature is enabled, each message send is wrapped
+/// in a [`tracing`] span named `msg_send` (or `msg_send_super`) at the trace
+/// level, with the selector recorded in the `selector` field. The time spent
+/// in the method can then be measured by the subscriber, which is useful for
+/// finding hot or slow methods.
+///
+/// [`tracing`]: https://docs.rs/tracing
+///
+///
 /// # Safety
 ///
 /// Similar to defining and calling an `extern` function in a foreign function
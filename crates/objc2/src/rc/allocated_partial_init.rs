@@ -29,6 +29,31 @@ use crate::{DefinedClass, Message};
 /// [object-allocation]: https://developer.apple.com/library/archive/documentation/General/Conceptual/CocoaEncyclopedia/ObjectAllocation/ObjectAllocation.html
 ///
 ///
+/// # Initialization
+///
+/// The only messages that can be sent to an `Allocated` object are those in
+/// the `init` family, and sending such a message with [`msg_send!`] consumes
+/// the `Allocated`. This ensures that the object is initialized at most
+/// once, and that it cannot be used before it is initialized.
+///
+/// The allocation is consumed even if the initializer fails and returns
+/// `nil`, since the initializer is responsible for releasing the object in
+/// that case.
+///
+/// ```compile_fail,E0382
+/// use objc2::rc::{Allocated, Retained};
+/// use objc2::runtime::NSObject;
+/// use objc2::{msg_send, AnyThread};
+///
+/// let obj: Allocated<NSObject> = NSObject::alloc();
+/// let _: Retained<NSObject> = unsafe { msg_send![obj, init] };
+/// // Not allowed, `obj` was consumed above.
+/// let _: Retained<NSObject> = unsafe { msg_send![obj, init] };
+/// ```
+///
+/// [`msg_send!`]: crate::msg_send
+///
+///
 /// # Memory layout
 ///
 /// This is guaranteed to have the same size and alignment as a pointer to the
@@ -117,6 +142,26 @@ impl<T: ?Sized + Message> Allocated<T> {
         }
     }
 
+    /// Allocate the object with `class_createInstance`, with the given
+    /// amount of extra bytes after the instance variables.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Allocated::alloc`].
+    #[doc(alias = "class_createInstance")]
+    #[inline]
+    #[cfg(not(feature = "unstable-objfw"))]
+    pub(crate) unsafe fn alloc_with_extra_bytes(cls: &AnyClass, extra_bytes: usize) -> Self
+    where
+        T: Sized,
+    {
+        // SAFETY: Thread safety checked by the caller.
+        let obj: *mut T = unsafe { crate::ffi::class_createInstance(cls, extra_bytes).cast() };
+        // SAFETY: The object is newly allocated, so this has +1 retain count
+        unsafe { Self::new(obj) }
+    }
+
     /// Returns a raw pointer to the object.
     ///
     /// The pointer is valid for at least as long as the `Allocated` is held.
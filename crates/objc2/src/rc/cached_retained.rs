@@ -0,0 +1,142 @@
+use core::fmt;
+use std::sync::OnceLock;
+
+use super::Retained;
+use crate::Message;
+
+/// A lazily initialized [`Retained`] that can be stored in a `static`.
+///
+/// This is useful for caching singletons exposed via `+sharedInstance`-style
+/// methods, such that subsequent accesses do not need to send a message.
+///
+/// The object is never released, as `static`s are never dropped.
+///
+/// To store this in a `static`, `T` must be [`Send`] and [`Sync`]. Objects
+/// that are not thread-safe cannot be cached this way, and must instead be
+/// retrieved with a message send each time (or cached in a thread local).
+///
+///
+/// # Singletons that may change
+///
+/// Only cache objects that are guaranteed to stay the same for the lifetime
+/// of the process. Some singletons can be reset or replaced, such as
+/// `NSUserDefaults.standardUserDefaults` after calling
+/// `+resetStandardUserDefaults`; caching those would keep returning the old
+/// object.
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2::rc::{CachedRetained, Retained};
+/// use objc2::runtime::NSObject;
+/// use objc2::{define_class, msg_send, ClassType};
+///
+/// define_class!(
+///     #[unsafe(super(NSObject))]
+///     struct MyManager;
+///
+///     impl MyManager {
+///         #[unsafe(method_id(sharedManager))]
+///         fn __shared_manager() -> Retained<Self> {
+///             unsafe { msg_send![Self::class(), new] }
+///         }
+///     }
+/// );
+///
+/// impl MyManager {
+///     fn shared() -> &'static Self {
+///         static SHARED: CachedRetained<MyManager> = CachedRetained::new();
+///         SHARED.get_or_init(|| unsafe { msg_send![Self::class(), sharedManager] })
+///     }
+/// }
+///
+/// // Only the first access sends `sharedManager`, and the same object is
+/// // returned every time.
+/// assert!(core::ptr::eq(MyManager::shared(), MyManager::shared()));
+/// ```
+pub struct CachedRetained<T: ?Sized> {
+    inner: OnceLock<Retained<T>>,
+}
+
+impl<T: ?Sized + Message> CachedRetained<T> {
+    /// Create a new, uninitialized cache.
+    #[inline]
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self {
+            inner: OnceLock::new(),
+        }
+    }
+
+    /// Get the cached object, initializing it with `f` if it has not been
+    /// initialized yet.
+    ///
+    /// If multiple threads call this concurrently, only one of them will
+    /// run `f`, and the others will block until it is done.
+    #[inline]
+    pub fn get_or_init(&self, f: impl FnOnce() -> Retained<T>) -> &T {
+        self.inner.get_or_init(f)
+    }
+
+    /// Get the cached object, or `None` if it has not been initialized yet.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        self.inner.get().map(|obj| &**obj)
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for CachedRetained<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CachedRetained")
+            .field(&self.inner.get())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+    use super::*;
+    use crate::runtime::{AnyObject, NSObject};
+    use crate::{define_class, msg_send, ClassType};
+
+    static SHARED_INSTANCE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        #[name = "CachedRetainedSingleton"]
+        struct Singleton;
+
+        impl Singleton {
+            #[unsafe(method_id(sharedInstance))]
+            fn shared_instance() -> Retained<Self> {
+                SHARED_INSTANCE_CALLS.fetch_add(1, Ordering::Relaxed);
+                // Return a new object every time, so that we can tell
+                // whether the result was cached.
+                unsafe { msg_send![Self::class(), new] }
+            }
+        }
+    );
+
+    assert_impl_all!(CachedRetained<Singleton>: Send, Sync);
+    assert_not_impl_any!(CachedRetained<NSObject>: Sync);
+    assert_not_impl_any!(CachedRetained<AnyObject>: Sync);
+
+    #[test]
+    fn test_cached_singleton() {
+        static SHARED: CachedRetained<Singleton> = CachedRetained::new();
+        let shared =
+            || SHARED.get_or_init(|| unsafe { msg_send![Singleton::class(), sharedInstance] });
+
+        assert!(SHARED.get().is_none());
+        let obj1 = shared();
+        let obj2 = shared();
+        assert!(core::ptr::eq(obj1, obj2));
+        assert!(core::ptr::eq(obj1, SHARED.get().unwrap()));
+        assert_eq!(SHARED_INSTANCE_CALLS.load(Ordering::Relaxed), 1);
+    }
+}
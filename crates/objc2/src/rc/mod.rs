@@ -51,6 +51,7 @@
 
 mod allocated_partial_init;
 mod autorelease;
+mod cached_retained;
 mod retained;
 mod retained_forwarding_impls;
 mod retained_traits;
@@ -62,13 +63,14 @@ pub use self::allocated_partial_init::{Allocated, PartialInit};
 pub use self::autorelease::{
     autoreleasepool, autoreleasepool_leaking, AutoreleasePool, AutoreleaseSafe,
 };
+pub use self::cached_retained::CachedRetained;
 // Re-export `Id` for backwards compatibility, but still mark it as deprecated.
 #[allow(deprecated)]
 pub use self::retained::Id;
 pub use self::retained::Retained;
 pub use self::retained_traits::{DefaultRetained, RetainedFromIterator, RetainedIntoIterator};
 #[cfg(test)]
-pub(crate) use self::test_object::{RcTestObject, ThreadTestData};
+pub(crate) use self::test_object::{assert_no_leaks, RcTestObject, ThreadTestData};
 pub use self::weak::Weak;
 // Same as above.
 #[allow(deprecated)]
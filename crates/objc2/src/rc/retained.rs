@@ -3,6 +3,7 @@ use core::marker::PhantomData;
 use core::mem::ManuallyDrop;
 use core::ops::Deref;
 use core::panic::{RefUnwindSafe, UnwindSafe};
+use core::pin::Pin;
 use core::ptr::{self, NonNull};
 
 use super::AutoreleasePool;
@@ -67,6 +68,32 @@ use crate::{ffi, ClassType, DowncastTarget, Message};
 /// [`RetainedFromIterator`]: crate::rc::RetainedFromIterator
 ///
 ///
+/// # Pinning
+///
+/// Objective-C objects are always allocated on the heap by the runtime, and
+/// are never moved from there for as long as they're alive; `Retained<T>`
+/// only ever hands out shared references to the object, so there is no way
+/// to move it out either.
+///
+/// This means that `Retained<T>` is effectively always pinned, and that
+/// `Retained<T>` can be [`Unpin`] regardless of `T`, just like `Arc<T>`. If
+/// you need to pass the object somewhere that requires [`Pin`], you can use
+/// [`Retained::into_pin`] or [`Retained::as_pin`].
+///
+/// In particular, this also applies to the instance variables of classes
+/// created with [`define_class!`], which live inside the object itself. So
+/// self-referential ivars (such as a future that is polled from within a
+/// method) can soundly be projected to from a `Pin<&Self>` with
+/// [`Pin::map_unchecked`].
+///
+/// Note however that the ivars _are_ moved into the object when it is
+/// initialized (with e.g. [`Allocated::set_ivars`]), so they must not be
+/// self-referential before that.
+///
+/// [`define_class!`]: crate::define_class
+/// [`Allocated::set_ivars`]: crate::rc::Allocated::set_ivars
+///
+///
 /// # Memory layout
 ///
 /// This is guaranteed to have the same size and alignment as a pointer to the
@@ -268,9 +295,49 @@ impl<T: ?Sized + Message> Retained<T> {
         this.ptr.as_ptr()
     }
 
+    /// Returns a non-null pointer to the object.
+    ///
+    /// This is the same as [`Retained::as_ptr`], except that it returns a
+    /// [`NonNull`]. The pointer is valid for at least as long as the
+    /// `Retained` is held; you must retain the object yourself if you need
+    /// the pointer to outlive it.
+    ///
+    /// This does not change the retain count of the object.
+    ///
+    /// This is an associated method, and must be called as
+    /// `Retained::as_non_null(obj)`.
     #[inline]
-    pub(crate) fn as_nonnull_ptr(&self) -> NonNull<T> {
-        self.ptr
+    pub fn as_non_null(this: &Self) -> NonNull<T> {
+        this.ptr
+    }
+
+    /// Convert the `Retained` into a pinned pointer.
+    ///
+    /// This is always safe, since the object is never moved (see [the
+    /// section on pinning](#pinning)), and does not change the retain count
+    /// of the object.
+    ///
+    /// This is an associated method, and must be called as
+    /// `Retained::into_pin(obj)`.
+    #[inline]
+    pub fn into_pin(this: Self) -> Pin<Self> {
+        // SAFETY: The object lives on the heap, and `Retained` does not
+        // allow moving it out of there. Any other `Retained` (or reference)
+        // to the object can likewise only access it through `&T`.
+        unsafe { Pin::new_unchecked(this) }
+    }
+
+    /// Get a pinned reference to the object.
+    ///
+    /// This is always safe, since the object is never moved (see [the
+    /// section on pinning](#pinning)).
+    ///
+    /// This is an associated method, and must be called as
+    /// `Retained::as_pin(obj)`.
+    #[inline]
+    pub fn as_pin(this: &Self) -> Pin<&T> {
+        // SAFETY: Same as in `into_pin`.
+        unsafe { Pin::new_unchecked(this) }
     }
 
     #[inline]
@@ -815,6 +882,27 @@ impl<P: ?Sized + 'static> From<Retained<ProtocolObject<P>>> for Retained<AnyObje
     }
 }
 
+impl<T: DowncastTarget> TryFrom<Retained<AnyObject>> for Retained<T> {
+    type Error = Retained<AnyObject>;
+
+    /// Attempt to downcast the object to a class of type `T`.
+    ///
+    /// This is the same as [`Retained::downcast`], see that for details.
+    ///
+    /// Note that protocol objects such as `ProtocolObject<dyn MyProtocol>`
+    /// cannot be the target of a downcast, since they do not have a class
+    /// to check against. Downcast to a concrete class first, and then use
+    /// [`ProtocolObject::from_retained`].
+    ///
+    /// # Errors
+    ///
+    /// If casting failed, this will return the object back as the error.
+    #[inline]
+    fn try_from(obj: Retained<AnyObject>) -> Result<Self, Self::Error> {
+        obj.downcast()
+    }
+}
+
 /// `Retained<T>` is `Send` if `T` is `Send + Sync`.
 //
 // SAFETY:
@@ -987,6 +1075,53 @@ mod tests {
         expected.assert_current();
     }
 
+    #[test]
+    fn test_as_non_null() {
+        let obj = RcTestObject::new();
+        let expected = ThreadTestData::current();
+
+        let ptr = Retained::as_non_null(&obj);
+        assert_eq!(ptr.as_ptr().cast_const(), Retained::as_ptr(&obj));
+        expected.assert_current();
+
+        let retain_count: usize = unsafe { msg_send![ptr, retainCount] };
+        assert_eq!(retain_count, 1);
+    }
+
+    #[test]
+    fn test_pin() {
+        use core::marker::PhantomPinned;
+
+        use crate::AnyThread;
+
+        define_class!(
+            #[unsafe(super(NSObject))]
+            #[name = "TestPinnedIvars"]
+            #[ivars = PhantomPinned]
+            struct Pinned;
+        );
+
+        fn requires_pin(obj: Pin<&Pinned>) -> *const Pinned {
+            &*obj
+        }
+
+        let obj: Retained<Pinned> = {
+            let obj = Pinned::alloc().set_ivars(PhantomPinned);
+            unsafe { msg_send![super(obj), init] }
+        };
+        assert_not_impl_any!(Pinned: Unpin);
+        assert_impl_all!(Retained<Pinned>: Unpin);
+
+        let ptr = Retained::as_ptr(&obj);
+        assert_eq!(requires_pin(Retained::as_pin(&obj)), ptr);
+
+        // Moving the `Retained` does not move the object.
+        let obj = Retained::into_pin(obj);
+        let obj = [obj];
+        assert_eq!(requires_pin(obj[0].as_ref()), ptr);
+        assert_eq!(obj[0].retainCount(), 1);
+    }
+
     #[test]
     fn test_cast() {
         let obj: Retained<RcTestObject> = RcTestObject::new();
@@ -999,6 +1134,31 @@ mod tests {
         expected.assert_current();
     }
 
+    #[test]
+    fn test_try_from() {
+        let obj: Retained<AnyObject> = RcTestObject::new().into();
+        let expected = ThreadTestData::current();
+
+        let obj = Retained::<RcTestObject>::try_from(obj).unwrap();
+        expected.assert_current();
+
+        let obj: Retained<AnyObject> = obj.into();
+        let obj: Result<Retained<NSObject>, _> = obj.try_into();
+        let _obj: Retained<NSObject> = obj.unwrap();
+        expected.assert_current();
+    }
+
+    #[test]
+    fn test_try_from_fails() {
+        let obj: Retained<AnyObject> = NSObject::new().into();
+        let ptr = Retained::as_ptr(&obj);
+
+        let obj = Retained::<RcTestObject>::try_from(obj).unwrap_err();
+        // The original object is returned.
+        assert_eq!(Retained::as_ptr(&obj), ptr);
+        assert_eq!(obj.class(), NSObject::class());
+    }
+
     #[repr(C)]
     struct MyObject<'a> {
         inner: NSObject,
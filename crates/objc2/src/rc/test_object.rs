@@ -2,7 +2,7 @@
 use core::cell::RefCell;
 use core::ptr;
 
-use crate::rc::{Allocated, DefaultRetained, Retained};
+use crate::rc::{autoreleasepool, Allocated, DefaultRetained, Retained};
 use crate::runtime::{NSObject, NSObjectProtocol, NSZone};
 use crate::{define_class, msg_send, ClassType};
 
@@ -56,6 +56,40 @@ impl ThreadTestData {
     }
 }
 
+/// Run the given closure inside an autorelease pool, and assert that every
+/// [`RcTestObject`] that it created was deallocated, and that every retain it
+/// performed was balanced by a release.
+///
+/// The counts are compared after the pool has been drained, so objects that
+/// are autoreleased inside the closure are correctly accounted for.
+#[track_caller]
+#[cfg(test)]
+pub(crate) fn assert_no_leaks(f: impl FnOnce()) {
+    let before = ThreadTestData::current();
+    autoreleasepool(|_| f());
+    let current = ThreadTestData::current();
+
+    let created = current.alloc - before.alloc;
+    // Creating an object gives it a retain count of 1, and a successful
+    // `_tryRetain` is also a retain (GNUStep uses `retain` for that).
+    let retains =
+        created + (current.retain - before.retain) + (current.try_retain - before.try_retain);
+
+    // Every created object must have been deallocated, and every retain
+    // must have been balanced by a release.
+    let mut expected = current.clone();
+    expected.drop = before.drop + created;
+    expected.release = before.release + retains;
+
+    if current != expected {
+        panic!(
+            "leaked objects:
+   current: `{current:?}`,
+  expected: `{expected:?}`"
+        )
+    }
+}
+
 std::thread_local! {
     static TEST_DATA: RefCell<ThreadTestData> = RefCell::default();
 }
@@ -318,3 +352,44 @@ impl RcTestObject {
         unsafe { Retained::from_raw(msg_send![Self::class(), new]) }.unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_leaks() {
+        assert_no_leaks(|| {
+            let obj = RcTestObject::new();
+            let clone = obj.clone();
+            drop(obj);
+            // Only released when the pool is drained.
+            let _ = Retained::autorelease_ptr(clone);
+        });
+    }
+
+    #[test]
+    fn no_leaks_existing_object() {
+        let obj = RcTestObject::new();
+        assert_no_leaks(|| {
+            let _ = Retained::autorelease_ptr(obj.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic = "leaked objects"]
+    fn leak_detected() {
+        assert_no_leaks(|| {
+            let _ = Retained::into_raw(RcTestObject::new());
+        });
+    }
+
+    #[test]
+    #[should_panic = "leaked objects"]
+    fn retain_leak_detected() {
+        let obj = RcTestObject::new();
+        assert_no_leaks(|| {
+            let _ = Retained::into_raw(obj.clone());
+        });
+    }
+}
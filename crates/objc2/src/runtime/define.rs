@@ -188,7 +188,7 @@ impl ClassBuilder {
     }
 
     #[allow(unused)]
-    fn name(&self) -> &CStr {
+    pub(crate) fn name(&self) -> &CStr {
         // SAFETY: Same as `superclass`
         unsafe { AnyClass::name_raw(self.cls.as_ptr()) }
     }
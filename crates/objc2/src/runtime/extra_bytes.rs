@@ -0,0 +1,62 @@
+use core::ptr::NonNull;
+
+use crate::rc::Allocated;
+use crate::runtime::AnyObject;
+use crate::{ffi, ClassType};
+
+/// Allocate an instance of the class with `extra_bytes` of additional
+/// zero-initialized storage placed after the instance variables.
+///
+/// This is useful for classes that store a variable amount of data inline,
+/// and the storage can be accessed with [`extra_bytes`]. It lives as long as
+/// the instance itself.
+///
+///
+/// # Safety
+///
+/// This calls `class_createInstance` directly instead of sending `alloc`, so
+/// the class (and its superclasses) must not rely on a custom `+alloc` or
+/// `+allocWithZone:` implementation. Classes created with [`define_class!`]
+/// whose superclass is `NSObject` are fine in this regard.
+///
+/// Additionally, the class must be safe to allocate on the current thread,
+/// see [`AnyThread`] and [`MainThreadOnly`].
+///
+/// [`define_class!`]: crate::define_class
+/// [`AnyThread`]: crate::AnyThread
+/// [`MainThreadOnly`]: crate::MainThreadOnly
+#[inline]
+#[doc(alias = "class_createInstance")]
+#[cfg(not(feature = "unstable-objfw"))]
+pub unsafe fn alloc_with_extra_bytes<T: ClassType>(extra_bytes: usize) -> Allocated<T> {
+    // SAFETY: Upheld by the caller.
+    unsafe { Allocated::alloc_with_extra_bytes(T::class(), extra_bytes) }
+}
+
+/// A pointer to the extra storage that the object was allocated with in
+/// [`alloc_with_extra_bytes`].
+///
+/// The pointer is valid for as long as the object is. It is aligned to the
+/// pointer size on Apple platforms, but no such guarantee is made on GNUStep,
+/// so you should use unaligned reads and writes if you store anything but
+/// bytes there.
+///
+///
+/// # Safety
+///
+/// The object must have been allocated with [`alloc_with_extra_bytes`].
+///
+/// The runtime doesn't keep track of the size of the storage, so you will
+/// have to do that yourself (e.g. in your instance variables); reading or
+/// writing past the amount of bytes that were allocated is undefined
+/// behaviour.
+#[inline]
+#[doc(alias = "object_getIndexedIvars")]
+#[cfg(not(feature = "unstable-objfw"))]
+pub unsafe fn extra_bytes(obj: &AnyObject) -> NonNull<u8> {
+    let ptr: *const AnyObject = obj;
+    // SAFETY: The pointer is a valid object.
+    let extra = unsafe { ffi::object_getIndexedIvars(ptr) };
+    // SAFETY: The runtime never returns NULL for valid objects.
+    unsafe { NonNull::new_unchecked(extra.cast_mut().cast()) }
+}
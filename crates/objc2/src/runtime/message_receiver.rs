@@ -10,30 +10,54 @@ use crate::Message;
 /// This is a macro to help with monomorphization when the feature is
 /// disabled, as well as improving the final stack trace (`#[track_caller]`
 /// doesn't really work on closures).
+///
+/// The class and selector of the message send are included in the panic
+/// message, to make e.g. unrecognized selectors easier to debug. These are
+/// only formatted when an exception actually occurred.
 #[cfg(not(feature = "catch-all"))]
 macro_rules! conditional_try {
-    (|| $expr:expr) => {
+    (|| $expr:expr, $cls:expr, $sel:expr) => {
         $expr
     };
 }
 
 #[cfg(feature = "catch-all")]
 macro_rules! conditional_try {
-    (|| $expr:expr) => {{
+    (|| $expr:expr, $cls:expr, $sel:expr) => {{
         let f = core::panic::AssertUnwindSafe(|| $expr);
         match crate::exception::catch(f) {
             Ok(r) => r,
             Err(exception) => {
+                let context = MessageContext($cls, $sel);
                 if let Some(exception) = exception {
-                    panic!("uncaught {exception:?}\n{}", exception.stack_trace())
+                    panic!(
+                        "uncaught {exception:?}\nwhile sending {context}\n{}",
+                        exception.stack_trace()
+                    )
                 } else {
-                    panic!("uncaught exception nil")
+                    panic!("uncaught exception nil\nwhile sending {context}")
                 }
             }
         }
     }};
 }
 
+/// Formats a message send as e.g. `-[NSObject description]`.
+#[cfg(feature = "catch-all")]
+struct MessageContext<'a>(Option<&'a AnyClass>, Sel);
+
+#[cfg(feature = "catch-all")]
+impl core::fmt::Display for MessageContext<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Self(cls, sel) = self;
+        match cls {
+            Some(cls) if cls.is_metaclass() => write!(f, "+[{cls} {sel}]"),
+            Some(cls) => write!(f, "-[{cls} {sel}]"),
+            None => write!(f, "message {sel} to nil"),
+        }
+    }
+}
+
 // More information on how objc_msgSend works:
 // <https://web.archive.org/web/20200118080513/http://www.friday.com/bbum/2009/12/18/objc_msgsend-part-1-the-road-map/>
 // <https://www.mikeash.com/pyblog/objc_msgsends-new-prototype.html>
@@ -190,6 +214,111 @@ mod msg_send_primitive {
         let msg_send_fn = R::MSG_SEND_SUPER;
         unsafe { A::__invoke(msg_send_fn, receiver, sel, args) }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::encode::Encode;
+
+        #[allow(dead_code)]
+        #[repr(C)]
+        struct Bytes<const N: usize>([u8; N]);
+
+        unsafe impl<const N: usize> Encode for Bytes<N> {
+            const ENCODING: Encoding =
+                Encoding::Struct("Bytes", &[Encoding::Array(N as u64, &u8::ENCODING)]);
+        }
+
+        fn msg_send_fn<R: EncodeReturn>() -> usize {
+            R::MSG_SEND as usize
+        }
+
+        fn msg_send_super_fn<R: EncodeReturn>() -> usize {
+            R::MSG_SEND_SUPER as usize
+        }
+
+        #[test]
+        #[cfg(target_arch = "aarch64")]
+        fn select_aarch64() {
+            let normal = ffi::objc_msgSend as usize;
+            assert_eq!(msg_send_fn::<()>(), normal);
+            assert_eq!(msg_send_fn::<f64>(), normal);
+            assert_eq!(msg_send_fn::<Bytes<64>>(), normal);
+            assert_eq!(
+                msg_send_super_fn::<Bytes<64>>(),
+                ffi::objc_msgSendSuper as usize
+            );
+        }
+
+        #[test]
+        #[cfg(target_arch = "arm")]
+        fn select_arm() {
+            let normal = ffi::objc_msgSend as usize;
+            let stret = ffi::objc_msgSend_stret as usize;
+            assert_eq!(msg_send_fn::<()>(), normal);
+            assert_eq!(msg_send_fn::<u32>(), normal);
+            assert_eq!(msg_send_fn::<u64>(), normal);
+            assert_eq!(msg_send_fn::<f64>(), normal);
+            assert_eq!(msg_send_fn::<Bytes<4>>(), normal);
+            // Composite types larger than 4 bytes are returned in memory.
+            assert_eq!(msg_send_fn::<Bytes<5>>(), stret);
+            assert_eq!(msg_send_fn::<Bytes<8>>(), stret);
+            assert_eq!(
+                msg_send_super_fn::<Bytes<4>>(),
+                ffi::objc_msgSendSuper as usize
+            );
+            assert_eq!(
+                msg_send_super_fn::<Bytes<5>>(),
+                ffi::objc_msgSendSuper_stret as usize
+            );
+        }
+
+        #[test]
+        #[cfg(target_arch = "x86")]
+        fn select_x86() {
+            let normal = ffi::objc_msgSend as usize;
+            let stret = ffi::objc_msgSend_stret as usize;
+            let fpret = ffi::objc_msgSend_fpret as usize;
+            assert_eq!(msg_send_fn::<()>(), normal);
+            assert_eq!(msg_send_fn::<u64>(), normal);
+            assert_eq!(msg_send_fn::<f32>(), fpret);
+            assert_eq!(msg_send_fn::<f64>(), fpret);
+            // Only structs of size 1, 2, 4 or 8 are returned in registers.
+            assert_eq!(msg_send_fn::<Bytes<1>>(), normal);
+            assert_eq!(msg_send_fn::<Bytes<2>>(), normal);
+            assert_eq!(msg_send_fn::<Bytes<3>>(), stret);
+            assert_eq!(msg_send_fn::<Bytes<4>>(), normal);
+            assert_eq!(msg_send_fn::<Bytes<6>>(), stret);
+            assert_eq!(msg_send_fn::<Bytes<8>>(), normal);
+            assert_eq!(msg_send_fn::<Bytes<12>>(), stret);
+            // There is no `objc_msgSendSuper_fpret`.
+            assert_eq!(msg_send_super_fn::<f64>(), ffi::objc_msgSendSuper as usize);
+            assert_eq!(
+                msg_send_super_fn::<Bytes<12>>(),
+                ffi::objc_msgSendSuper_stret as usize
+            );
+        }
+
+        #[test]
+        #[cfg(target_arch = "x86_64")]
+        fn select_x86_64() {
+            let normal = ffi::objc_msgSend as usize;
+            let stret = ffi::objc_msgSend_stret as usize;
+            assert_eq!(msg_send_fn::<()>(), normal);
+            assert_eq!(msg_send_fn::<f64>(), normal);
+            // Structs up to two eightbytes are returned in registers.
+            assert_eq!(msg_send_fn::<Bytes<16>>(), normal);
+            assert_eq!(msg_send_fn::<Bytes<17>>(), stret);
+            assert_eq!(
+                msg_send_super_fn::<Bytes<16>>(),
+                ffi::objc_msgSendSuper as usize
+            );
+            assert_eq!(
+                msg_send_super_fn::<Bytes<17>>(),
+                ffi::objc_msgSendSuper_stret as usize
+            );
+        }
+    }
 }
 
 #[cfg(feature = "gnustep-1-7")]
@@ -359,6 +488,16 @@ mod private {
 ///
 /// Examples include objects pointers, class pointers, and block pointers.
 ///
+/// This is implemented for references like `&T`, as well as for the raw
+/// pointer types `*const T`, `*mut T` and [`NonNull<T>`]. The latter allow
+/// messaging an object without first wrapping it in [`Retained`], which can
+/// be useful when the pointer comes from FFI. Since sending a message is
+/// already `unsafe`, it is up to the caller to ensure that such a pointer
+/// points to a live object; prefer [`NonNull<T>`] when possible, since that
+/// rules out messaging `nil` at the type level.
+///
+/// [`Retained`]: crate::rc::Retained
+///
 ///
 /// # Safety
 ///
@@ -428,8 +567,19 @@ pub unsafe trait MessageReceiver: private::Sealed + Sized {
             msg_send_check(obj, sel, A::ENCODINGS, &R::ENCODING_RETURN);
         }
 
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("msg_send", selector = %sel).entered();
+
         // SAFETY: Upheld by caller
-        conditional_try!(|| unsafe { msg_send_primitive::send(receiver, sel, args) })
+        //
+        // The class is only looked up if an exception occurred, to avoid the
+        // cost on the happy path.
+        conditional_try!(
+            || unsafe { msg_send_primitive::send(receiver, sel, args) },
+            // SAFETY: Caller ensures only valid or NULL pointers.
+            unsafe { receiver.as_ref() }.map(|obj| obj.class()),
+            sel
+        )
     }
 
     /// Sends a message to a specific superclass with the given selector and
@@ -471,10 +621,17 @@ pub unsafe trait MessageReceiver: private::Sealed + Sized {
             msg_send_check_class(superclass, sel, A::ENCODINGS, &R::ENCODING_RETURN);
         }
 
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::trace_span!("msg_send_super", selector = %sel, superclass = %superclass)
+                .entered();
+
         // SAFETY: Upheld by caller
-        conditional_try!(|| unsafe {
-            msg_send_primitive::send_super(receiver, superclass, sel, args)
-        })
+        conditional_try!(
+            || unsafe { msg_send_primitive::send_super(receiver, superclass, sel, args) },
+            Some(superclass),
+            sel
+        )
     }
 }
 
@@ -482,6 +639,10 @@ pub unsafe trait MessageReceiver: private::Sealed + Sized {
 // to support `extern type`s in the future, not because we want to allow DSTs.
 
 impl<T: ?Sized + Message> private::Sealed for *const T {}
+/// Raw pointers may be NULL, in which case the message is sent to `nil`.
+///
+/// When sending messages to a raw pointer, the caller must ensure that the
+/// pointer is either NULL, or points to a valid object.
 unsafe impl<T: ?Sized + Message> MessageReceiver for *const T {
     type __Inner = T;
 
@@ -492,6 +653,7 @@ unsafe impl<T: ?Sized + Message> MessageReceiver for *const T {
 }
 
 impl<T: ?Sized + Message> private::Sealed for *mut T {}
+/// Same as the implementation for `*const T`.
 unsafe impl<T: ?Sized + Message> MessageReceiver for *mut T {
     type __Inner = T;
 
@@ -502,6 +664,11 @@ unsafe impl<T: ?Sized + Message> MessageReceiver for *mut T {
 }
 
 impl<T: ?Sized + Message> private::Sealed for NonNull<T> {}
+/// Allows messaging a pointer that is known to not be `nil`.
+///
+/// When sending messages to a `NonNull<T>`, the caller must ensure that the
+/// pointer points to a valid object, that is, that it is not dangling, and
+/// that the object hasn't been deallocated.
 unsafe impl<T: ?Sized + Message> MessageReceiver for NonNull<T> {
     type __Inner = T;
 
@@ -542,10 +709,10 @@ mod tests {
     use core::ptr;
 
     use super::*;
-    use crate::msg_send;
     use crate::rc::{Allocated, Retained};
     use crate::runtime::NSObject;
     use crate::test_utils;
+    use crate::{msg_send, sel};
 
     #[allow(unused)]
     fn test_different_receivers(obj: &mut AnyObject) {
@@ -579,6 +746,22 @@ mod tests {
         assert_eq!(result, 4);
     }
 
+    #[test]
+    fn test_send_message_non_null() {
+        let obj = test_utils::custom_object();
+        let _: () = unsafe { msg_send![&obj, setFoo: 4u32] };
+
+        // The pointer is derived from `obj`, which is alive for the duration
+        // of the message sends.
+        let ptr = Retained::as_non_null(&obj);
+        let result: u32 = unsafe { msg_send![ptr, foo] };
+        assert_eq!(result, 4);
+
+        let _: () = unsafe { msg_send![ptr, setFoo: 5u32] };
+        let result: u32 = unsafe { MessageReceiver::send_message(ptr, sel!(foo), ()) };
+        assert_eq!(result, 5);
+    }
+
     #[test]
     fn test_send_message_stret() {
         let obj = test_utils::custom_object();
@@ -592,6 +775,21 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_send_message_small_struct() {
+        let obj = test_utils::custom_object();
+        let result: test_utils::CustomSmallStruct = unsafe { msg_send![&obj, customSmallStruct] };
+        let expected = test_utils::CustomSmallStruct { a: 5, b: 6, c: 7 };
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_send_message_fpret() {
+        let obj = test_utils::custom_object();
+        let result: f64 = unsafe { msg_send![&obj, customDouble] };
+        assert_eq!(result, 1.5);
+    }
+
     #[test]
     #[cfg_attr(debug_assertions, should_panic = "messsaging description to nil")]
     fn test_send_message_nil() {
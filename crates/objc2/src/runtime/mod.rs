@@ -21,6 +21,7 @@ use core::ffi::c_uint;
 use core::ffi::{c_void, CStr};
 use core::fmt;
 use core::hash;
+use core::iter::{self, FusedIterator};
 use core::panic::{RefUnwindSafe, UnwindSafe};
 use core::ptr::{self, NonNull};
 
@@ -30,6 +31,7 @@ use core::ptr::{self, NonNull};
 pub mod __nsstring;
 mod bool;
 mod define;
+mod extra_bytes;
 mod malloc;
 mod message_receiver;
 mod method_encoding_iter;
@@ -54,6 +56,8 @@ pub use self::nsproxy::NSProxy as __NSProxy;
 
 pub use self::bool::Bool;
 pub use self::define::{ClassBuilder, ProtocolBuilder};
+#[cfg(not(feature = "unstable-objfw"))]
+pub use self::extra_bytes::{alloc_with_extra_bytes, extra_bytes};
 pub use self::message_receiver::MessageReceiver;
 pub use self::method_implementation::MethodImplementation;
 pub use self::nsobject::{NSObject, NSObjectProtocol};
@@ -210,6 +214,10 @@ impl Sel {
     }
 
     /// Returns the string representation of the selector.
+    ///
+    /// Selector names are almost always UTF-8, so if you just want to log
+    /// the selector, you can use its [`Display`][fmt::Display]
+    /// implementation instead.
     #[inline]
     #[doc(alias = "sel_getName")]
     pub fn name(self) -> &'static CStr {
@@ -464,6 +472,14 @@ impl Ivar {
 
 standard_pointer_impls!(Ivar);
 
+impl fmt::Display for Ivar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Instance variable names are usually UTF-8, so it's probably fine
+        // to do a lossy conversion here.
+        fmt::Display::fmt(&self.name().to_string_lossy(), f)
+    }
+}
+
 impl fmt::Debug for Ivar {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Ivar")
@@ -665,6 +681,12 @@ impl Method {
 
 standard_pointer_impls!(Method);
 
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.name(), f)
+    }
+}
+
 impl fmt::Debug for Method {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Method")
@@ -752,6 +774,10 @@ impl AnyClass {
     }
 
     /// Returns the name of the class.
+    ///
+    /// Class names are almost always UTF-8, so if you just want to log the
+    /// class, you can use its [`Display`][fmt::Display] implementation
+    /// instead.
     #[inline]
     #[doc(alias = "class_getName")]
     pub fn name(&self) -> &CStr {
@@ -779,6 +805,31 @@ impl AnyClass {
         unsafe { Self::superclass_raw(self) }
     }
 
+    /// Returns an iterator over the superclasses of self, starting with the
+    /// direct superclass, and ending with the root class.
+    ///
+    /// The iterator is empty if self is a root class.
+    ///
+    /// Note that the superclass of a root metaclass is the root class
+    /// itself, so the chain of a metaclass ends with the root class (and not
+    /// the root metaclass).
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2::runtime::NSObject;
+    /// use objc2::ClassType;
+    ///
+    /// let cls = NSObject::class();
+    /// assert_eq!(cls.superclass_chain().count(), 0);
+    /// ```
+    #[inline]
+    #[doc(alias = "class_getSuperclass")]
+    pub fn superclass_chain(&self) -> impl FusedIterator<Item = &AnyClass> {
+        iter::successors(self.superclass(), |cls| cls.superclass())
+    }
+
     /// Returns the metaclass of self.
     ///
     ///
@@ -1627,6 +1678,33 @@ mod tests {
         assert_eq!(subclass.superclass().unwrap(), cls);
     }
 
+    #[test]
+    fn test_superclass_chain() {
+        let superclass = ClassBuilder::new(&c("SuperclassChainA"), NSObject::class())
+            .unwrap()
+            .register();
+        let subclass = ClassBuilder::new(&c("SuperclassChainB"), superclass)
+            .unwrap()
+            .register();
+
+        let chain: Vec<_> = subclass.superclass_chain().collect();
+        assert_eq!(chain, [superclass, NSObject::class()]);
+
+        assert_eq!(NSObject::class().superclass_chain().next(), None);
+        assert_eq!(test_utils::custom_class().superclass_chain().count(), 0);
+
+        let metaclass = subclass.metaclass();
+        let chain: Vec<_> = metaclass.superclass_chain().collect();
+        assert_eq!(
+            chain,
+            [
+                superclass.metaclass(),
+                NSObject::class().metaclass(),
+                NSObject::class(),
+            ]
+        );
+    }
+
     #[test]
     fn test_classes_count() {
         assert!(AnyClass::classes_count() > 0);
@@ -1690,6 +1768,17 @@ mod tests {
         assert_eq!(sub_proto.adopted_protocols()[0], super_proto);
     }
 
+    #[test]
+    fn test_protocol_get() {
+        let proto = AnyProtocol::get(&c("NSObject")).unwrap();
+        assert_eq!(proto.name(), &*c("NSObject"));
+        assert_eq!(Some(proto), <dyn NSObjectProtocol>::protocol());
+        assert!(NSObject::class().conforms_to(proto));
+        assert!(!test_utils::custom_class().conforms_to(proto));
+
+        assert_eq!(AnyProtocol::get(&c("NonExistentProtocol")), None);
+    }
+
     #[test]
     fn test_protocols() {
         // Ensure that a protocol has been registered on linux
@@ -1774,6 +1863,10 @@ mod tests {
             format!("{cls:?}"),
             "AnyClass { name: \"CustomObject\", .. }"
         );
+        let ivar = cls.instance_variable(&c("_foo")).unwrap();
+        assert_eq!(format!("{ivar}"), "_foo");
+        let method = cls.instance_method(sel!(setBar:)).unwrap();
+        assert_eq!(format!("{method}"), "setBar:");
         let protocol = test_utils::custom_protocol();
         assert_eq!(format!("{protocol}"), "CustomProtocol");
         assert_eq!(
@@ -36,6 +36,12 @@ crate::__extern_class_impl_traits! {
     (AnyObject)
 }
 
+crate::__extern_class_impl_as_ref_protocol! {
+    ()
+    ()
+    (NSObject)
+}
+
 // We do not want to expose this type publicly, even though it's exposed in
 // the trait impl.
 mod private {
@@ -341,6 +347,118 @@ pub unsafe trait NSObjectProtocol {
         unsafe { msg_send![self, retainCount] }
     }
 
+    /// Send a message with the given selector to the object.
+    ///
+    /// The returned object is retained, since `performSelector:` returns it
+    /// at +0 (autoreleased).
+    ///
+    /// You should prefer [`msg_send!`], or [`MessageReceiver::send_message`]
+    /// if the selector is only known at runtime, as those are both more
+    /// performant and more flexible than this.
+    ///
+    /// The variants that perform the selector after a delay or on a
+    /// different thread are not provided here, since they are Foundation
+    /// categories on `NSObject`, and not part of the `NSObject` protocol.
+    /// Use [`NSObjectNSThreadPerformAdditions`] (e.g. for
+    /// `performSelectorOnMainThread:withObject:waitUntilDone:`) and
+    /// [`NSObjectNSDelayedPerforming`] (e.g. for
+    /// `performSelector:withObject:afterDelay:`) from `objc2-foundation`
+    /// instead.
+    ///
+    /// See [Apple's documentation][apple-doc] for details.
+    ///
+    /// [apple-doc]: https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418867-performselector?language=objc
+    /// [`NSObjectNSThreadPerformAdditions`]: https://docs.rs/objc2-foundation/latest/objc2_foundation/trait.NSObjectNSThreadPerformAdditions.html
+    /// [`NSObjectNSDelayedPerforming`]: https://docs.rs/objc2-foundation/latest/objc2_foundation/trait.NSObjectNSDelayedPerforming.html
+    /// [`msg_send!`]: crate::msg_send
+    /// [`MessageReceiver::send_message`]: crate::runtime::MessageReceiver::send_message
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The method that the selector refers to must take no arguments, and
+    /// must return an object (or `nil`).
+    ///
+    /// In particular, the method must not return `void`, since the return
+    /// value is then unspecified, and retaining it would be undefined
+    /// behaviour. Use [`msg_send!`] for such methods instead.
+    ///
+    /// The method must not be in the `new`, `alloc`, `init`, `copy` or
+    /// `mutableCopy` families, as those return +1 objects, which
+    /// `performSelector:` would leak.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2::runtime::{NSObject, NSObjectProtocol};
+    /// use objc2::sel;
+    ///
+    /// let obj = NSObject::new();
+    /// // SAFETY: `-self` takes no arguments, and returns an object.
+    /// let res = unsafe { obj.performSelector(sel!(self)) }.unwrap();
+    /// assert_eq!(&*res, &**obj);
+    /// ```
+    #[doc(alias = "performSelector:")]
+    unsafe fn performSelector(&self, aSelector: Sel) -> Option<Retained<AnyObject>>
+    where
+        Self: Sized + Message,
+    {
+        unsafe { msg_send![self, performSelector: aSelector] }
+    }
+
+    /// Send a message with the given selector and an object argument to the
+    /// object.
+    ///
+    /// See [`performSelector`][Self::performSelector] for details.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Same as [`performSelector`][Self::performSelector], except that the
+    /// method must take a single object argument of the given type.
+    #[doc(alias = "performSelector:withObject:")]
+    unsafe fn performSelector_withObject(
+        &self,
+        aSelector: Sel,
+        object: Option<&AnyObject>,
+    ) -> Option<Retained<AnyObject>>
+    where
+        Self: Sized + Message,
+    {
+        unsafe { msg_send![self, performSelector: aSelector, withObject: object] }
+    }
+
+    /// Send a message with the given selector and two object arguments to
+    /// the object.
+    ///
+    /// See [`performSelector`][Self::performSelector] for details.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Same as [`performSelector`][Self::performSelector], except that the
+    /// method must take two object arguments of the given types.
+    #[doc(alias = "performSelector:withObject:withObject:")]
+    unsafe fn performSelector_withObject_withObject(
+        &self,
+        aSelector: Sel,
+        object1: Option<&AnyObject>,
+        object2: Option<&AnyObject>,
+    ) -> Option<Retained<AnyObject>>
+    where
+        Self: Sized + Message,
+    {
+        unsafe {
+            msg_send![
+                self,
+                performSelector: aSelector,
+                withObject: object1,
+                withObject: object2,
+            ]
+        }
+    }
+
     // retain, release and autorelease below to this protocol.
 }
 
@@ -602,6 +720,61 @@ mod tests {
         assert!(NSObject::class().conforms_to(protocol));
     }
 
+    #[test]
+    fn perform_selector() {
+        use crate::{define_class, sel};
+
+        define_class!(
+            #[unsafe(super(NSObject))]
+            #[name = "__PerformSelectorTest"]
+            struct PerformSelectorTest;
+
+            impl PerformSelectorTest {
+                #[unsafe(method_id(returnNull))]
+                fn return_null(&self) -> Option<Retained<AnyObject>> {
+                    None
+                }
+
+                #[unsafe(method_id(echo:))]
+                fn echo(&self, obj: Option<&AnyObject>) -> Option<Retained<AnyObject>> {
+                    obj.map(|obj| obj.retain())
+                }
+
+                #[unsafe(method_id(second:second:))]
+                fn second(
+                    &self,
+                    _obj1: Option<&AnyObject>,
+                    obj2: Option<&AnyObject>,
+                ) -> Option<Retained<AnyObject>> {
+                    obj2.map(|obj| obj.retain())
+                }
+            }
+        );
+
+        let obj: Retained<PerformSelectorTest> =
+            unsafe { msg_send![PerformSelectorTest::class(), new] };
+        let arg = NSObject::new();
+
+        // Zero arguments.
+        let res = unsafe { obj.performSelector(sel!(self)) }.unwrap();
+        assert_eq!(Retained::as_ptr(&res), Retained::as_ptr(&obj).cast());
+        let res = unsafe { obj.performSelector(sel!(returnNull)) };
+        assert!(res.is_none());
+
+        // One argument.
+        let res = unsafe { obj.performSelector_withObject(sel!(echo:), Some(&arg)) }.unwrap();
+        assert_eq!(Retained::as_ptr(&res), Retained::as_ptr(&arg).cast());
+        let res = unsafe { obj.performSelector_withObject(sel!(echo:), None) };
+        assert!(res.is_none());
+
+        // Two arguments.
+        let res = unsafe {
+            obj.performSelector_withObject_withObject(sel!(second:second:), None, Some(&arg))
+        }
+        .unwrap();
+        assert_eq!(Retained::as_ptr(&res), Retained::as_ptr(&arg).cast());
+    }
+
     // Ensure that importing `NSObjectProtocol::hash` does not cause conflicts
     // when using `Hash::hash` on normal types.
     mod hash_does_not_overlap_with_normal_hash_method {
@@ -345,6 +345,54 @@ mod tests {
         let _foobar: Retained<ProtocolObject<dyn FooBar>> = ProtocolObject::from_retained(obj);
     }
 
+    #[test]
+    fn send_sync() {
+        assert_impl_all!(ProtocolObject<dyn Foo + Send + Sync>: Foo, Send, Sync);
+        assert_impl_all!(ProtocolObject<dyn FooBar + Send + Sync>: Foo, Bar, FooBar, Send, Sync);
+
+        fn upcast(
+            obj: &ProtocolObject<dyn FooBar + Send + Sync>,
+        ) -> &ProtocolObject<dyn Foo + Send + Sync> {
+            ProtocolObject::from_ref(obj)
+        }
+
+        fn erase(obj: &ProtocolObject<dyn FooBar + Send + Sync>) -> &ProtocolObject<dyn FooBar> {
+            ProtocolObject::from_ref(obj)
+        }
+
+        fn erase_retained(
+            obj: Retained<ProtocolObject<dyn Bar + Send + Sync>>,
+        ) -> Retained<ProtocolObject<dyn Bar>> {
+            ProtocolObject::from_retained(obj)
+        }
+
+        let _ = (upcast, erase, erase_retained);
+    }
+
+    #[test]
+    fn as_ref() {
+        fn takes_protocol(obj: &ProtocolObject<dyn Bar>) -> bool {
+            obj.isKindOfClass(DummyClass::class())
+        }
+
+        let obj = DummyClass::new();
+        // `Bar` is implemented outside `define_class!`, similar to a class
+        // conforming to a protocol in a category.
+        assert!(takes_protocol(obj.as_ref()));
+
+        let foo: &ProtocolObject<dyn Foo> = obj.as_ref();
+        assert_eq!(
+            NonNull::from(foo).cast::<AnyObject>(),
+            NonNull::from(&*obj).cast::<AnyObject>(),
+        );
+        let nsobject: Retained<ProtocolObject<dyn NSObjectProtocol>> = Retained::from(&*obj);
+        assert!(nsobject.isKindOfClass(DummyClass::class()));
+
+        let obj = NSObject::new();
+        let proto: &ProtocolObject<dyn NSObjectProtocol> = obj.as_ref();
+        assert!(proto.isKindOfClass(NSObject::class()));
+    }
+
     #[test]
     fn convert_to_anyobj() {
         let obj = NSObject::new();
@@ -42,6 +42,22 @@ unsafe impl Encode for CustomStruct {
     );
 }
 
+/// A struct that is returned in registers on x86_64, but not on x86 or ARM.
+#[derive(Debug, Eq, PartialEq)]
+#[repr(C)]
+pub(crate) struct CustomSmallStruct {
+    pub(crate) a: u32,
+    pub(crate) b: u32,
+    pub(crate) c: u32,
+}
+
+unsafe impl Encode for CustomSmallStruct {
+    const ENCODING: Encoding = Encoding::Struct(
+        "CustomSmallStruct",
+        &[u32::ENCODING, u32::ENCODING, u32::ENCODING],
+    );
+}
+
 // TODO: Remove once c"" strings are in MSRV
 fn c(s: &str) -> CString {
     CString::new(s).unwrap()
@@ -95,6 +111,17 @@ pub(crate) fn custom_class() -> &'static AnyClass {
             }
         }
 
+        extern "C-unwind" fn custom_obj_get_small_struct(
+            _this: &AnyObject,
+            _cmd: Sel,
+        ) -> CustomSmallStruct {
+            CustomSmallStruct { a: 5, b: 6, c: 7 }
+        }
+
+        extern "C-unwind" fn custom_obj_get_double(_this: &AnyObject, _cmd: Sel) -> f64 {
+            1.5
+        }
+
         extern "C-unwind" fn custom_obj_class_method(_this: &AnyClass, _cmd: Sel) -> u32 {
             7
         }
@@ -182,6 +209,11 @@ pub(crate) fn custom_class() -> &'static AnyClass {
             builder.add_method(sel!(fooReference), get_foo_reference);
             let get_struct: extern "C-unwind" fn(_, _) -> CustomStruct = custom_obj_get_struct;
             builder.add_method(sel!(customStruct), get_struct);
+            let get_small_struct: extern "C-unwind" fn(_, _) -> CustomSmallStruct =
+                custom_obj_get_small_struct;
+            builder.add_method(sel!(customSmallStruct), get_small_struct);
+            let get_double: extern "C-unwind" fn(_, _) -> f64 = custom_obj_get_double;
+            builder.add_method(sel!(customDouble), get_double);
             let class_method: extern "C-unwind" fn(_, _) -> _ = custom_obj_class_method;
             builder.add_class_method(sel!(classFoo), class_method);
 
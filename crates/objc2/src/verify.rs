@@ -4,7 +4,7 @@ use core::mem::size_of;
 use std::error::Error;
 
 use crate::encode::{Encoding, EncodingBox};
-use crate::runtime::{EncodingParseError, Method};
+use crate::runtime::{EncodingParseError, Method, MethodEncodingIter, Sel};
 
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub(crate) enum Inner {
@@ -160,8 +160,17 @@ pub(crate) fn verify_method_signature(
     args: &[Encoding],
     ret: &Encoding,
 ) -> Result<(), VerificationError> {
-    let mut iter = method.types();
+    verify_method_types(method.types(), method.name(), args, ret)
+}
 
+/// Verify the arguments and return type against a method type encoding,
+/// such as the one from a method or from a protocol's method description.
+pub(crate) fn verify_method_types(
+    mut iter: MethodEncodingIter<'_>,
+    sel: Sel,
+    args: &[Encoding],
+    ret: &Encoding,
+) -> Result<(), VerificationError> {
     // TODO: Verify stack layout
     if *ret != Encoding::None {
         let (expected, _stack_layout) = iter.extract_return()?;
@@ -196,7 +205,7 @@ pub(crate) fn verify_method_signature(
         return Err(Inner::MismatchedArgumentsCount(actual_count + remaining, actual_count).into());
     }
 
-    let expected_count = method.name().number_of_arguments();
+    let expected_count = sel.number_of_arguments();
     if expected_count != actual_count {
         return Err(Inner::MismatchedArgumentsCount(expected_count, actual_count).into());
     }
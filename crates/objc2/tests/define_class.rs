@@ -5,8 +5,8 @@ use std::marker::PhantomData;
 use std::panic::{RefUnwindSafe, UnwindSafe};
 
 use objc2::rc::Retained;
-use objc2::runtime::NSObject;
-use objc2::{define_class, extern_methods, sel, ClassType, MainThreadOnly};
+use objc2::runtime::{alloc_with_extra_bytes, extra_bytes, NSObject};
+use objc2::{define_class, extern_methods, msg_send, sel, ClassType, DefinedClass, MainThreadOnly};
 use static_assertions::{assert_impl_all, assert_not_impl_any};
 
 // Test that adding the `deprecated` attribute does not mean that warnings
@@ -615,3 +615,105 @@ fn name_can_be_expr() {
     assert_eq!(Name5Concat::class().name().to_str().unwrap(), expected);
     assert_eq!(Name5Concat::NAME, expected);
 }
+
+#[test]
+fn test_initialize() {
+    use objc2::AnyThread;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static INITIALIZED: AtomicUsize = AtomicUsize::new(0);
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        struct Initialize;
+
+        impl Initialize {
+            #[unsafe(method(initialize))]
+            fn initialize() {
+                INITIALIZED.fetch_add(1, Ordering::Relaxed);
+            }
+
+            #[unsafe(method(classMethod))]
+            fn __class_method() {}
+        }
+    );
+
+    impl Initialize {
+        extern_methods!(
+            #[unsafe(method(classMethod))]
+            fn class_method();
+        );
+    }
+
+    // Registering the class does not send it any messages.
+    let _ = Initialize::class();
+    assert_eq!(INITIALIZED.load(Ordering::Relaxed), 0);
+
+    // `+initialize` is sent before the first message.
+    Initialize::class_method();
+    assert_eq!(INITIALIZED.load(Ordering::Relaxed), 1);
+
+    // And only once.
+    Initialize::class_method();
+    let _ = Initialize::alloc();
+    assert_eq!(INITIALIZED.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic = "will never be called on classes created at runtime, use +initialize instead"
+)]
+fn test_load_unsupported() {
+    define_class!(
+        #[unsafe(super(NSObject))]
+        #[name = "TestLoadUnsupported"]
+        struct TestLoadUnsupported;
+
+        impl TestLoadUnsupported {
+            #[unsafe(method(load))]
+            fn load() {}
+        }
+    );
+
+    let _ = TestLoadUnsupported::class();
+}
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[ivars = usize]
+    struct TrailingBytes;
+);
+
+impl TrailingBytes {
+    fn new(data: &[u8]) -> Retained<Self> {
+        // SAFETY: The class does not override `alloc`, and is `AnyThread`.
+        let this = unsafe { alloc_with_extra_bytes::<Self>(data.len()) };
+        let this = this.set_ivars(data.len());
+        let this: Retained<Self> = unsafe { msg_send![super(this), init] };
+        // SAFETY: We allocated `data.len()` extra bytes.
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), extra_bytes(&this).as_ptr(), data.len());
+        }
+        this
+    }
+
+    fn data(&self) -> &[u8] {
+        // SAFETY: The length is stored in the ivars, and the bytes live as
+        // long as the object.
+        unsafe { std::slice::from_raw_parts(extra_bytes(self).as_ptr(), *self.ivars()) }
+    }
+}
+
+#[test]
+fn trailing_bytes() {
+    let obj = TrailingBytes::new(b"hello world");
+    assert_eq!(obj.data(), b"hello world");
+
+    // Write to the trailing storage.
+    unsafe { *extra_bytes(&obj).as_ptr() = b'j' };
+    assert_eq!(obj.data(), b"jello world");
+
+    let empty = TrailingBytes::new(&[]);
+    assert_eq!(empty.data(), b"");
+}
@@ -0,0 +1,75 @@
+//! Test that message sends are recorded as `tracing` spans.
+#![cfg(feature = "tracing")]
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use objc2::rc::Retained;
+use objc2::runtime::NSObject;
+use objc2::{msg_send, ClassType};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// A subscriber that records the name and selector of each span.
+#[derive(Default)]
+struct Recorder {
+    next_id: AtomicU64,
+    spans: Arc<Mutex<Vec<(&'static str, String)>>>,
+}
+
+struct SelectorVisitor(Option<String>);
+
+impl Visit for SelectorVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "selector" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut visitor = SelectorVisitor(None);
+        span.record(&mut visitor);
+        let selector = visitor.0.expect("span had no selector");
+        self.spans
+            .lock()
+            .unwrap()
+            .push((span.metadata().name(), selector));
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn span_recorded() {
+    let recorder = Recorder::default();
+    let spans = recorder.spans.clone();
+
+    tracing::subscriber::with_default(recorder, || {
+        let obj = NSObject::new();
+        let _: usize = unsafe { msg_send![&obj, hash] };
+        let _: Retained<NSObject> = unsafe { msg_send![super(&*obj, NSObject::class()), self] };
+    });
+
+    let spans = spans.lock().unwrap();
+    assert!(spans.contains(&("msg_send", "hash".into())), "{spans:?}");
+    assert!(
+        spans.contains(&("msg_send_super", "self".into())),
+        "{spans:?}"
+    );
+}
@@ -0,0 +1,36 @@
+//! Test that blocks that escape cannot capture non-`'static` references,
+//! while blocks that don't escape can.
+use block2::{Block, RcBlock, StackBlock};
+
+fn escaping(_: &Block<dyn Fn() + 'static>) {}
+
+fn escaping_rc(_: RcBlock<dyn Fn() + 'static>) {}
+
+fn non_escaping(_: &Block<dyn Fn() + '_>) {}
+
+fn main() {
+    // Allowed, the block does not outlive `x`.
+    {
+        let x = 5;
+        let r = &x;
+        non_escaping(&StackBlock::new(move || println!("{r}")));
+        non_escaping(&RcBlock::new(move || println!("{r}")));
+    }
+
+    // Not allowed, the block must be `'static`.
+    {
+        let x = 5;
+        let r = &x;
+        escaping_rc(RcBlock::new(move || println!("{r}")));
+    }
+    {
+        let x = 5;
+        let r = &x;
+        escaping(&RcBlock::new(move || println!("{r}")));
+    }
+    {
+        let x = 5;
+        let r = &x;
+        escaping(&StackBlock::new(move || println!("{r}")));
+    }
+}
@@ -0,0 +1,34 @@
+//! Arguments to methods declared with `extern_methods!` are type-checked,
+//! and must implement `Encode`.
+use objc2::runtime::NSObject;
+use objc2::{extern_class, extern_methods};
+
+extern_class!(
+    #[unsafe(super(NSObject))]
+    pub struct MyObject;
+);
+
+impl MyObject {
+    extern_methods!(
+        #[unsafe(method(setValue:))]
+        fn set_value(&self, value: f64);
+
+        // `bool` is converted to `BOOL`, so this is allowed.
+        #[unsafe(method(setEnabled:))]
+        fn set_enabled(&self, enabled: bool);
+    );
+
+    extern_methods!(
+        #[unsafe(method(setData:))]
+        fn set_data(&self, data: Vec<u8>);
+    );
+}
+
+fn test(obj: &MyObject) {
+    obj.set_enabled(true);
+
+    let value: f32 = 1.0;
+    obj.set_value(value);
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//! Test that global blocks cannot capture runtime variables.
+use block2::global_block;
+
+const FACTOR: i32 = 2;
+
+// Allowed, constants are not captured.
+global_block! {
+    static MUL = |x: i32| -> i32 { x * FACTOR };
+}
+
+fn main() {
+    let factor = 2;
+    global_block! {
+        static BLOCK = |x: i32| -> i32 { x * factor };
+    }
+    let _ = (&MUL, &BLOCK, factor);
+}
@@ -0,0 +1,17 @@
+//! Test that an `Allocated` object cannot be used after it has been
+//! initialized.
+use objc2::rc::{Allocated, Retained};
+use objc2::runtime::NSObject;
+use objc2::{msg_send, AnyThread};
+
+fn main() {
+    let obj: Allocated<NSObject> = NSObject::alloc();
+    let _: Retained<NSObject> = unsafe { msg_send![obj, init] };
+    // Initializing twice.
+    let _: Retained<NSObject> = unsafe { msg_send![obj, init] };
+
+    let obj: Allocated<NSObject> = NSObject::alloc();
+    // `init` consumes the allocation, also when it returns `nil`.
+    let _: Option<Retained<NSObject>> = unsafe { msg_send![obj, init] };
+    let _ = Allocated::as_ptr(&obj);
+}
@@ -0,0 +1,12 @@
+//! Test that an `Allocated` object can only be sent `init` messages.
+use objc2::rc::{Allocated, Retained};
+use objc2::runtime::NSObject;
+use objc2::{msg_send, AnyThread};
+
+fn main() {
+    let obj: Allocated<NSObject> = NSObject::alloc();
+    let _: Retained<NSObject> = unsafe { msg_send![obj, description] };
+
+    let obj: Allocated<NSObject> = NSObject::alloc();
+    let _: bool = unsafe { msg_send![obj, isProxy] };
+}
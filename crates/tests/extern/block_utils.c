@@ -84,6 +84,36 @@ LargeStruct invoke_large_struct_block(LargeStructBlock block, LargeStruct s) {
     return block(s);
 }
 
+// Small structs are passed in registers, unlike `LargeStruct`.
+typedef struct {
+    double x;
+    double y;
+} Point;
+
+typedef struct {
+    int32_t a;
+    float b;
+} Mixed;
+
+typedef Point (^PointBlock)(Point, int32_t);
+typedef Mixed (^MixedBlock)(Mixed, Point);
+
+PointBlock get_point_block() {
+    return ^(Point p, int32_t factor) {
+        p.x *= factor;
+        p.y += 1.0;
+        return p;
+    };
+}
+
+Point invoke_point_block(PointBlock block, Point p, int32_t factor) {
+    return block(p, factor);
+}
+
+Mixed invoke_mixed_block(MixedBlock block, Mixed m, Point p) {
+    return block(m, p);
+}
+
 
 typedef int32_t (^ABlock)(void);
 
@@ -34,6 +34,28 @@ unsafe impl Encode for LargeStruct {
         Encoding::Struct("LargeStruct", &[f32::ENCODING, <[u8; 100]>::ENCODING]);
 }
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+unsafe impl Encode for Point {
+    const ENCODING: Encoding = Encoding::Struct("Point", &[f64::ENCODING, f64::ENCODING]);
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Mixed {
+    a: i32,
+    b: f32,
+}
+
+unsafe impl Encode for Mixed {
+    const ENCODING: Encoding = Encoding::Struct("Mixed", &[i32::ENCODING, f32::ENCODING]);
+}
+
 type Add12 = Block<dyn Fn(i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32) -> i32>;
 
 struct VoidToVoid;
@@ -108,6 +130,18 @@ extern "C-unwind" {
         s: LargeStruct,
     ) -> LargeStruct;
 
+    fn get_point_block() -> *mut Block<dyn Fn(Point, i32) -> Point>;
+    fn invoke_point_block(
+        block: &Block<dyn Fn(Point, i32) -> Point>,
+        p: Point,
+        factor: i32,
+    ) -> Point;
+    fn invoke_mixed_block(
+        block: &Block<dyn Fn(Mixed, Point) -> Mixed>,
+        m: Mixed,
+        p: Point,
+    ) -> Mixed;
+
     fn try_block_debugging(x: i32);
 }
 
@@ -234,6 +268,13 @@ fn test_large_struct_block() {
         expected
     );
 
+    // Blocks compiled by Clang return the struct through a hidden pointer,
+    // make sure that calling them from Rust agrees with that.
+    let block = unsafe { &*get_large_struct_block() };
+    assert_eq!(block.call((data,)), expected);
+    let block = unsafe { RcBlock::from_raw(get_large_struct_block_with(expected)) }.unwrap();
+    assert_eq!(block.call((data,)), expected);
+
     global_block! {
         static BLOCK = |data: LargeStruct| -> LargeStruct {
             let mut data = data;
@@ -276,6 +317,60 @@ fn test_large_struct_block() {
     assert_eq!(unsafe { invoke_large_struct_block(&block, data) }, new_data);
 }
 
+#[test]
+fn test_small_struct_blocks() {
+    #[track_caller]
+    fn invoke_assert_point(block: &Block<dyn Fn(Point, i32) -> Point>) {
+        let p = Point { x: 1.5, y: -2.0 };
+        let expected = Point { x: 4.5, y: -1.0 };
+        assert_eq!(block.call((p, 3)), expected);
+        assert_eq!(unsafe { invoke_point_block(block, p, 3) }, expected);
+    }
+
+    #[track_caller]
+    fn invoke_assert_mixed(block: &Block<dyn Fn(Mixed, Point) -> Mixed>) {
+        let m = Mixed { a: 7, b: 0.5 };
+        let p = Point { x: 2.0, y: 3.0 };
+        let expected = Mixed { a: 9, b: 3.5 };
+        assert_eq!(block.call((m, p)), expected);
+        assert_eq!(unsafe { invoke_mixed_block(block, m, p) }, expected);
+    }
+
+    fn point(p: Point, factor: i32) -> Point {
+        Point {
+            x: p.x * factor as f64,
+            y: p.y + 1.0,
+        }
+    }
+
+    fn mixed(m: Mixed, p: Point) -> Mixed {
+        Mixed {
+            a: m.a + p.x as i32,
+            b: m.b + p.y as f32,
+        }
+    }
+
+    global_block! {
+        static GLOBAL_POINT = |p: Point, factor: i32| -> Point {
+            point(p, factor)
+        };
+    }
+    global_block! {
+        static GLOBAL_MIXED = |m: Mixed, p: Point| -> Mixed {
+            mixed(m, p)
+        };
+    }
+
+    invoke_assert_point(unsafe { &*get_point_block() });
+    invoke_assert_point(&StackBlock::new(point));
+    invoke_assert_point(&RcBlock::new(point));
+    invoke_assert_point(&GLOBAL_POINT);
+
+    invoke_assert_mixed(&StackBlock::new(mixed));
+    invoke_assert_mixed(&RcBlock::new(mixed));
+    invoke_assert_mixed(&GLOBAL_MIXED);
+}
+
 #[test]
 fn test_block_copy() {
     let s = "Hello!".to_string();
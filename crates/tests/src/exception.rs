@@ -87,6 +87,35 @@ fn raise_catch_all2() {
     exc.raise();
 }
 
+#[test]
+#[cfg(feature = "catch-all")]
+#[should_panic = "reason: def\nwhile sending -[NSException raise]"]
+fn raise_catch_all_context() {
+    let name = NSString::from_str("abc");
+    let reason = NSString::from_str("def");
+
+    let exc = NSException::new(&name, Some(&reason), None).unwrap();
+    exc.raise();
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic = "invalid message send to -[NSObject bogusSelector]: method not found"
+)]
+#[cfg_attr(
+    all(not(debug_assertions), feature = "catch-all"),
+    should_panic = "while sending -[NSObject bogusSelector]"
+)]
+#[cfg_attr(
+    all(not(debug_assertions), not(feature = "catch-all")),
+    ignore = "Unwinding an exception through Rust code aborts without catch-all"
+)]
+fn unrecognized_selector() {
+    let obj = NSObject::new();
+    let _: () = unsafe { msg_send![&obj, bogusSelector] };
+}
+
 #[test]
 #[cfg_attr(
     feature = "catch-all",
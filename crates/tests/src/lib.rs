@@ -19,6 +19,8 @@ mod block;
 mod exception;
 mod rc_test_object;
 #[cfg(test)]
+mod test_define_class_block_ivar;
+#[cfg(test)]
 mod test_define_class_protocol;
 #[cfg(test)]
 mod test_encode_utils;
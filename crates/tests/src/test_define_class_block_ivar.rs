@@ -0,0 +1,86 @@
+use alloc::rc::Rc;
+use core::cell::{Cell, RefCell};
+
+use block2::{Block, RcBlock};
+use objc2::rc::Retained;
+use objc2::runtime::NSObject;
+use objc2::{define_class, msg_send, AnyThread, DefinedClass};
+
+#[derive(Default)]
+struct Ivars {
+    completion_handler: RefCell<Option<RcBlock<dyn Fn(i32)>>>,
+}
+
+define_class!(
+    // @interface CompletionHolder : NSObject
+    // - (void)setCompletionHandler:(void (^)(int))handler;
+    // - (void)finishWithValue:(int)value;
+    // @end
+    #[unsafe(super(NSObject))]
+    #[name = "TestDefineClassBlockIvar"]
+    #[ivars = Ivars]
+    struct CompletionHolder;
+
+    impl CompletionHolder {
+        #[unsafe(method(setCompletionHandler:))]
+        fn set_completion_handler(&self, handler: &Block<dyn Fn(i32)>) {
+            // The block must be copied to the heap to outlive this method.
+            *self.ivars().completion_handler.borrow_mut() = Some(handler.copy());
+        }
+
+        #[unsafe(method(finishWithValue:))]
+        fn finish_with_value(&self, value: i32) {
+            // Take the handler out before calling it, in case it re-enters.
+            let handler = self.ivars().completion_handler.borrow_mut().take();
+            if let Some(handler) = handler {
+                handler.call((value,));
+            }
+        }
+    }
+);
+
+impl CompletionHolder {
+    fn new() -> Retained<Self> {
+        let this = Self::alloc().set_ivars(Ivars::default());
+        unsafe { msg_send![super(this), init] }
+    }
+}
+
+#[test]
+fn test_block_ivar() {
+    let obj = CompletionHolder::new();
+    let result = Rc::new(Cell::new(0));
+
+    {
+        let result = result.clone();
+        let block = RcBlock::new(move |value: i32| result.set(value));
+        let _: () = unsafe { msg_send![&obj, setCompletionHandler: &*block] };
+    }
+    // The closure is now kept alive only by the ivar.
+    assert_eq!(Rc::strong_count(&result), 2);
+    assert_eq!(result.get(), 0);
+
+    // Invoked later, e.g. from a delegate callback.
+    let _: () = unsafe { msg_send![&obj, finishWithValue: 42i32] };
+    assert_eq!(result.get(), 42);
+    // The handler is only called once, and is released afterwards.
+    assert_eq!(Rc::strong_count(&result), 1);
+}
+
+#[test]
+fn test_block_ivar_dropped_with_object() {
+    let obj = CompletionHolder::new();
+    let result = Rc::new(Cell::new(0));
+
+    let closure_result = result.clone();
+    let block = RcBlock::new(move |value: i32| closure_result.set(value));
+    let _: () = unsafe { msg_send![&obj, setCompletionHandler: &*block] };
+    drop(block);
+    assert_eq!(Rc::strong_count(&result), 2);
+
+    // Deallocating the object drops the stored block, and with it the
+    // closure.
+    drop(obj);
+    assert_eq!(Rc::strong_count(&result), 1);
+    assert_eq!(result.get(), 0);
+}
@@ -1,8 +1,39 @@
+use std::sync::Once;
+
 use objc2::rc::Retained;
-use objc2::runtime::{NSObject, NSZone};
-use objc2::{define_class, ClassType, ProtocolType};
+use objc2::runtime::{AnyProtocol, Bool, NSObject, NSObjectProtocol, NSZone, ProtocolBuilder};
+use objc2::{define_class, extern_protocol, msg_send, sel, ClassType, ProtocolType};
 use objc2_foundation::NSCopying;
 
+extern_protocol!(
+    /// A delegate protocol that is registered at runtime, since we need the
+    /// method descriptions to be available.
+    #[allow(clippy::missing_safety_doc)]
+    #[name = "TestDefineClassDelegate"]
+    unsafe trait Delegate: NSObjectProtocol {
+        #[unsafe(method(didFinishWithCount:))]
+        fn did_finish_with_count(&self, count: isize);
+
+        #[optional]
+        #[unsafe(method(shouldContinue))]
+        fn should_continue(&self) -> Bool;
+    }
+);
+
+fn register_delegate_protocol() -> &'static AnyProtocol {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        let mut builder = ProtocolBuilder::new(c"TestDefineClassDelegate").unwrap();
+        builder.add_protocol(<dyn NSObjectProtocol>::protocol().unwrap());
+        builder.add_method_description::<(isize,), ()>(sel!(didFinishWithCount:), true);
+        builder.add_method_description::<(), Bool>(sel!(shouldContinue), false);
+        builder.register();
+    });
+
+    <dyn Delegate>::protocol().unwrap()
+}
+
 #[test]
 fn test_define_class_protocol() {
     define_class!(
@@ -63,7 +94,10 @@ fn test_define_class_missing_protocol_method() {
 }
 
 #[test]
-// #[cfg_attr(debug_assertions, should_panic = "...")]
+#[cfg_attr(
+    debug_assertions,
+    should_panic = "defined invalid protocol method -[NSCopying copyWithZone:]: expected return to have type code '@', but found 'C'"
+)]
 fn test_define_class_invalid_protocol_method() {
     define_class!(
         #[unsafe(super(NSObject))]
@@ -107,3 +141,59 @@ fn test_define_class_extra_protocol_method() {
 
     let _cls = Custom::class();
 }
+
+#[test]
+fn test_define_class_delegate_protocol() {
+    let protocol = register_delegate_protocol();
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        #[name = "TestDefineClassDelegateProtocol"]
+        struct Custom;
+
+        unsafe impl NSObjectProtocol for Custom {}
+
+        unsafe impl Delegate for Custom {
+            #[unsafe(method(didFinishWithCount:))]
+            fn did_finish_with_count(&self, _count: isize) {}
+
+            #[unsafe(method(shouldContinue))]
+            fn should_continue(&self) -> Bool {
+                Bool::YES
+            }
+        }
+    );
+
+    let cls = Custom::class();
+    assert!(cls.conforms_to(protocol));
+
+    let obj: Retained<Custom> = unsafe { msg_send![cls, new] };
+    let _: () = unsafe { msg_send![&obj, didFinishWithCount: 42isize] };
+    let should_continue: bool = unsafe { msg_send![&obj, shouldContinue] };
+    assert!(should_continue);
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic = "defined invalid protocol method -[TestDefineClassDelegate didFinishWithCount:]: expected argument at index 0 to have type code"
+)]
+fn test_define_class_delegate_protocol_mismatch() {
+    register_delegate_protocol();
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        #[name = "TestDefineClassDelegateProtocolMismatch"]
+        struct Custom;
+
+        unsafe impl NSObjectProtocol for Custom {}
+
+        unsafe impl Delegate for Custom {
+            // Typo: Should have taken `isize`
+            #[unsafe(method(didFinishWithCount:))]
+            fn did_finish_with_count(&self, _count: f32) {}
+        }
+    );
+
+    let _cls = Custom::class();
+}
@@ -7,7 +7,7 @@ use objc2_foundation::{
     NSMutableSet, NSNumber, NSSet, NSValue,
 };
 
-use crate::rc_test_object::{RcTestObject, ThreadTestData};
+use crate::rc_test_object::{assert_no_leaks, RcTestObject, ThreadTestData};
 
 #[test]
 fn array_retains_stored() {
@@ -287,6 +287,18 @@ fn array_remove() {
     assert_eq!(array.len(), 0);
 }
 
+#[test]
+fn array_does_not_leak() {
+    assert_no_leaks(|| {
+        let array = NSMutableArray::new();
+        for _ in 0..4 {
+            array.addObject(&*RcTestObject::new());
+        }
+        let _copy = array.copy();
+        let _obj = array.firstObject().unwrap();
+    });
+}
+
 #[test]
 fn set_insert_retain_release() {
     let set = <NSMutableSet<RcTestObject>>::new();
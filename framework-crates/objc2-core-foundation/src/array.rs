@@ -600,6 +600,45 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "CFString")]
+    fn into_iter() {
+        use alloc::string::ToString;
+        use alloc::vec::Vec;
+
+        let objects = [
+            CFString::from_str("some long string that doesn't get small-string optimized"),
+            CFString::from_str("another long string that doesn't get small-string optimized"),
+        ];
+        let array = CFArray::from_retained_objects(&objects);
+        drop(objects);
+        assert_eq!(unsafe { array.get_unchecked(0) }.retain_count(), 1);
+
+        // Consuming the array releases it, but the yielded elements are
+        // retained before that happens, so they stay alive.
+        let items: Vec<CFRetained<CFString>> = array.into_iter().collect();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].retain_count(), 1);
+        assert_eq!(items[1].retain_count(), 1);
+        assert_eq!(
+            items[0].to_string(),
+            "some long string that doesn't get small-string optimized"
+        );
+
+        // Partially consumed.
+        let array = CFMutableArray::<CFString>::with_capacity(2);
+        array.append(&items[0]);
+        array.append(&items[1]);
+        let mut iter = array.into_iter();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next().as_deref(), Some(&*items[0]));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(items[1].retain_count(), 2);
+        drop(iter);
+        assert_eq!(items[0].retain_count(), 1);
+        assert_eq!(items[1].retain_count(), 1);
+    }
+
     #[test]
     #[cfg(feature = "CFString")]
     fn iter_fused() {
@@ -0,0 +1,73 @@
+#![cfg(all(feature = "CFDate", feature = "CFString"))]
+use core::ptr;
+
+use crate::{CFDate, CFDateFormatter, CFRetained, CFString};
+
+impl CFDateFormatter {
+    /// Format the date as a string, according to the formatter's locale,
+    /// style and format.
+    #[inline]
+    #[doc(alias = "CFDateFormatterCreateStringWithDate")]
+    pub fn string_from_date(&self, date: &CFDate) -> CFRetained<CFString> {
+        Self::new_string_with_date(None, Some(self), Some(date)).expect("failed formatting date")
+    }
+
+    /// Parse a date from a string, according to the formatter's locale,
+    /// style and format.
+    ///
+    /// Returns `None` if the entire string could not be parsed as a date.
+    #[inline]
+    #[doc(alias = "CFDateFormatterCreateDateFromString")]
+    pub fn date_from_string(&self, string: &CFString) -> Option<CFRetained<CFDate>> {
+        // Passing a NULL range means that the entire string must be parsed.
+        let rangep = ptr::null_mut();
+        // SAFETY: The range pointer is allowed to be NULL.
+        unsafe { Self::new_date_from_string(None, Some(self), Some(string), rangep) }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "CFLocale")]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::{CFDateFormatterStyle, CFLocale};
+
+    fn formatter(format: &'static str) -> CFRetained<CFDateFormatter> {
+        // Use the POSIX locale, which is unaffected by user settings.
+        let locale = CFLocale::new(None, Some(&CFString::from_static_str("en_US_POSIX"))).unwrap();
+        let formatter = CFDateFormatter::new(
+            None,
+            Some(&locale),
+            CFDateFormatterStyle::NoStyle,
+            CFDateFormatterStyle::NoStyle,
+        )
+        .unwrap();
+        formatter.set_format(Some(&CFString::from_static_str(format)));
+        formatter
+    }
+
+    #[test]
+    fn roundtrip() {
+        let formatter = formatter("yyyy-MM-dd HH:mm:ss");
+        let string = CFString::from_static_str("2001-02-03 04:05:06");
+        let date = formatter.date_from_string(&string).unwrap();
+        assert_eq!(formatter.string_from_date(&date), string);
+
+        let later = CFDate::new(None, date.absolute_time() + 60.0).unwrap();
+        assert_eq!(
+            formatter.string_from_date(&later).to_string(),
+            "2001-02-03 04:06:06"
+        );
+    }
+
+    #[test]
+    fn parse_failure() {
+        let formatter = formatter("yyyy-MM-dd");
+        let parse = |s| formatter.date_from_string(&CFString::from_static_str(s));
+        assert!(parse("not a date").is_none());
+        assert!(parse("2001/02/03").is_none());
+        assert!(parse("").is_none());
+    }
+}
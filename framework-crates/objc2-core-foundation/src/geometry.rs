@@ -1,3 +1,5 @@
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
 #[cfg(feature = "objc2")]
 use objc2::encode::{Encode, Encoding, RefEncode};
 
@@ -99,6 +101,58 @@ impl CGPoint {
     pub const ZERO: Self = Self::new(0.0, 0.0);
 }
 
+impl Add for CGPoint {
+    type Output = Self;
+
+    /// Add the coordinates of two points component-wise.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_core_foundation::CGPoint;
+    /// let point = CGPoint::new(1.0, 2.0) + CGPoint::new(3.0, -4.0);
+    /// assert_eq!(point, CGPoint::new(4.0, -2.0));
+    /// ```
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for CGPoint {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for CGPoint {
+    type Output = Self;
+
+    /// Subtract the coordinates of two points component-wise.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_core_foundation::CGPoint;
+    /// let point = CGPoint::new(1.0, 2.0) - CGPoint::new(3.0, -4.0);
+    /// assert_eq!(point, CGPoint::new(-2.0, 6.0));
+    /// ```
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for CGPoint {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
 /// A two-dimensional size.
 ///
 /// As this is sometimes used to represent a distance vector, rather than a
@@ -321,22 +375,165 @@ impl CGRect {
         // self.size.width <= 0.0 || self.size.height <= 0.0
     }
 
+    /// The null rectangle.
+    ///
+    /// This is the rectangle returned by [`CGRect::intersection`] when the
+    /// rectangles do not overlap. It is positioned at infinity, and is
+    /// distinct from [`CGRect::ZERO`].
+    #[doc(alias = "CGRectNull")]
+    pub const NULL: Self = Self::new(
+        CGPoint::new(CGFloat::INFINITY, CGFloat::INFINITY),
+        CGSize::ZERO,
+    );
+
+    /// Returns whether the rectangle is the null rectangle.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_core_foundation::CGRect;
+    /// assert!(CGRect::NULL.is_null());
+    /// assert!(!CGRect::ZERO.is_null());
+    /// ```
+    #[inline]
+    #[doc(alias = "CGRectIsNull")]
+    pub fn is_null(self) -> bool {
+        self.origin.x == CGFloat::INFINITY || self.origin.y == CGFloat::INFINITY
+    }
+
+    /// The rectangle with its origin moved such that the size is
+    /// non-negative, like `CGRectStandardize`.
+    ///
+    /// Unlike [`CGRect::standardize`], this doesn't require `std`.
+    #[inline]
+    fn normalized(self) -> Self {
+        let mut rect = self;
+        if rect.size.width < 0.0 {
+            rect.origin.x += rect.size.width;
+            rect.size.width = -rect.size.width;
+        }
+        if rect.size.height < 0.0 {
+            rect.origin.y += rect.size.height;
+            rect.size.height = -rect.size.height;
+        }
+        rect
+    }
+
+    /// Returns whether the rectangle contains the given point.
+    ///
+    /// The rectangle is standardized first, and a point is considered inside
+    /// if it lies on the minimum edges, but not if it lies on the maximum
+    /// edges. The null rectangle and empty rectangles contain no points.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+    /// let rect = CGRect::new(CGPoint::new(1.0, 1.0), CGSize::new(2.0, 2.0));
+    /// assert!(rect.contains_point(CGPoint::new(1.0, 2.0)));
+    /// assert!(!rect.contains_point(CGPoint::new(3.0, 2.0)));
+    /// ```
+    #[inline]
+    #[doc(alias = "CGRectContainsPoint")]
+    #[doc(alias = "NSPointInRect")]
+    pub fn contains_point(self, point: CGPoint) -> bool {
+        if self.is_null() {
+            return false;
+        }
+        let rect = self.normalized();
+        let (min, max) = (rect.min(), rect.max());
+        min.x <= point.x && point.x < max.x && min.y <= point.y && point.y < max.y
+    }
+
+    /// The intersection of two rectangles.
+    ///
+    /// Both rectangles are standardized first. If the rectangles don't
+    /// overlap, or if either of them is the null rectangle,
+    /// [`CGRect::NULL`] is returned.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+    /// let a = CGRect::new(CGPoint::new(0.0, 0.0), CGSize::new(2.0, 2.0));
+    /// let b = CGRect::new(CGPoint::new(1.0, 1.0), CGSize::new(2.0, 2.0));
+    /// let expected = CGRect::new(CGPoint::new(1.0, 1.0), CGSize::new(1.0, 1.0));
+    /// assert_eq!(a.intersection(b), expected);
+    ///
+    /// let c = CGRect::new(CGPoint::new(5.0, 5.0), CGSize::new(1.0, 1.0));
+    /// assert!(a.intersection(c).is_null());
+    /// ```
+    #[doc(alias = "CGRectIntersection")]
+    #[doc(alias = "NSIntersectionRect")]
+    pub fn intersection(self, other: Self) -> Self {
+        if self.is_null() || other.is_null() {
+            return Self::NULL;
+        }
+        let (a, b) = (self.normalized(), other.normalized());
+        let min_x = a.min().x.max(b.min().x);
+        let min_y = a.min().y.max(b.min().y);
+        let max_x = a.max().x.min(b.max().x);
+        let max_y = a.max().y.min(b.max().y);
+        if max_x < min_x || max_y < min_y {
+            return Self::NULL;
+        }
+        Self::new(
+            CGPoint::new(min_x, min_y),
+            CGSize::new(max_x - min_x, max_y - min_y),
+        )
+    }
+
+    /// The smallest rectangle that contains both rectangles.
+    ///
+    /// Both rectangles are standardized first. The null rectangle is
+    /// ignored, so the union of a rectangle with [`CGRect::NULL`] is the
+    /// (standardized) rectangle itself.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+    /// let a = CGRect::new(CGPoint::new(0.0, 0.0), CGSize::new(1.0, 1.0));
+    /// let b = CGRect::new(CGPoint::new(2.0, 3.0), CGSize::new(1.0, 1.0));
+    /// let expected = CGRect::new(CGPoint::new(0.0, 0.0), CGSize::new(3.0, 4.0));
+    /// assert_eq!(a.union(b), expected);
+    /// assert_eq!(a.union(CGRect::NULL), a);
+    /// ```
+    #[doc(alias = "CGRectUnion")]
+    #[doc(alias = "NSUnionRect")]
+    pub fn union(self, other: Self) -> Self {
+        if self.is_null() {
+            return other.normalized();
+        }
+        if other.is_null() {
+            return self.normalized();
+        }
+        let (a, b) = (self.normalized(), other.normalized());
+        let min_x = a.min().x.min(b.min().x);
+        let min_y = a.min().y.min(b.min().y);
+        let max_x = a.max().x.max(b.max().x);
+        let max_y = a.max().y.max(b.max().y);
+        Self::new(
+            CGPoint::new(min_x, min_y),
+            CGSize::new(max_x - min_x, max_y - min_y),
+        )
+    }
+
     // TODO: NSContainsRect / CGRectContainsRect
     // TODO: NSDivideRect / CGRectDivide
     // TODO: NSInsetRect / CGRectInset
     // TODO: NSIntegralRect / CGRectIntegral
-    // TODO: NSIntersectionRect / CGRectIntersection
-    // TODO: NSUnionRect / CGRectUnion
     // TODO: NSIntersectsRect / CGRectIntersectsRect
     // TODO: NSMouseInRect
     // TODO: NSMouseInRect
-    // TODO: NSPointInRect / CGRectContainsPoint
     // TODO: NSOffsetRect / CGRectOffset
 
-    // TODO: CGRectIsNull
     // TODO: CGRectIsInfinite
     // TODO: CGRectInfinite
-    // TODO: CGRectNull
 
     // TODO: NSHeight / CGRectGetHeight (standardized)
     // TODO: NSWidth / CGRectGetWidth (standardized)
@@ -385,4 +582,94 @@ mod tests {
         CGSize::new(-1.0, 1.0);
         CGSize::new(1.0, -1.0);
     }
+
+    fn rect(x: CGFloat, y: CGFloat, width: CGFloat, height: CGFloat) -> CGRect {
+        CGRect::new(CGPoint::new(x, y), CGSize::new(width, height))
+    }
+
+    #[test]
+    fn test_point_arithmetic() {
+        let mut point = CGPoint::new(1.0, 2.0);
+        assert_eq!(point + CGPoint::ZERO, point);
+        assert_eq!(point - point, CGPoint::ZERO);
+        point += CGPoint::new(0.5, -1.0);
+        assert_eq!(point, CGPoint::new(1.5, 1.0));
+        point -= CGPoint::new(1.5, 1.0);
+        assert_eq!(point, CGPoint::ZERO);
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let r = rect(0.0, 0.0, 2.0, 2.0);
+        assert!(r.contains_point(CGPoint::new(0.0, 0.0)));
+        assert!(r.contains_point(CGPoint::new(1.0, 1.9)));
+        assert!(!r.contains_point(CGPoint::new(2.0, 1.0)));
+        assert!(!r.contains_point(CGPoint::new(1.0, 2.0)));
+        assert!(!r.contains_point(CGPoint::new(-0.1, 1.0)));
+
+        // Negative sizes are standardized.
+        let r = rect(2.0, 2.0, -2.0, -2.0);
+        assert!(r.contains_point(CGPoint::new(0.0, 0.0)));
+        assert!(!r.contains_point(CGPoint::new(2.0, 2.0)));
+
+        // Empty and null rectangles contain nothing.
+        assert!(!CGRect::ZERO.contains_point(CGPoint::ZERO));
+        assert!(!rect(0.0, 0.0, 0.0, 1.0).contains_point(CGPoint::ZERO));
+        assert!(!CGRect::NULL.contains_point(CGPoint::ZERO));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = rect(0.0, 0.0, 4.0, 4.0);
+        let b = rect(2.0, 1.0, 4.0, 2.0);
+        assert_eq!(a.intersection(b), rect(2.0, 1.0, 2.0, 2.0));
+        assert_eq!(b.intersection(a), rect(2.0, 1.0, 2.0, 2.0));
+        assert_eq!(a.intersection(a), a);
+
+        // Contained.
+        let inner = rect(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(a.intersection(inner), inner);
+
+        // Negative sizes are standardized.
+        let flipped = rect(6.0, 3.0, -4.0, -2.0);
+        assert_eq!(a.intersection(flipped), rect(2.0, 1.0, 2.0, 2.0));
+
+        // Touching edges give an empty, but non-null, rectangle.
+        let touching = a.intersection(rect(4.0, 0.0, 1.0, 1.0));
+        assert!(!touching.is_null());
+        assert!(touching.is_empty());
+        assert_eq!(touching, rect(4.0, 0.0, 0.0, 1.0));
+
+        // Non-overlapping.
+        assert!(a.intersection(rect(5.0, 5.0, 1.0, 1.0)).is_null());
+        assert!(a.intersection(rect(0.0, 5.0, 4.0, 1.0)).is_null());
+        assert_eq!(a.intersection(rect(-2.0, 0.0, 1.0, 1.0)), CGRect::NULL);
+
+        // Null.
+        assert!(a.intersection(CGRect::NULL).is_null());
+        assert!(CGRect::NULL.intersection(a).is_null());
+    }
+
+    #[test]
+    fn test_union() {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        let b = rect(2.0, -1.0, 1.0, 1.0);
+        assert_eq!(a.union(b), rect(0.0, -1.0, 3.0, 2.0));
+        assert_eq!(b.union(a), rect(0.0, -1.0, 3.0, 2.0));
+        assert_eq!(a.union(a), a);
+
+        // Negative sizes are standardized.
+        assert_eq!(
+            a.union(rect(3.0, 0.0, -1.0, -1.0)),
+            rect(0.0, -1.0, 3.0, 2.0)
+        );
+
+        // Null rectangles are ignored.
+        assert_eq!(a.union(CGRect::NULL), a);
+        assert_eq!(CGRect::NULL.union(a), a);
+        assert!(CGRect::NULL.union(CGRect::NULL).is_null());
+
+        // Empty, non-null rectangles are not.
+        assert_eq!(a.union(rect(3.0, 3.0, 0.0, 0.0)), rect(0.0, 0.0, 3.0, 3.0));
+    }
 }
@@ -28,6 +28,8 @@ mod cf_type;
 mod data;
 #[cfg(feature = "CFDate")]
 mod date;
+#[cfg(feature = "CFDateFormatter")]
+mod date_formatter;
 #[cfg(feature = "CFDictionary")]
 mod dictionary;
 #[cfg(feature = "CFError")]
@@ -41,6 +43,8 @@ mod generated;
 mod geometry;
 #[cfg(feature = "CFNumber")]
 mod number;
+#[cfg(feature = "CFNumberFormatter")]
+mod number_formatter;
 mod opaque;
 mod retained;
 #[cfg(feature = "CFString")]
@@ -48,6 +52,8 @@ mod string;
 mod thread_safety;
 #[cfg(feature = "CFTimeZone")]
 mod timezone;
+#[cfg(feature = "alloc")]
+mod to_owned;
 mod type_traits;
 #[cfg(feature = "CFURL")]
 mod url;
@@ -55,6 +61,8 @@ mod url;
 mod user_notification;
 #[cfg(feature = "CFUUID")]
 mod uuid;
+#[cfg(feature = "objc2")]
+mod weak;
 
 #[cfg(feature = "CFArray")]
 pub use self::array::*;
@@ -67,6 +75,8 @@ pub use self::generated::*;
 pub use self::geometry::*;
 pub use self::retained::CFRetained;
 pub use self::type_traits::{ConcreteType, Type};
+#[cfg(feature = "objc2")]
+pub use self::weak::CFWeak;
 
 // This is not exposed publicly, so the only way to use this in types with
 // generics is to use it through the default type (e.g. the user should write
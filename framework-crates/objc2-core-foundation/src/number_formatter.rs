@@ -0,0 +1,70 @@
+#![cfg(all(feature = "CFNumber", feature = "CFString"))]
+use core::ptr;
+
+use crate::{CFNumber, CFNumberFormatter, CFRetained, CFString};
+
+impl CFNumberFormatter {
+    /// Format the number as a string, according to the formatter's locale
+    /// and style.
+    #[inline]
+    #[doc(alias = "CFNumberFormatterCreateStringWithNumber")]
+    pub fn string_from_number(&self, number: &CFNumber) -> CFRetained<CFString> {
+        Self::new_string_with_number(None, Some(self), Some(number))
+            .expect("failed formatting number")
+    }
+
+    /// Parse a number from a string, according to the formatter's locale
+    /// and style.
+    ///
+    /// Returns `None` if the entire string could not be parsed as a number.
+    #[inline]
+    #[doc(alias = "CFNumberFormatterCreateNumberFromString")]
+    pub fn number_from_string(&self, string: &CFString) -> Option<CFRetained<CFNumber>> {
+        // Passing a NULL range means that the entire string must be parsed.
+        let rangep = ptr::null_mut();
+        // SAFETY: The range pointer is allowed to be NULL.
+        unsafe { Self::new_number_from_string(None, Some(self), Some(string), rangep, 0) }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "CFLocale")]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::{CFLocale, CFNumberFormatterStyle};
+
+    fn formatter(locale: &'static str) -> CFRetained<CFNumberFormatter> {
+        let locale = CFLocale::new(None, Some(&CFString::from_static_str(locale))).unwrap();
+        CFNumberFormatter::new(None, Some(&locale), CFNumberFormatterStyle::DecimalStyle).unwrap()
+    }
+
+    #[test]
+    fn roundtrip() {
+        let formatter = formatter("en_US");
+        let string = formatter.string_from_number(&CFNumber::new_f64(1234.5));
+        assert_eq!(string.to_string(), "1,234.5");
+        let number = formatter.number_from_string(&string).unwrap();
+        assert_eq!(number.as_f64(), Some(1234.5));
+    }
+
+    #[test]
+    fn locale() {
+        let formatter = formatter("de_DE");
+        let string = formatter.string_from_number(&CFNumber::new_f64(1234.5));
+        assert_eq!(string.to_string(), "1.234,5");
+        let number = formatter.number_from_string(&string).unwrap();
+        assert_eq!(number.as_f64(), Some(1234.5));
+    }
+
+    #[test]
+    fn parse_failure() {
+        let formatter = formatter("en_US");
+        let parse = |s| formatter.number_from_string(&CFString::from_static_str(s));
+        assert!(parse("abc").is_none());
+        // Trailing garbage is not accepted.
+        assert!(parse("12abc").is_none());
+        assert!(parse("").is_none());
+    }
+}
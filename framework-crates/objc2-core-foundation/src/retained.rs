@@ -68,6 +68,18 @@ use CFRetained as Retained;
 /// Additionally, it participates in the null-pointer optimization, that is,
 /// `Option<CFRetained<T>>` is guaranteed to have the same size as
 /// `CFRetained<T>`.
+///
+///
+/// # Thread safety
+///
+/// Same as `Arc`, `CFRetained<T>` is [`Send`] and [`Sync`] if `T` is both
+/// `Send` and `Sync`. This allows placing thread-safe CoreFoundation types
+/// such as `CFNumber` or `CFUUID` in `static`s, e.g. using
+/// [`std::sync::OnceLock`].
+///
+/// Types that are mutable, or that have a mutable subclass (such as
+/// `CFString`, which `CFMutableString` dereferences to), are not thread-safe,
+/// and `CFRetained` to those can thus not be shared between threads.
 #[repr(transparent)]
 #[doc(alias = "id")]
 #[doc(alias = "Retained")]
@@ -226,6 +238,29 @@ impl<T: Type> CFRetained<T> {
         }
     }
 
+    /// Erase the type, converting it to [`CFType`].
+    ///
+    /// This is useful for storing values of different types together, e.g.
+    /// in a `Vec<CFRetained<CFType>>`. Use [`downcast`][Self::downcast] to
+    /// convert back to the concrete type.
+    ///
+    /// The retain count is unchanged, the erased value takes over ownership
+    /// of the object.
+    ///
+    /// This is equivalent to the `From<CFRetained<T>>` implementation on
+    /// `CFRetained<CFType>`.
+    //
+    // NOTE: Not an associated method, for consistency with `downcast`.
+    #[inline]
+    pub fn into_type(self) -> CFRetained<CFType>
+    where
+        T: 'static,
+    {
+        // SAFETY: All `'static` types can be converted to `CFType` without
+        // loss of information.
+        unsafe { Self::cast_unchecked(self) }
+    }
+
     /// Retain the pointer and construct a [`CFRetained`] from it.
     ///
     /// This is useful when you have been given a pointer to a type from some
@@ -433,3 +468,40 @@ impl<T: ?Sized + RefUnwindSafe> RefUnwindSafe for CFRetained<T> {}
 
 // Same as `std::sync::Arc` and `objc2::rc::Retained`.
 impl<T: ?Sized + RefUnwindSafe> UnwindSafe for CFRetained<T> {}
+
+#[cfg(test)]
+#[cfg(all(feature = "CFNumber", feature = "CFString"))]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::{CFNumber, CFString};
+
+    #[test]
+    fn erase_and_downcast() {
+        let string = CFString::from_str("some long string that doesn't get small-string optimized");
+        let number = CFNumber::new_i32(42);
+        assert_eq!(string.retain_count(), 1);
+
+        let values: Vec<CFRetained<CFType>> = vec![string.into_type(), number.into_type()];
+        // Erasing does not retain.
+        assert_eq!(values[0].retain_count(), 1);
+
+        let mut values = values.into_iter();
+
+        let string = values.next().unwrap();
+        // Downcasting to the wrong type gives back the original.
+        let string = string.downcast::<CFNumber>().unwrap_err();
+        let string = string.downcast::<CFString>().unwrap();
+        assert_eq!(string.retain_count(), 1);
+        assert_eq!(
+            string.to_string(),
+            "some long string that doesn't get small-string optimized"
+        );
+
+        let number = values.next().unwrap().downcast::<CFNumber>().unwrap();
+        assert_eq!(number.as_i32(), Some(42));
+    }
+}
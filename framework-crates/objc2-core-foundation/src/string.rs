@@ -1,12 +1,13 @@
 use core::cmp::Ordering;
 use core::ffi::c_char;
 use core::fmt::Write;
+use core::ops::Range;
 use core::ptr::NonNull;
 use core::{fmt, slice, str};
 
 use crate::{
-    kCFAllocatorNull, CFIndex, CFRange, CFRetained, CFString, CFStringBuiltInEncodings,
-    CFStringCompareFlags,
+    kCFAllocatorNull, CFIndex, CFMutableString, CFRange, CFRetained, CFString,
+    CFStringBuiltInEncodings, CFStringCompareFlags, CFStringNormalizationForm,
 };
 
 #[track_caller]
@@ -81,6 +82,24 @@ impl CFString {
         s.expect("failed creating CFString")
     }
 
+    /// Creates a new `CFString` from a slice of UTF-16 code units.
+    ///
+    /// Unlike `String::from_utf16`, this does not validate the input;
+    /// unpaired surrogates are stored as-is, and are preserved when reading
+    /// the code units back out of the string.
+    #[inline]
+    #[doc(alias = "CFStringCreateWithCharacters")]
+    pub fn from_utf16(string: &[u16]) -> CFRetained<Self> {
+        // Can never happen, allocations in Rust cannot be this large.
+        debug_assert!(string.len() < CFIndex::MAX as usize);
+        let len = string.len() as CFIndex;
+        // SAFETY: The pointer and length are valid, and `u16` is the same as
+        // `UniChar`.
+        let s = unsafe { Self::with_characters(None, string.as_ptr(), len) };
+        // Should only fail on allocation error.
+        s.expect("failed creating CFString")
+    }
+
     /// Get the [`str`](`prim@str`) representation of this string if it can be
     /// done efficiently.
     ///
@@ -127,16 +146,16 @@ impl CFString {
     }
 }
 
-impl fmt::Display for CFString {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Copy UTF-8 bytes from the CFString to the formatter in a loop, to
-        // avoid allocating.
-        //
-        // We have to do this instead of using `CFStringGetCStringPtr`, as
-        // that will be invalidated if the string is mutated while in use, and
-        // `fmt::Formatter` contains `dyn Write` which may very theoretically
-        // do exactly that.
-
+impl CFString {
+    /// Call the closure with consecutive UTF-8 chunks of the string, without
+    /// allocating.
+    ///
+    /// UTF-16 code units that cannot be converted to UTF-8 (i.e. lone
+    /// surrogates) are passed as `None`.
+    fn try_for_each_utf8_chunk<E>(
+        &self,
+        mut f: impl FnMut(Option<&str>) -> Result<(), E>,
+    ) -> Result<(), E> {
         // Somewhat reasonably sized stack buffer.
         // TODO: Do performance testing, and tweak this value.
         //
@@ -146,6 +165,8 @@ impl fmt::Display for CFString {
         let mut location_utf16 = 0;
 
         loop {
+            // NOTE: The closure may have mutated the string, so re-read the
+            // length each time.
             let len_utf16 = self.length();
             let mut read_utf8 = 0;
             let read_utf16 = unsafe {
@@ -165,8 +186,8 @@ impl fmt::Display for CFString {
             if read_utf16 <= 0 {
                 if location_utf16 < len_utf16 {
                     // We're not done reading the entire string yet; emit
-                    // replacement character, advance one character, and try again.
-                    f.write_char(char::REPLACEMENT_CHARACTER)?;
+                    // unconvertible code unit, advance one, and try again.
+                    f(None)?;
                     location_utf16 += 1;
                     continue;
                 }
@@ -181,14 +202,114 @@ impl fmt::Display for CFString {
             // split it up when returning UTF-8.
             let s = unsafe { debug_checked_utf8_unchecked(&buf[0..read_utf8 as usize]) };
 
-            // NOTE: May unwind, and may invalidate the string contents.
-            f.write_str(s)?;
+            f(Some(s))?;
         }
 
         Ok(())
     }
 }
 
+impl fmt::Display for CFString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Copy UTF-8 bytes from the CFString to the formatter in a loop, to
+        // avoid allocating.
+        //
+        // We have to do this instead of using `CFStringGetCStringPtr`, as
+        // that will be invalidated if the string is mutated while in use, and
+        // `fmt::Formatter` contains `dyn Write` which may very theoretically
+        // do exactly that.
+        self.try_for_each_utf8_chunk(|chunk| match chunk {
+            // NOTE: May unwind, and may invalidate the string contents.
+            Some(s) => f.write_str(s),
+            None => f.write_char(char::REPLACEMENT_CHARACTER),
+        })
+    }
+}
+
+impl CFString {
+    /// Write the contents of the string to the given writer as UTF-8.
+    ///
+    /// The string is converted in small chunks using `CFStringGetBytes`, so
+    /// this never materializes the whole string as a Rust [`String`], which
+    /// is useful for very large strings.
+    ///
+    /// UTF-16 code units that cannot be converted to UTF-8 (i.e. lone
+    /// surrogates) are written as U+FFFD REPLACEMENT CHARACTER, the same as
+    /// in the [`Display`] implementation.
+    ///
+    /// [`String`]: std::string::String
+    /// [`Display`]: fmt::Display
+    #[cfg(feature = "std")]
+    #[doc(alias = "CFStringGetBytes")]
+    pub fn write_utf8_to(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        let mut replacement = [0; 4];
+        let replacement = char::REPLACEMENT_CHARACTER.encode_utf8(&mut replacement);
+        self.try_for_each_utf8_chunk(|chunk| match chunk {
+            // NOTE: May unwind, and may invalidate the string contents.
+            Some(s) => w.write_all(s.as_bytes()),
+            None => w.write_all(replacement.as_bytes()),
+        })
+    }
+}
+
+impl PartialEq<str> for CFString {
+    /// Compare the contents of the string with a Rust string, without
+    /// allocating.
+    ///
+    /// Strings containing lone surrogates never compare equal.
+    fn eq(&self, other: &str) -> bool {
+        // Each UTF-16 code unit takes up between 1 and 3 bytes in UTF-8.
+        let len_utf16 = self.length() as usize;
+        if other.len() < len_utf16 || len_utf16.saturating_mul(3) < other.len() {
+            return false;
+        }
+
+        // Fast path for ASCII strings.
+        //
+        // SAFETY: The string is not mutated while the reference is alive.
+        if let Some(s) = unsafe { self.as_str_unchecked() } {
+            return s == other;
+        }
+
+        let mut rest = other.as_bytes();
+        let res = self.try_for_each_utf8_chunk(|chunk| {
+            let chunk = chunk.ok_or(())?;
+            rest = rest.strip_prefix(chunk.as_bytes()).ok_or(())?;
+            Ok(())
+        });
+        res.is_ok() && rest.is_empty()
+    }
+}
+
+impl PartialEq<&str> for CFString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<alloc::string::String> for CFString {
+    #[inline]
+    fn eq(&self, other: &alloc::string::String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<CFString> for str {
+    #[inline]
+    fn eq(&self, other: &CFString) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<CFString> for &str {
+    #[inline]
+    fn eq(&self, other: &CFString) -> bool {
+        other == *self
+    }
+}
+
 impl PartialOrd for CFString {
     #[inline]
     #[doc(alias = "CFStringCompare")]
@@ -207,12 +328,259 @@ impl Ord for CFString {
     }
 }
 
+impl CFString {
+    /// Create a copy of the string that is normalized to the given Unicode
+    /// normalization form.
+    ///
+    /// This is useful for comparing strings, since a character such as "é"
+    /// can be represented both as a single precomposed code point, and as an
+    /// "e" followed by a combining acute accent.
+    ///
+    /// The original string is left untouched.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if `form` is not one of the known normalization forms.
+    #[doc(alias = "CFStringNormalize")]
+    pub fn normalized(&self, form: CFStringNormalizationForm) -> CFRetained<CFString> {
+        assert!(
+            matches!(
+                form,
+                CFStringNormalizationForm::D
+                    | CFStringNormalizationForm::KD
+                    | CFStringNormalizationForm::C
+                    | CFStringNormalizationForm::KC
+            ),
+            "invalid normalization form {form:?}",
+        );
+        // A max length of 0 means that the string is unbounded.
+        let copy = CFMutableString::new_copy(None, 0, Some(self))
+            .expect("failed creating mutable copy of CFString");
+        // SAFETY: The normalization form was checked to be valid above.
+        unsafe { CFMutableString::normalize(Some(&copy), form) };
+        // SAFETY: Upcasting `CFMutableString` to `CFString`.
+        unsafe { CFRetained::cast_unchecked::<CFString>(copy) }
+    }
+
+    /// Find the first occurrence of `substring` in the string.
+    ///
+    /// The returned range is in UTF-16 code units, as is used everywhere
+    /// else in CoreFoundation, and not in bytes like Rust's [`str::find`].
+    ///
+    /// Returns `None` if the substring was not found.
+    #[doc(alias = "CFStringFind")]
+    pub fn find_range(
+        &self,
+        substring: &CFString,
+        options: CFStringCompareFlags,
+    ) -> Option<Range<usize>> {
+        let range = self.find(Some(substring), options);
+        // `kCFNotFound` is negative.
+        if range.location < 0 {
+            return None;
+        }
+        let start = range.location as usize;
+        Some(start..start + range.length as usize)
+    }
+}
+
+/// Convenience mutation methods.
+impl CFMutableString {
+    /// Append a single character to the string.
+    ///
+    /// Characters outside the Basic Multilingual Plane are appended as a
+    /// UTF-16 surrogate pair.
+    #[inline]
+    #[doc(alias = "CFStringAppendCharacters")]
+    pub fn push(&self, c: char) {
+        let mut buf = [0; 2];
+        let chars = c.encode_utf16(&mut buf);
+        // SAFETY: The pointer and length are valid, and come from a slice.
+        unsafe {
+            CFMutableString::append_characters(Some(self), chars.as_ptr(), chars.len() as CFIndex)
+        };
+    }
+
+    /// Append a string slice to the string.
+    #[inline]
+    #[doc(alias = "CFStringAppend")]
+    pub fn push_str(&self, string: &str) {
+        CFMutableString::append(Some(self), Some(&CFString::from_str(string)));
+    }
+
+    /// Replace all occurrences of `target` in the string with `replacement`.
+    ///
+    /// The string is searched from the start, and the matches do not
+    /// overlap; so replacing `"aa"` in `"aaa"` only replaces the first two
+    /// characters.
+    ///
+    /// Returns the number of replacements that were made.
+    #[doc(alias = "CFStringFindAndReplace")]
+    pub fn replace_occurrences(&self, target: &CFString, replacement: &CFString) -> usize {
+        let range = CFRange {
+            location: 0,
+            length: self.length(),
+        };
+        let flags = CFStringCompareFlags::empty();
+        // SAFETY: The range covers exactly the entire string.
+        let count = unsafe {
+            CFMutableString::find_and_replace(
+                Some(self),
+                Some(target),
+                Some(replacement),
+                range,
+                flags,
+            )
+        };
+        count as usize
+    }
+}
+
+impl Extend<char> for &CFMutableString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            self.push(c);
+        }
+    }
+}
+
+impl<'a> Extend<&'a str> for &CFMutableString {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push_str(s);
+        }
+    }
+}
+
+impl Extend<char> for CFRetained<CFMutableString> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        (&**self).extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a str> for CFRetained<CFMutableString> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        (&**self).extend(iter);
+    }
+}
+
+impl FromIterator<char> for CFRetained<CFMutableString> {
+    #[doc(alias = "CFStringCreateMutable")]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        // A max length of 0 means that the string is unbounded.
+        let mut string = CFMutableString::new(None, 0).expect("failed creating CFMutableString");
+        string.extend(iter);
+        string
+    }
+}
+
+impl FromIterator<char> for CFRetained<CFString> {
+    #[doc(alias = "CFStringCreateCopy")]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let string: CFRetained<CFMutableString> = iter.into_iter().collect();
+        // Make an immutable copy, the string would otherwise still be
+        // mutable behind the `CFString`.
+        CFString::new_copy(None, Some(&string)).expect("failed copying CFString")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
+    use alloc::vec::Vec;
     use core::ffi::CStr;
 
     use super::*;
+    use crate::{CFGetTypeID, CFType, ConcreteType};
+
+    #[test]
+    fn from_utf16() {
+        // "a😀b", where the emoji is outside the Basic Multilingual Plane.
+        let utf16: Vec<u16> = "a😀b".encode_utf16().collect();
+        assert_eq!(utf16.len(), 4);
+
+        let s = CFString::from_utf16(&utf16);
+        assert_eq!(s.length(), 4);
+        assert_eq!(s.to_string(), "a😀b");
+        assert_eq!(s, CFString::from_str("a😀b"));
+
+        let mut buf = [0u16; 4];
+        unsafe { s.characters(CFRange::new(0, 4), buf.as_mut_ptr()) };
+        assert_eq!(buf[..], utf16[..]);
+
+        // Unpaired surrogates are kept.
+        let lone = [0x61, 0xD83D, 0x62];
+        let s = CFString::from_utf16(&lone);
+        assert_eq!(s.length(), 3);
+        let mut buf = [0u16; 3];
+        unsafe { s.characters(CFRange::new(0, 3), buf.as_mut_ptr()) };
+        assert_eq!(buf, lone);
+
+        assert_eq!(CFString::from_utf16(&[]).length(), 0);
+    }
+
+    #[test]
+    fn normalize() {
+        // "e" followed by U+0301 COMBINING ACUTE ACCENT.
+        let decomposed = CFString::from_str("e\u{301}");
+        let precomposed = CFString::from_str("\u{e9}");
+        assert_ne!(decomposed, precomposed);
+
+        let normalized = decomposed.normalized(CFStringNormalizationForm::C);
+        assert_eq!(normalized, precomposed);
+        assert_eq!(normalized.length(), 1);
+        // The original is untouched.
+        assert_eq!(decomposed.to_string(), "e\u{301}");
+
+        let normalized = precomposed.normalized(CFStringNormalizationForm::D);
+        assert_eq!(normalized, decomposed);
+    }
+
+    #[test]
+    fn find() {
+        let s = CFString::from_str("a😀bcb");
+        let flags = CFStringCompareFlags::empty();
+        // The emoji is two UTF-16 code units.
+        assert_eq!(s.find_range(&CFString::from_str("b"), flags), Some(3..4));
+        assert_eq!(s.find_range(&CFString::from_str("bcb"), flags), Some(3..6));
+        assert_eq!(s.find_range(&CFString::from_str("x"), flags), None);
+        assert_eq!(s.find_range(&CFString::from_str("B"), flags), None);
+        assert_eq!(
+            s.find_range(
+                &CFString::from_str("B"),
+                CFStringCompareFlags::CompareCaseInsensitive
+            ),
+            Some(3..4)
+        );
+    }
+
+    #[test]
+    fn replace() {
+        let s: CFRetained<CFMutableString> = "a-b-c".chars().collect();
+        let count = s.replace_occurrences(&CFString::from_str("-"), &CFString::from_str("😀"));
+        assert_eq!(count, 2);
+        assert_eq!(s.to_string(), "a😀b😀c");
+
+        // Matches do not overlap.
+        let s: CFRetained<CFMutableString> = "aaa".chars().collect();
+        let count = s.replace_occurrences(&CFString::from_str("aa"), &CFString::from_str("b"));
+        assert_eq!(count, 1);
+        assert_eq!(s.to_string(), "ba");
+
+        let count = s.replace_occurrences(&CFString::from_str("x"), &CFString::from_str("y"));
+        assert_eq!(count, 0);
+        assert_eq!(s.to_string(), "ba");
+    }
+
+    #[test]
+    fn type_id() {
+        let s = CFString::from_str("abc");
+        let cf: &CFType = &s;
+        assert_eq!(CFString::type_id(), CFGetTypeID(Some(cf)));
+    }
 
     #[test]
     fn basic_conversion() {
@@ -317,6 +685,29 @@ mod tests {
         assert_eq!(CFString::from_str(s).to_string(), s);
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn write_utf8() {
+        // Mix of 1, 2, 3 and 4 byte UTF-8 sequences, so that they end up
+        // across chunk boundaries.
+        let expected = "aæ♥😀".repeat(1000);
+        let s = CFString::from_str(&expected);
+        let mut buf = Vec::new();
+        s.write_utf8_to(&mut buf).unwrap();
+        assert_eq!(buf, expected.as_bytes());
+
+        let mut buf = Vec::new();
+        CFString::from_static_str("")
+            .write_utf8_to(&mut buf)
+            .unwrap();
+        assert!(buf.is_empty());
+
+        let s = CFString::from_utf16(&[0x61, 0xD83D, 0x62]);
+        let mut buf = Vec::new();
+        s.write_utf8_to(&mut buf).unwrap();
+        assert_eq!(buf, "a\u{FFFD}b".as_bytes());
+    }
+
     #[test]
     fn create_with_cstring_broken_on_non_8_bit() {
         // A CFString that is supposed to contain a "♥" (the UTF-8 encoding of
@@ -376,5 +767,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eq_str() {
+        let s = CFString::from_str("abc");
+        assert_eq!(*s, *"abc");
+        assert_eq!(*s, "abc");
+        assert_eq!("abc", *s);
+        assert_ne!(*s, "abd");
+        assert_ne!(*s, "ab");
+        assert_ne!(*s, "abcd");
+        assert_ne!(*s, "");
+        assert_eq!(*CFString::from_str(""), "");
+        #[cfg(feature = "alloc")]
+        assert_eq!(*s, alloc::string::String::from("abc"));
+
+        // Not ASCII, so doesn't hit the fast path.
+        let s = CFString::from_str("æ♥😀");
+        assert_eq!(unsafe { s.as_str_unchecked() }, None);
+        assert_eq!(*s, "æ♥😀");
+        assert_ne!(*s, "æ♥😁");
+        assert_ne!(*s, "æ♥");
+        assert_ne!(*s, "æ♥😀a");
+        assert_ne!(*s, "abc");
+
+        // Longer than the internal buffer.
+        let long = "ø".repeat(40);
+        assert_eq!(*CFString::from_str(&long), *long);
+        assert_ne!(*CFString::from_str(&long), *"ø".repeat(39));
+
+        // Lone surrogates never compare equal, even to the replacement
+        // character that they're displayed as.
+        let s = CFString::from_utf16(&[0x61, 0xD800]);
+        assert_eq!(s.to_string(), "a\u{FFFD}");
+        assert_ne!(*s, "a\u{FFFD}");
+    }
+
+    #[test]
+    fn collect_chars() {
+        let s: CFRetained<CFString> = "abc😀æ".chars().collect();
+        assert_eq!(s.to_string(), "abc😀æ");
+        // The emoji is outside the BMP, and is stored as a surrogate pair.
+        assert_eq!(s.length(), 7);
+
+        let s: CFRetained<CFString> = core::iter::empty().collect();
+        assert_eq!(s.to_string(), "");
+    }
+
+    #[test]
+    fn extend_mutable() {
+        let mut s: CFRetained<CFMutableString> = "ab".chars().collect();
+        s.extend(['c', '𝄞']);
+        s.extend(["de", "", "ƒ"]);
+        assert_eq!(s.to_string(), "abc𝄞deƒ");
+    }
+
     // TODO: Test mutation while formatting.
 }
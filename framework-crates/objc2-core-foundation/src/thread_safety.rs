@@ -195,6 +195,40 @@ mod tests {
         thread_safe!(CFURL);
     }
 
+    #[test]
+    fn retained() {
+        #[cfg(feature = "CFNumber")]
+        thread_safe!(CFRetained<CFNumber>);
+        #[cfg(feature = "CFString")]
+        not_thread_safe!(CFRetained<CFString>, CFRetained<CFMutableString>);
+        not_thread_safe!(CFRetained<CFType>);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "CFNumber"))]
+    fn retained_in_static() {
+        use std::sync::OnceLock;
+        use std::thread;
+        use std::vec::Vec;
+
+        static NUMBER: OnceLock<CFRetained<CFNumber>> = OnceLock::new();
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                thread::spawn(|| {
+                    let number = NUMBER.get_or_init(|| CFNumber::new_i32(42));
+                    assert_eq!(number.as_i32(), Some(42));
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(NUMBER.get().unwrap().as_i32(), Some(42));
+    }
+
     #[test]
     fn uncertain() {
         // Uncertain, so marked as thread-unsafe for now.
@@ -0,0 +1,116 @@
+//! `ToOwned` implementations, to allow using CoreFoundation types in `Cow`.
+
+#[cfg(feature = "CFData")]
+impl alloc::borrow::ToOwned for crate::CFData {
+    type Owned = crate::CFRetained<Self>;
+
+    /// Create an immutable copy of the data.
+    ///
+    /// This is necessary (instead of just retaining), since the data may be
+    /// a `CFMutableData`.
+    #[doc(alias = "CFDataCreateCopy")]
+    fn to_owned(&self) -> Self::Owned {
+        Self::new_copy(None, Some(self)).expect("failed copying CFData")
+    }
+}
+
+#[cfg(feature = "CFString")]
+impl alloc::borrow::ToOwned for crate::CFString {
+    type Owned = crate::CFRetained<Self>;
+
+    /// Create an immutable copy of the string.
+    ///
+    /// This is necessary (instead of just retaining), since the string may
+    /// be a `CFMutableString`.
+    #[doc(alias = "CFStringCreateCopy")]
+    fn to_owned(&self) -> Self::Owned {
+        Self::new_copy(None, Some(self)).expect("failed copying CFString")
+    }
+}
+
+// The following types are immutable, so we can just retain them.
+
+#[cfg(feature = "CFNumber")]
+impl alloc::borrow::ToOwned for crate::CFBoolean {
+    type Owned = crate::CFRetained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        crate::Type::retain(self)
+    }
+}
+
+#[cfg(feature = "CFNumber")]
+impl alloc::borrow::ToOwned for crate::CFNumber {
+    type Owned = crate::CFRetained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        crate::Type::retain(self)
+    }
+}
+
+#[cfg(feature = "CFDate")]
+impl alloc::borrow::ToOwned for crate::CFDate {
+    type Owned = crate::CFRetained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        crate::Type::retain(self)
+    }
+}
+
+#[cfg(feature = "CFURL")]
+impl alloc::borrow::ToOwned for crate::CFURL {
+    type Owned = crate::CFRetained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        crate::Type::retain(self)
+    }
+}
+
+#[cfg(feature = "CFUUID")]
+impl alloc::borrow::ToOwned for crate::CFUUID {
+    type Owned = crate::CFRetained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        crate::Type::retain(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "CFString")]
+    fn cow_string() {
+        use alloc::borrow::Cow;
+
+        let s = crate::CFString::from_str("abc");
+
+        let cow: Cow<'_, crate::CFString> = Cow::Borrowed(&s);
+        assert_eq!(&*cow, &*s);
+
+        let owned = cow.into_owned();
+        assert_eq!(owned, s);
+    }
+
+    #[test]
+    #[cfg(feature = "CFNumber")]
+    fn cow_number() {
+        use alloc::borrow::Cow;
+
+        use crate::CFRetained;
+
+        let n = crate::CFNumber::new_i32(42);
+        let cow: Cow<'_, crate::CFNumber> = Cow::Borrowed(&n);
+        let owned = cow.into_owned();
+        assert_eq!(CFRetained::as_ptr(&owned), CFRetained::as_ptr(&n));
+    }
+
+    #[test]
+    #[cfg(feature = "CFString")]
+    fn borrow_in_collection() {
+        use alloc::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert(crate::CFString::from_str("a"), 1);
+        map.insert(crate::CFString::from_str("b"), 2);
+
+        // Look up using `&CFString`, via `Borrow`.
+        let key = crate::CFString::from_str("b");
+        let key: &crate::CFString = &key;
+        assert_eq!(map.get(key), Some(&2));
+    }
+}
@@ -0,0 +1,149 @@
+use core::fmt;
+
+use objc2::rc::Weak;
+use objc2::Message;
+
+use crate::{CFRetained, Type};
+
+/// A weak pointer to a CoreFoundation object.
+///
+/// This allows checking whether the object has been deallocated, without
+/// keeping it alive, and is the CoreFoundation equivalent of
+/// [`objc2::rc::Weak`].
+///
+/// This uses the Objective-C runtime's weak reference system, and thus only
+/// works for objects that participate in that; in practice, this is limited
+/// to types that are [toll-free bridged] to an Objective-C class, such as
+/// `CFString` or `CFArray`. Trying to create a weak reference to an object
+/// that doesn't support it will abort the process.
+///
+/// [toll-free bridged]: https://developer.apple.com/library/archive/documentation/General/Conceptual/CocoaEncyclopedia/Toll-FreeBridgin/Toll-FreeBridgin.html
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2_core_foundation::{CFRetained, CFString, CFWeak};
+///
+/// let string = CFString::from_str("a string that is stored on the heap");
+/// let weak = CFRetained::downgrade(&string);
+/// assert_eq!(weak.load().as_deref(), Some(&*string));
+///
+/// drop(string);
+/// assert!(weak.load().is_none());
+/// ```
+#[repr(transparent)]
+#[doc(alias = "objc_storeWeak")]
+pub struct CFWeak<T: ?Sized>(Weak<T>);
+
+impl<T: Type + Message> CFWeak<T> {
+    /// Construct a new weak pointer that references the given object.
+    #[inline]
+    #[doc(alias = "objc_initWeak")]
+    pub fn new(obj: &T) -> Self {
+        Self(Weak::new(obj))
+    }
+
+    /// Load a retained pointer to the object, or `None` if the object has
+    /// been deallocated.
+    #[inline]
+    #[doc(alias = "objc_loadWeakRetained")]
+    #[doc(alias = "upgrade")]
+    pub fn load(&self) -> Option<CFRetained<T>> {
+        self.0.load().map(CFRetained::from)
+    }
+}
+
+impl<T: Type + Message> CFRetained<T> {
+    /// Create a new [`CFWeak`] pointer to the object.
+    ///
+    /// This is an associated method, and must be called as
+    /// `CFRetained::downgrade(&obj)`.
+    #[inline]
+    pub fn downgrade(this: &Self) -> CFWeak<T> {
+        CFWeak::new(this)
+    }
+}
+
+impl<T: Type + Message> Clone for CFWeak<T> {
+    #[inline]
+    #[doc(alias = "objc_copyWeak")]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Type + Message> Default for CFWeak<T> {
+    /// Construct a new weak pointer that references nothing.
+    #[inline]
+    fn default() -> Self {
+        Self(Weak::default())
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for CFWeak<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Same as `objc2::rc::Weak`, don't print the inner pointer.
+        write!(f, "(CFWeak)")
+    }
+}
+
+impl<T: Type + Message> From<&T> for CFWeak<T> {
+    #[inline]
+    fn from(obj: &T) -> Self {
+        Self::new(obj)
+    }
+}
+
+impl<T: Type + Message> From<&CFRetained<T>> for CFWeak<T> {
+    #[inline]
+    fn from(obj: &CFRetained<T>) -> Self {
+        Self::new(obj)
+    }
+}
+
+impl<T: Type + Message> From<CFWeak<T>> for Weak<T> {
+    #[inline]
+    fn from(weak: CFWeak<T>) -> Self {
+        weak.0
+    }
+}
+
+impl<T: Type + Message> From<Weak<T>> for CFWeak<T> {
+    #[inline]
+    fn from(weak: Weak<T>) -> Self {
+        Self(weak)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "CFString")]
+mod tests {
+    use super::*;
+    use crate::CFString;
+
+    #[test]
+    fn downgrade_upgrade() {
+        // Long enough that it won't be a tagged pointer.
+        let string = CFString::from_str("a string that is stored on the heap");
+        let weak = CFRetained::downgrade(&string);
+        let weak2 = weak.clone();
+
+        // Upgrade before release.
+        let loaded = weak.load().unwrap();
+        assert_eq!(CFRetained::as_ptr(&loaded), CFRetained::as_ptr(&string));
+        drop(loaded);
+        assert!(weak2.load().is_some());
+
+        // Upgrade after release.
+        drop(string);
+        assert!(weak.load().is_none());
+        assert!(weak2.load().is_none());
+    }
+
+    #[test]
+    fn default_is_empty() {
+        let weak = CFWeak::<CFString>::default();
+        assert!(weak.load().is_none());
+    }
+}
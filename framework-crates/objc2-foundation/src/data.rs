@@ -35,12 +35,18 @@ impl NSMutableData {
 }
 
 impl NSData {
-    // TODO: Rename to `from_bytes` to match `CFData::from_bytes`.
-    pub fn with_bytes(bytes: &[u8]) -> Retained<Self> {
+    /// Create a new data object by copying the given bytes.
+    #[doc(alias = "initWithBytes:length:")]
+    pub fn from_bytes(bytes: &[u8]) -> Retained<Self> {
         let bytes_ptr = bytes.as_ptr() as *mut c_void;
         unsafe { Self::initWithBytes_length(Self::alloc(), bytes_ptr, bytes.len()) }
     }
 
+    #[deprecated = "renamed to `NSData::from_bytes`"]
+    pub fn with_bytes(bytes: &[u8]) -> Retained<Self> {
+        Self::from_bytes(bytes)
+    }
+
     #[cfg(feature = "block2")]
     #[cfg(feature = "alloc")]
     pub fn from_vec(bytes: Vec<u8>) -> Retained<Self> {
@@ -49,12 +55,19 @@ impl NSData {
 }
 
 impl NSMutableData {
-    pub fn with_bytes(bytes: &[u8]) -> Retained<Self> {
+    /// Create a new mutable data object by copying the given bytes.
+    #[doc(alias = "initWithBytes:length:")]
+    pub fn from_bytes(bytes: &[u8]) -> Retained<Self> {
         let bytes_ptr = bytes.as_ptr() as *mut c_void;
-        // SAFETY: Same as `NSData::with_bytes`
+        // SAFETY: Same as `NSData::from_bytes`
         unsafe { Self::initWithBytes_length(Self::alloc(), bytes_ptr, bytes.len()) }
     }
 
+    #[deprecated = "renamed to `NSMutableData::from_bytes`"]
+    pub fn with_bytes(bytes: &[u8]) -> Retained<Self> {
+        Self::from_bytes(bytes)
+    }
+
     #[cfg(feature = "block2")]
     pub fn from_vec(bytes: Vec<u8>) -> Retained<Self> {
         // SAFETY: Same as `NSData::from_vec`
@@ -73,6 +86,9 @@ impl NSData {
 
     /// The bytes in the data.
     ///
+    /// If the data is empty, this returns an empty slice (even if the
+    /// underlying `bytes` pointer is NULL).
+    ///
     /// # Safety
     ///
     /// The data must not be mutated while the returned slice is alive.
@@ -80,6 +96,7 @@ impl NSData {
     /// difficult to uphold.
     ///
     /// [`to_vec`]: Self::to_vec
+    #[doc(alias = "bytes")]
     pub unsafe fn as_bytes_unchecked(&self) -> &[u8] {
         let ptr = self.bytes_raw();
         if !ptr.is_null() {
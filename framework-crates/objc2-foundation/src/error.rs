@@ -32,6 +32,44 @@ impl NSError {
     pub fn NSLocalizedDescriptionKey() -> &'static crate::NSErrorUserInfoKey {
         unsafe { crate::NSLocalizedDescriptionKey }
     }
+
+    /// The string stored under the given key in the user info dictionary.
+    ///
+    /// Returns `None` if the key is missing, or if the value is not a string.
+    #[cfg(feature = "NSDictionary")]
+    #[cfg(feature = "NSString")]
+    fn user_info_string(
+        &self,
+        key: &crate::NSErrorUserInfoKey,
+    ) -> Option<Retained<crate::NSString>> {
+        self.userInfo().objectForKey(key)?.downcast().ok()
+    }
+
+    /// The value of [`NSLocalizedFailureReasonErrorKey`] in the user info
+    /// dictionary, or `None` if not set.
+    ///
+    /// Unlike [`localizedFailureReason`][Self::localizedFailureReason], this
+    /// only looks at the user info dictionary itself.
+    ///
+    /// [`NSLocalizedFailureReasonErrorKey`]: crate::NSLocalizedFailureReasonErrorKey
+    #[cfg(feature = "NSDictionary")]
+    #[cfg(feature = "NSString")]
+    pub fn localized_failure_reason(&self) -> Option<Retained<crate::NSString>> {
+        self.user_info_string(unsafe { crate::NSLocalizedFailureReasonErrorKey })
+    }
+
+    /// The value of [`NSLocalizedRecoverySuggestionErrorKey`] in the user
+    /// info dictionary, or `None` if not set.
+    ///
+    /// Unlike [`localizedRecoverySuggestion`][Self::localizedRecoverySuggestion],
+    /// this only looks at the user info dictionary itself.
+    ///
+    /// [`NSLocalizedRecoverySuggestionErrorKey`]: crate::NSLocalizedRecoverySuggestionErrorKey
+    #[cfg(feature = "NSDictionary")]
+    #[cfg(feature = "NSString")]
+    pub fn recovery_suggestion(&self) -> Option<Retained<crate::NSString>> {
+        self.user_info_string(unsafe { crate::NSLocalizedRecoverySuggestionErrorKey })
+    }
 }
 
 #[cfg(feature = "std")]
@@ -0,0 +1,227 @@
+use alloc::ffi::CString;
+use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::mem;
+use std::sync::Mutex;
+
+use objc2::runtime::{AnyClass, AnyObject, Imp, Sel};
+use objc2::{ffi, sel};
+
+use crate::NSInvocation;
+
+type Before = Arc<dyn Fn(&NSInvocation) + Send + Sync>;
+
+struct Interceptor {
+    cls: &'static AnyClass,
+    sel: Sel,
+    /// The selector that the original implementation was moved to.
+    original: Sel,
+    before: Before,
+}
+
+struct Forwarder {
+    cls: &'static AnyClass,
+    /// The `forwardInvocation:` implementation that was in effect before we
+    /// installed ours.
+    previous: Imp,
+}
+
+struct Registry {
+    interceptors: Vec<Interceptor>,
+    forwarders: Vec<Forwarder>,
+}
+
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry {
+    interceptors: Vec::new(),
+    forwarders: Vec::new(),
+});
+
+/// Intercept calls to an instance method, and call the given closure before
+/// the original implementation is run.
+///
+/// The closure receives an [`NSInvocation`] describing the message, which
+/// can be used to inspect the receiver and arguments. Afterwards, the
+/// invocation is forwarded to the original implementation, and its return
+/// value is passed back to the caller unchanged.
+///
+/// This works by replacing the method's implementation with the runtime's
+/// message forwarding machinery, and then overriding `forwardInvocation:` on
+/// the class to call the closure. Since `NSInvocation` is created from the
+/// method's signature, arguments and return values of any type are
+/// preserved. This also affects subclasses, unless they override the method
+/// without calling `super`.
+///
+/// Note that message forwarding is quite slow, so this is mostly useful for
+/// debugging and logging.
+///
+///
+/// # Panics
+///
+/// Panics if the class doesn't have an instance method with the given
+/// selector, or if the method has already been intercepted.
+///
+/// On targets other than AArch64 (such as x86_64), this also panics if the
+/// method returns a struct, since those may use a different forwarding
+/// mechanism.
+///
+///
+/// # Safety
+///
+/// - The closure must not modify the invocation's target or selector, and
+///   must only modify the arguments in ways that are valid for the method.
+/// - The class must not be modified in conflicting ways, such as by other
+///   code that also overrides `forwardInvocation:` or swizzles the method
+///   at the same time.
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use objc2::{class, sel};
+/// use objc2_foundation::intercept;
+///
+/// unsafe {
+///     intercept(class!(NSObject), sel!(description), |invocation| {
+///         println!("called {:?}", invocation.selector());
+///     })
+/// };
+/// ```
+#[doc(alias = "swizzle")]
+#[doc(alias = "forwardInvocation:")]
+pub unsafe fn intercept(
+    cls: &'static AnyClass,
+    sel: Sel,
+    before: impl Fn(&NSInvocation) + Send + Sync + 'static,
+) {
+    let method = cls
+        .instance_method(sel)
+        .unwrap_or_else(|| panic!("-[{cls} {sel}] does not exist"));
+    // SAFETY: The method pointer is valid.
+    let types = unsafe { ffi::method_getTypeEncoding(method) };
+    assert!(!types.is_null(), "method type encoding was NULL");
+
+    #[cfg(not(target_arch = "aarch64"))]
+    {
+        let ret = method.return_type();
+        assert!(
+            !ret.to_bytes().starts_with(b"{"),
+            "intercepting methods that return structs is not supported"
+        );
+    }
+
+    let mut registry = REGISTRY.lock().unwrap_or_else(|err| err.into_inner());
+
+    // Also check the implementation, in case a superclass was intercepted.
+    let forward: Imp = ffi::_objc_msgForward;
+    assert!(
+        !registry
+            .interceptors
+            .iter()
+            .any(|i| i.cls == cls && i.sel == sel)
+            && method.implementation() as usize != forward as usize,
+        "-[{cls} {sel}] is already intercepted"
+    );
+
+    let cls_ptr: *const AnyClass = cls;
+    let cls_ptr = cls_ptr.cast_mut();
+
+    // Move the original implementation to a new selector.
+    let original = CString::new(format!("__objc2_intercepted_{sel}")).unwrap();
+    let original = Sel::register(&original);
+    // SAFETY: The implementation and types are taken from the same method.
+    let added = unsafe { ffi::class_addMethod(cls_ptr, original, method.implementation(), types) };
+    assert!(added.as_bool(), "failed adding method {original}");
+
+    // Install our `forwardInvocation:`, if not already done on this class.
+    if !registry.forwarders.iter().any(|f| f.cls == cls) {
+        let forward_sel = sel!(forwardInvocation:);
+        let previous = cls
+            .instance_method(forward_sel)
+            .expect("class must implement forwardInvocation:")
+            .implementation();
+        // SAFETY: The function has the signature `v@:@`.
+        let imp: Imp = unsafe {
+            mem::transmute::<unsafe extern "C-unwind" fn(&AnyObject, Sel, &NSInvocation), Imp>(
+                forward_invocation,
+            )
+        };
+        // SAFETY: The encoding matches the function above.
+        unsafe { ffi::class_replaceMethod(cls_ptr, forward_sel, imp, b"v@:@\0".as_ptr().cast()) };
+        registry.forwarders.push(Forwarder { cls, previous });
+    }
+
+    registry.interceptors.push(Interceptor {
+        cls,
+        sel,
+        original,
+        before: Arc::new(before),
+    });
+
+    // Finally, route the method through the forwarding machinery. This is
+    // done last, so that the registry is ready when the method is called.
+    //
+    // SAFETY: `_objc_msgForward` can be used as the implementation of any
+    // method (that doesn't return a struct outside AArch64, checked above),
+    // and we keep the types the same.
+    unsafe { ffi::class_replaceMethod(cls_ptr, sel, forward, types) };
+}
+
+/// Walk the class hierarchy to find the first class that matches.
+fn find<'a, T>(
+    mut cls: Option<&AnyClass>,
+    items: &'a [T],
+    mut matches: impl FnMut(&AnyClass, &T) -> bool,
+) -> Option<&'a T> {
+    while let Some(current) = cls {
+        if let Some(item) = items.iter().find(|item| matches(current, item)) {
+            return Some(item);
+        }
+        cls = current.superclass();
+    }
+    None
+}
+
+unsafe extern "C-unwind" fn forward_invocation(
+    this: &AnyObject,
+    cmd: Sel,
+    invocation: &NSInvocation,
+) {
+    let sel = invocation.selector();
+    let cls = this.class();
+
+    let registry = REGISTRY.lock().unwrap_or_else(|err| err.into_inner());
+    let interceptor = find(Some(cls), &registry.interceptors, |cls, i| {
+        i.cls == cls && i.sel == sel
+    });
+
+    if let Some(interceptor) = interceptor {
+        let original = interceptor.original;
+        let before = interceptor.before.clone();
+        // Don't hold the lock while calling out to user code.
+        drop(registry);
+
+        before(invocation);
+
+        // SAFETY: The original implementation was added under this selector
+        // with the same types, so the invocation's signature is still valid.
+        unsafe { invocation.setSelector(original) };
+        // SAFETY: Upheld by the caller of `intercept`.
+        unsafe { invocation.invoke() };
+        // Restore the selector, in case the invocation is inspected later.
+        unsafe { invocation.setSelector(sel) };
+    } else {
+        // Not a message we've intercepted, so defer to the previous
+        // implementation (which usually raises "unrecognized selector").
+        let forwarder = find(Some(cls), &registry.forwarders, |cls, f| f.cls == cls)
+            .expect("forwardInvocation: called on class without forwarder");
+        let previous = forwarder.previous;
+        drop(registry);
+
+        // SAFETY: The previous implementation of `forwardInvocation:` has
+        // the signature `v@:@`.
+        let previous: unsafe extern "C-unwind" fn(&AnyObject, Sel, &NSInvocation) =
+            unsafe { mem::transmute(previous) };
+        unsafe { previous(this, cmd, invocation) };
+    }
+}
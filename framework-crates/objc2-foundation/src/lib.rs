@@ -120,6 +120,8 @@ extern crate std;
 
 #[doc(hidden)]
 pub mod __ns_macro_helpers;
+#[cfg(all(feature = "NSInvocation", feature = "std", target_vendor = "apple"))]
+mod intercept;
 #[cfg(feature = "NSEnumerator")]
 #[macro_use]
 mod iter;
@@ -151,6 +153,13 @@ mod generated;
 #[cfg(feature = "NSGeometry")]
 mod geometry;
 mod macros;
+#[cfg(all(
+    feature = "NSNotification",
+    feature = "NSOperation",
+    feature = "NSString",
+    feature = "block2"
+))]
+mod notification;
 mod ns_consumed;
 #[cfg(feature = "NSValue")]
 mod number;
@@ -190,8 +199,17 @@ pub use self::generated::*;
 pub use self::geometry::NSRectEdge;
 #[cfg(all(feature = "NSGeometry", feature = "objc2-core-foundation"))]
 pub use self::geometry::{NSPoint, NSRect, NSSize};
+#[cfg(all(feature = "NSInvocation", feature = "std", target_vendor = "apple"))]
+pub use self::intercept::intercept;
 #[cfg(feature = "NSMapTable")]
 pub use self::ns_consumed::NSFreeMapTable;
+#[cfg(all(
+    feature = "NSNotification",
+    feature = "NSOperation",
+    feature = "NSString",
+    feature = "block2"
+))]
+pub use self::notification::NSNotificationObserver;
 #[cfg(feature = "NSRange")]
 pub use self::range::NSRange;
 #[cfg(feature = "NSThread")]
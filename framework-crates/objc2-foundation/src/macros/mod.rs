@@ -1,2 +1,6 @@
+#[cfg(feature = "NSArray")]
+mod ns_array;
+#[cfg(all(feature = "NSDictionary", feature = "NSObject"))]
+mod ns_dictionary;
 #[cfg(feature = "NSString")]
 mod ns_string;
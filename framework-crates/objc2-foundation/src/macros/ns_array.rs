@@ -0,0 +1,44 @@
+/// Create a [`NSArray`] containing the given objects.
+///
+/// This is similar to [`vec!`], and is equivalent to the [container
+/// literals] `@[a, b, c]` syntax in Objective-C.
+///
+/// The elements can be anything that dereferences to an object, such as
+/// `&T` or `Retained<T>`, and will be retained by the array. The element type
+/// is inferred from the context, or from the first element if the context
+/// doesn't specify it; so if the elements are of different, but related
+/// types (e.g. `NSString` and `NSMutableString`), you may need to annotate
+/// the desired type.
+///
+/// This expands to a call to [`NSArray::from_slice`].
+///
+/// [`NSArray`]: crate::NSArray
+/// [`NSArray::from_slice`]: crate::NSArray::from_slice
+/// [container literals]: https://clang.llvm.org/docs/ObjectiveCLiterals.html#container-literals
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2_foundation::{ns_array, ns_string, NSArray, NSMutableString, NSString};
+///
+/// let array = ns_array![ns_string!("a"), ns_string!("b"), ns_string!("c")];
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(&*array.objectAtIndex(1), ns_string!("b"));
+///
+/// // Annotate the type when using subclasses.
+/// let mutable = NSMutableString::from_str("d");
+/// let array: objc2::rc::Retained<NSArray<NSString>> = ns_array![mutable, ns_string!("e")];
+/// assert_eq!(array.len(), 2);
+/// ```
+// For auto_doc_cfg
+#[cfg(feature = "NSArray")]
+#[macro_export]
+macro_rules! ns_array {
+    () => {
+        $crate::NSArray::from_slice(&[])
+    };
+    ($($obj:expr),+ $(,)?) => {
+        $crate::NSArray::from_slice(&[$(&*$obj),+])
+    };
+}
@@ -0,0 +1,41 @@
+/// Create a [`NSDictionary`] containing the given keys and objects.
+///
+/// This is equivalent to the [container literals] `@{key: value}` syntax in
+/// Objective-C.
+///
+/// Keys and objects can be anything that dereferences to an object, such as
+/// `&T` or `Retained<T>`. The keys are copied, and the objects are retained
+/// by the dictionary. Like with [`ns_array!`], you may need to annotate the
+/// type if the keys or objects are of different, but related types.
+///
+/// This expands to a call to [`NSDictionary::from_slices`].
+///
+/// [`NSDictionary`]: crate::NSDictionary
+/// [`NSDictionary::from_slices`]: crate::NSDictionary::from_slices
+/// [`ns_array!`]: crate::ns_array
+/// [container literals]: https://clang.llvm.org/docs/ObjectiveCLiterals.html#container-literals
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2_foundation::{ns_dictionary, ns_string, NSNumber};
+///
+/// let dict = ns_dictionary! {
+///     ns_string!("one") => NSNumber::new_i32(1),
+///     ns_string!("two") => NSNumber::new_i32(2),
+/// };
+/// assert_eq!(dict.len(), 2);
+/// assert_eq!(dict.objectForKey(ns_string!("two")).unwrap().as_i32(), 2);
+/// ```
+// For auto_doc_cfg
+#[cfg(all(feature = "NSDictionary", feature = "NSObject"))]
+#[macro_export]
+macro_rules! ns_dictionary {
+    () => {
+        $crate::NSDictionary::from_slices(&[], &[])
+    };
+    ($($key:expr => $obj:expr),+ $(,)?) => {
+        $crate::NSDictionary::from_slices(&[$(&*$key),+], &[$(&*$obj),+])
+    };
+}
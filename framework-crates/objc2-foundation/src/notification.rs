@@ -0,0 +1,106 @@
+use core::fmt;
+use core::ptr::NonNull;
+
+use block2::{DynBlock, RcBlock};
+use objc2::msg_send;
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, NSObjectProtocol, ProtocolObject};
+
+use crate::{NSNotification, NSNotificationCenter, NSNotificationName, NSOperationQueue};
+
+impl NSNotificationCenter {
+    /// Add an observer that calls the given closure for each matching
+    /// notification.
+    ///
+    /// Only notifications with the given `name` and sent by the given
+    /// `object` are delivered; passing `None` for either matches any name or
+    /// sender.
+    ///
+    /// If `queue` is `None`, the closure is called synchronously on the
+    /// thread that posted the notification, otherwise it is added as an
+    /// operation to the given queue. Since notifications can be posted from
+    /// any thread, the closure must be `Send + Sync`.
+    ///
+    /// The closure is kept alive by the notification center until the
+    /// returned [`NSNotificationObserver`] is dropped, at which point the
+    /// observer is removed, and the closure is released.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSNotificationCenter};
+    ///
+    /// let center = NSNotificationCenter::defaultCenter();
+    /// let name = ns_string!("MyNotification");
+    /// let observer = center.add_observer(Some(name), None, None, |notification| {
+    ///     println!("received {:?}", notification.name());
+    /// });
+    ///
+    /// // ...
+    ///
+    /// // Stop observing.
+    /// drop(observer);
+    /// ```
+    #[doc(alias = "addObserverForName:object:queue:usingBlock:")]
+    pub fn add_observer(
+        &self,
+        name: Option<&NSNotificationName>,
+        object: Option<&AnyObject>,
+        queue: Option<&NSOperationQueue>,
+        closure: impl Fn(&NSNotification) + Send + Sync + 'static,
+    ) -> NSNotificationObserver {
+        let block = RcBlock::new(move |notification: NonNull<NSNotification>| {
+            // SAFETY: The notification is valid for the duration of the
+            // call.
+            closure(unsafe { notification.as_ref() });
+        });
+        let block: &DynBlock<dyn Fn(NonNull<NSNotification>) + 'static> = &block;
+
+        // SAFETY: The block has the correct signature, and the closure is
+        // thread-safe, so it is fine for the block to be called from any
+        // thread. `object` is only used for identity comparisons.
+        let token: Retained<ProtocolObject<dyn NSObjectProtocol>> = unsafe {
+            msg_send![
+                self,
+                addObserverForName: name,
+                object: object,
+                queue: queue,
+                usingBlock: block,
+            ]
+        };
+
+        NSNotificationObserver {
+            center: self.retain(),
+            token,
+        }
+    }
+}
+
+/// An observer added with [`NSNotificationCenter::add_observer`].
+///
+/// The observer is removed from the notification center when this is
+/// dropped, after which the closure is no longer called.
+#[must_use = "the observer is removed when this is dropped"]
+pub struct NSNotificationObserver {
+    center: Retained<NSNotificationCenter>,
+    token: Retained<ProtocolObject<dyn NSObjectProtocol>>,
+}
+
+impl Drop for NSNotificationObserver {
+    #[doc(alias = "removeObserver:")]
+    fn drop(&mut self) {
+        // SAFETY: The token was returned from
+        // `addObserverForName:object:queue:usingBlock:` on this center.
+        let _: () = unsafe { msg_send![&self.center, removeObserver: &*self.token] };
+    }
+}
+
+impl fmt::Debug for NSNotificationObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NSNotificationObserver")
+            .field("center", &self.center)
+            .field("token", &self.token)
+            .finish()
+    }
+}
@@ -1,14 +1,22 @@
 #[cfg(feature = "NSObjCRuntime")]
 use core::cmp;
+#[cfg(target_vendor = "apple")]
+use core::ffi::c_char;
 use core::ffi::c_void;
 use core::fmt;
 use core::ops::AddAssign;
 use core::panic::RefUnwindSafe;
 use core::panic::UnwindSafe;
+#[cfg(target_vendor = "apple")]
+use core::ptr::NonNull;
+#[cfg(target_vendor = "apple")]
+use core::slice;
 use core::str;
 
 use objc2::msg_send;
 use objc2::rc::{autoreleasepool_leaking, Allocated, AutoreleasePool, Retained};
+#[cfg(all(feature = "NSObjCRuntime", feature = "NSLocale", feature = "NSRange"))]
+use objc2::runtime::AnyObject;
 use objc2::runtime::__nsstring::{nsstring_len, nsstring_to_str, UTF8_ENCODING};
 use objc2::{AnyThread, Message};
 
@@ -100,6 +108,78 @@ impl NSString {
         unsafe { nsstring_to_str(self, pool) }
     }
 
+    /// Get a borrowed [`str`](`prim@str`) view of this string if it can be
+    /// done efficiently, without allocating.
+    ///
+    /// This is a view directly into the internal storage of the string, and
+    /// is thus only available when the string stores its contents as ASCII
+    /// internally (which is common for immutable strings). Returns [`None`]
+    /// otherwise, in which case you should use [`to_str`][Self::to_str] or
+    /// `to_string` instead.
+    ///
+    /// Note that `NSString` cannot implement `Deref<Target = str>`, since it
+    /// already dereferences to its superclass.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The `NSString` must not be mutated for the lifetime of the returned
+    /// string.
+    ///
+    /// Same as for [`CFString::as_str_unchecked`], this is very difficult to
+    /// ensure in generic contexts.
+    ///
+    /// [`CFString::as_str_unchecked`]: https://docs.rs/objc2-core-foundation/latest/objc2_core_foundation/struct.CFString.html#method.as_str_unchecked
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSString;
+    ///
+    /// let string = NSString::from_str("a somewhat long ASCII string");
+    /// // SAFETY: The string is not mutated while the view is alive.
+    /// if let Some(view) = unsafe { string.as_str_unchecked() } {
+    ///     assert_eq!(view, "a somewhat long ASCII string");
+    /// }
+    /// ```
+    #[cfg(target_vendor = "apple")]
+    #[doc(alias = "CFStringGetCStringPtr")]
+    pub unsafe fn as_str_unchecked(&self) -> Option<&str> {
+        extern "C-unwind" {
+            // `NSString` is toll-free bridged with `CFString`.
+            fn CFStringGetCStringPtr(the_string: &NSString, encoding: u32) -> *const c_char;
+        }
+        // kCFStringEncodingASCII
+        const ENCODING_ASCII: u32 = 0x0600;
+
+        // SAFETY: The string is valid, and the encoding is a valid
+        // `CFStringEncoding`.
+        let bytes = unsafe { CFStringGetCStringPtr(self, ENCODING_ASCII) };
+        NonNull::new(bytes as *mut c_char).map(|bytes| {
+            // The returned string may contain interior NUL bytes, so we can't
+            // use `CStr::from_ptr`. Instead, since the encoding is ASCII, the
+            // number of UTF-16 code units is the same as the number of bytes.
+            let len = self.length();
+
+            // SAFETY: The pointer is valid for as long as the string is not
+            // mutated (which the caller ensures it isn't for the lifetime of
+            // the reference), and the length is correct (see above).
+            let bytes: &[u8] = unsafe { slice::from_raw_parts(bytes.as_ptr().cast(), len) };
+
+            // SAFETY: ASCII is a subset of UTF-8.
+            #[cfg(not(debug_assertions))]
+            unsafe {
+                str::from_utf8_unchecked(bytes)
+            }
+
+            #[cfg(debug_assertions)]
+            {
+                str::from_utf8(bytes).expect("invalid UTF-8 in NSString")
+            }
+        })
+    }
+
     // TODO: Allow usecases where the NUL byte from `UTF8String` is kept?
 
     /// Creates an immutable `NSString` by copying the given string slice.
@@ -154,6 +234,49 @@ impl PartialEq<NSMutableString> for NSString {
     }
 }
 
+/// Comparison helpers.
+#[cfg(feature = "NSObjCRuntime")]
+impl NSString {
+    /// Compare the string with another string, using the given options.
+    ///
+    /// Use e.g. [`NSStringCompareOptions::CaseInsensitiveSearch`] to ignore
+    /// case, or [`NSStringCompareOptions::NumericSearch`] to compare numbers
+    /// in the strings by their numeric value, such that "file2" comes before
+    /// "file10".
+    ///
+    /// This is a non-localized comparison, see
+    /// [`cmp_with_locale`][Self::cmp_with_locale] for comparing strings
+    /// according to a locale's rules.
+    #[doc(alias = "compare:options:")]
+    pub fn cmp_with_options(
+        &self,
+        other: &NSString,
+        options: crate::NSStringCompareOptions,
+    ) -> cmp::Ordering {
+        self.compare_options(other, options).into()
+    }
+
+    /// Compare the string with another string, using the given options and
+    /// the rules of the given locale.
+    ///
+    /// If `locale` is `None`, this is the same as
+    /// [`cmp_with_options`][Self::cmp_with_options].
+    #[doc(alias = "compare:options:range:locale:")]
+    #[cfg(all(feature = "NSLocale", feature = "NSRange"))]
+    pub fn cmp_with_locale(
+        &self,
+        other: &NSString,
+        options: crate::NSStringCompareOptions,
+        locale: Option<&crate::NSLocale>,
+    ) -> cmp::Ordering {
+        let range = crate::NSRange::new(0, self.length());
+        let locale = locale.map(AsRef::<AnyObject>::as_ref);
+        // SAFETY: The range is the full range of the receiver, and the locale
+        // is an `NSLocale` (or `None`), as the method expects.
+        unsafe { self.compare_options_range_locale(other, options, range, locale) }.into()
+    }
+}
+
 #[cfg(feature = "NSObjCRuntime")]
 impl PartialOrd for NSString {
     #[inline]
@@ -198,6 +198,28 @@ fn test_cast() {
     assert_eq!(unsafe { value.get::<i64>() }, 42);
 }
 
+#[test]
+fn test_ns_array_macro() {
+    let array = crate::ns_array![NSNumber::new_i32(1), NSNumber::new_i32(2)];
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.objectAtIndex(0).as_i32(), 1);
+    assert_eq!(array.objectAtIndex(1).as_i32(), 2);
+
+    // References and trailing comma.
+    let obj = NSObject::new();
+    let array = crate::ns_array![&*obj, &obj,];
+    assert_eq!(array.len(), 2);
+    assert!(ptr::eq(&*array.objectAtIndex(0), &*obj));
+
+    // Related types are coerced to the annotated type.
+    let array: Retained<NSArray<NSValue>> =
+        crate::ns_array![NSNumber::new_i32(3), NSValue::new::<i32>(4)];
+    assert_eq!(array.len(), 2);
+
+    let array: Retained<NSArray<NSObject>> = crate::ns_array![];
+    assert!(array.is_empty());
+}
+
 #[test]
 #[cfg(feature = "objc2-core-foundation")]
 #[cfg(not(feature = "gnustep-1-7"))]
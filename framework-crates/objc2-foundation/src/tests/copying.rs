@@ -1,6 +1,8 @@
 #![cfg(feature = "NSString")]
+use alloc::string::ToString;
+
 use objc2::{rc::Retained, runtime::ProtocolObject};
-use objc2_foundation::{NSCopying, NSMutableCopying, NSString};
+use objc2_foundation::{ns_string, NSCopying, NSMutableCopying, NSMutableString, NSString};
 
 #[test]
 fn copy() {
@@ -15,3 +17,22 @@ fn copy_mutable() {
     let protocol_object: &ProtocolObject<dyn NSMutableCopying> = ProtocolObject::from_ref(&*obj);
     let _: Retained<ProtocolObject<dyn NSMutableCopying>> = protocol_object.mutableCopy();
 }
+
+#[test]
+fn copy_counterparts() {
+    let string = NSString::from_str("abc");
+
+    // Copying an immutable string returns `NSString`.
+    let copy: Retained<NSString> = string.copy();
+    assert_eq!(copy, string);
+
+    // Mutable copies return the mutable counterpart.
+    let mutable: Retained<NSMutableString> = string.mutableCopy();
+    mutable.appendString(ns_string!("def"));
+    assert_eq!(mutable.to_string(), "abcdef");
+    assert_eq!(string.to_string(), "abc");
+
+    // And copying the mutable string goes back to the immutable one.
+    let copy: Retained<NSString> = mutable.copy();
+    assert_eq!(copy.to_string(), "abcdef");
+}
@@ -6,7 +6,7 @@ use crate::NSData;
 #[test]
 fn test_bytes() {
     let bytes = [3, 7, 16, 52, 112, 19];
-    let data = NSData::with_bytes(&bytes);
+    let data = NSData::from_bytes(&bytes);
     assert_eq!(data.len(), bytes.len());
     assert_eq!(data.to_vec(), bytes);
 }
@@ -17,6 +17,24 @@ fn test_no_bytes() {
     assert!(Some(data.to_vec()).is_some());
 }
 
+#[test]
+fn test_roundtrip_bytes() {
+    let bytes = [3, 7, 16, 52, 112, 19];
+    let data = NSData::from_bytes(&bytes);
+    assert_eq!(unsafe { data.as_bytes_unchecked() }, bytes);
+    assert_eq!(NSData::from_bytes(&data.to_vec()), data);
+}
+
+#[test]
+fn test_roundtrip_empty() {
+    let data = NSData::from_bytes(&[]);
+    assert!(data.is_empty());
+    // `bytes` may return NULL here, make sure we handle that.
+    assert_eq!(unsafe { data.as_bytes_unchecked() }, &[] as &[u8]);
+    assert_eq!(data.to_vec(), alloc::vec::Vec::<u8>::new());
+    assert_eq!(NSData::from_bytes(&data.to_vec()), data);
+}
+
 #[cfg(feature = "block2")]
 #[test]
 fn test_from_vec() {
@@ -30,7 +48,7 @@ fn test_from_vec() {
 #[test]
 fn test_debug() {
     let bytes = [3, 7, 16, 52, 112, 19];
-    let data = NSData::with_bytes(&bytes);
+    let data = NSData::from_bytes(&bytes);
     assert_eq!(format!("{data:?}"), "[3, 7, 16, 52, 112, 19]");
 }
 
@@ -15,6 +15,22 @@ use objc2::{
 
 use crate::{ns_string, NSDictionary, NSObject, NSString, NSUInteger};
 
+#[test]
+fn test_ns_dictionary_macro() {
+    let obj = NSObject::new();
+    let dict = crate::ns_dictionary! {
+        ns_string!("a") => obj.clone(),
+        NSString::from_str("b") => NSObject::new(),
+    };
+    assert_eq!(dict.len(), 2);
+    assert_eq!(dict.objectForKey(ns_string!("a")).unwrap(), obj);
+    assert!(dict.objectForKey(ns_string!("b")).is_some());
+    assert!(dict.objectForKey(ns_string!("c")).is_none());
+
+    let dict: Retained<NSDictionary<NSString, NSObject>> = crate::ns_dictionary! {};
+    assert!(dict.is_empty());
+}
+
 fn sample_dict(key: &str) -> Retained<NSDictionary<NSString, NSObject>> {
     let string = NSString::from_str(key);
     let obj = NSObject::new();
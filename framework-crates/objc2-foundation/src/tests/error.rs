@@ -1,6 +1,8 @@
 #![cfg(feature = "NSError")]
 #![cfg(feature = "NSString")]
 use alloc::format;
+#[cfg(feature = "NSDictionary")]
+use objc2::{runtime::AnyObject, AnyThread};
 
 use crate::{ns_string, NSCocoaErrorDomain, NSError};
 
@@ -27,3 +29,32 @@ fn custom_domain() {
     };
     assert_eq!(format!("{error}"), expected);
 }
+
+#[test]
+#[cfg(feature = "NSDictionary")]
+fn user_info() {
+    use crate::{NSDictionary, NSLocalizedFailureReasonErrorKey};
+
+    let reason = ns_string!("the disk is full");
+    let value: &AnyObject = reason;
+    let user_info =
+        NSDictionary::from_slices(&[unsafe { NSLocalizedFailureReasonErrorKey }], &[value]);
+    let error = unsafe {
+        NSError::initWithDomain_code_userInfo(
+            NSError::alloc(),
+            ns_string!("MyDomain"),
+            1,
+            Some(&user_info),
+        )
+    };
+
+    assert_eq!(error.userInfo().count(), 1);
+    assert_eq!(error.localized_failure_reason().as_deref(), Some(reason));
+    // Missing keys.
+    assert_eq!(error.recovery_suggestion(), None);
+
+    let error = NSError::new(2, ns_string!("MyDomain"));
+    assert_eq!(error.userInfo().count(), 0);
+    assert_eq!(error.localized_failure_reason(), None);
+    assert_eq!(error.recovery_suggestion(), None);
+}
@@ -0,0 +1,70 @@
+#![cfg(all(feature = "NSInvocation", feature = "std", target_vendor = "apple"))]
+use core::ptr::NonNull;
+use core::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
+
+use objc2::rc::Retained;
+use objc2::runtime::NSObject;
+use objc2::{define_class, msg_send, sel, ClassType};
+
+use crate::intercept;
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "TestInterceptAdder"]
+    struct Adder;
+
+    impl Adder {
+        #[unsafe(method(addOne:))]
+        fn add_one(&self, x: i32) -> i32 {
+            x + 1
+        }
+
+        #[unsafe(method(untouched))]
+        fn untouched(&self) -> i32 {
+            7
+        }
+    }
+);
+
+#[test]
+fn intercept_method() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    static SEEN: AtomicI32 = AtomicI32::new(0);
+
+    unsafe {
+        intercept(Adder::class(), sel!(addOne:), |invocation| {
+            assert_eq!(invocation.selector(), sel!(addOne:));
+            let mut arg: i32 = 0;
+            // SAFETY: The argument at index 2 (after self and _cmd) is an
+            // `i32`.
+            unsafe { invocation.getArgument_atIndex(NonNull::from(&mut arg).cast(), 2) };
+            SEEN.store(arg, Ordering::SeqCst);
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        })
+    };
+
+    let obj: Retained<Adder> = unsafe { msg_send![Adder::class(), new] };
+
+    // The original implementation is still called, and its return value is
+    // passed through.
+    let res: i32 = unsafe { msg_send![&obj, addOne: 41i32] };
+    assert_eq!(res, 42);
+    assert_eq!(SEEN.load(Ordering::SeqCst), 41);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    let res: i32 = unsafe { msg_send![&obj, addOne: -5i32] };
+    assert_eq!(res, -4);
+    assert_eq!(SEEN.load(Ordering::SeqCst), -5);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    // Other methods are unaffected.
+    let res: i32 = unsafe { msg_send![&obj, untouched] };
+    assert_eq!(res, 7);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+#[should_panic = "-[TestInterceptAdder doesNotExist] does not exist"]
+fn intercept_missing() {
+    unsafe { intercept(Adder::class(), sel!(doesNotExist), |_| {}) };
+}
@@ -7,12 +7,14 @@ mod decimal_number;
 mod dictionary;
 mod error;
 mod exception;
+mod intercept;
 mod lock;
 mod mutable_array;
 mod mutable_data;
 mod mutable_dictionary;
 mod mutable_set;
 mod mutable_string;
+mod notification;
 mod number;
 mod process_info;
 mod proxy;
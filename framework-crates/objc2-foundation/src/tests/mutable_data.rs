@@ -4,14 +4,14 @@ use crate::{NSData, NSMutableData};
 
 #[test]
 fn test_bytes_mut() {
-    let data = NSMutableData::with_bytes(&[7, 16]);
+    let data = NSMutableData::from_bytes(&[7, 16]);
     unsafe { data.as_mut_bytes_unchecked()[0] = 3 };
     assert_eq!(data.to_vec(), [3, 16]);
 }
 
 #[test]
 fn test_set_len() {
-    let data = NSMutableData::with_bytes(&[7, 16]);
+    let data = NSMutableData::from_bytes(&[7, 16]);
     data.setLength(4);
     assert_eq!(data.len(), 4);
     assert_eq!(data.to_vec(), [7, 16, 0, 0]);
@@ -23,7 +23,7 @@ fn test_set_len() {
 
 #[test]
 fn test_append() {
-    let data = NSMutableData::with_bytes(&[7, 16]);
+    let data = NSMutableData::from_bytes(&[7, 16]);
     data.extend_from_slice(&[3, 52]);
     assert_eq!(data.len(), 4);
     assert_eq!(data.to_vec(), [7, 16, 3, 52]);
@@ -32,7 +32,7 @@ fn test_append() {
 #[test]
 #[cfg(feature = "NSRange")]
 fn test_replace() {
-    let data = NSMutableData::with_bytes(&[7, 16]);
+    let data = NSMutableData::from_bytes(&[7, 16]);
     data.replace_range(0..0, &[3]);
     assert_eq!(data.to_vec(), [3, 7, 16]);
 
@@ -48,7 +48,7 @@ fn test_replace() {
 
 #[test]
 fn test_from_data() {
-    let data = NSData::with_bytes(&[1, 2]);
+    let data = NSData::from_bytes(&[1, 2]);
     let mut_data = NSMutableData::dataWithData(&data);
     assert_eq!(&*data, &**mut_data);
 }
@@ -65,9 +65,9 @@ fn test_with_capacity() {
 
 #[test]
 fn test_extend() {
-    let mut data = NSMutableData::with_bytes(&[1, 2]);
+    let mut data = NSMutableData::from_bytes(&[1, 2]);
     data.extend(3..=5);
     assert_eq!(data.to_vec(), &[1, 2, 3, 4, 5]);
-    (&data).extend(&*NSData::with_bytes(&[6, 7]));
+    (&data).extend(&*NSData::from_bytes(&[6, 7]));
     assert_eq!(data.to_vec(), &[1, 2, 3, 4, 5, 6, 7]);
 }
@@ -0,0 +1,73 @@
+#![cfg(all(
+    feature = "NSNotification",
+    feature = "NSOperation",
+    feature = "NSString",
+    feature = "NSDictionary",
+    feature = "NSValue",
+    feature = "block2"
+))]
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
+
+use objc2::rc::autoreleasepool;
+
+use crate::{ns_string, NSDictionary, NSNotificationCenter, NSNumber, NSString};
+
+#[test]
+fn observe_and_remove() {
+    let center = NSNotificationCenter::defaultCenter();
+    let name = ns_string!("TestObjc2FoundationNotification");
+    let key = ns_string!("value");
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let last_value = Arc::new(AtomicI32::new(0));
+    let observer = center.add_observer(Some(name), None, None, {
+        let count = count.clone();
+        let last_value = last_value.clone();
+        move |notification| {
+            assert_eq!(&*notification.name(), name);
+            let user_info = notification.userInfo().unwrap();
+            // SAFETY: The user info dictionary is created below.
+            let user_info = unsafe { user_info.cast_unchecked::<NSString, NSNumber>() };
+            last_value.store(
+                user_info.objectForKey(key).unwrap().as_i32(),
+                Ordering::SeqCst,
+            );
+            count.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    let post = |value: i32| {
+        let user_info = NSDictionary::from_slices(&[key], &[&*NSNumber::new_i32(value)]);
+        // SAFETY: The user info is a valid dictionary.
+        unsafe {
+            center.postNotificationName_object_userInfo(
+                name,
+                None,
+                Some(user_info.cast_unchecked()),
+            )
+        };
+    };
+
+    // Observers without a queue are called synchronously.
+    post(42);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert_eq!(last_value.load(Ordering::SeqCst), 42);
+
+    // Notifications with other names are not delivered.
+    unsafe { center.postNotificationName_object(ns_string!("SomethingElse"), None) };
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    post(3);
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+    assert_eq!(last_value.load(Ordering::SeqCst), 3);
+
+    // The closure is released once the observer is removed.
+    assert_eq!(Arc::strong_count(&count), 2);
+    autoreleasepool(|_| drop(observer));
+    assert_eq!(Arc::strong_count(&count), 1);
+
+    post(7);
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+    assert_eq!(last_value.load(Ordering::SeqCst), 3);
+}
@@ -18,6 +18,71 @@ fn test_equality() {
     assert_ne!(s1, s3);
 }
 
+#[test]
+#[cfg(feature = "NSObjCRuntime")]
+fn test_cmp_with_options() {
+    use core::cmp::Ordering;
+
+    use crate::NSStringCompareOptions;
+
+    let lower = ns_string!("hello");
+    let upper = ns_string!("HELLO");
+    let empty = NSStringCompareOptions::empty();
+    assert_ne!(lower.cmp_with_options(upper, empty), Ordering::Equal);
+    assert_eq!(
+        lower.cmp_with_options(upper, NSStringCompareOptions::CaseInsensitiveSearch),
+        Ordering::Equal,
+    );
+    assert_eq!(
+        ns_string!("a").cmp_with_options(
+            ns_string!("B"),
+            NSStringCompareOptions::CaseInsensitiveSearch
+        ),
+        Ordering::Less,
+    );
+
+    // Lexically, "file10" sorts before "file2", but not numerically.
+    let file2 = ns_string!("file2");
+    let file10 = ns_string!("file10");
+    assert_eq!(file2.cmp_with_options(file10, empty), Ordering::Greater);
+    assert_eq!(
+        file2.cmp_with_options(file10, NSStringCompareOptions::NumericSearch),
+        Ordering::Less,
+    );
+    assert_eq!(
+        ns_string!("File10").cmp_with_options(
+            ns_string!("file10"),
+            NSStringCompareOptions::NumericSearch | NSStringCompareOptions::CaseInsensitiveSearch,
+        ),
+        Ordering::Equal,
+    );
+}
+
+#[test]
+#[cfg(all(feature = "NSObjCRuntime", feature = "NSLocale", feature = "NSRange"))]
+fn test_cmp_with_locale() {
+    use core::cmp::Ordering;
+
+    use crate::{NSLocale, NSStringCompareOptions};
+
+    let file2 = ns_string!("file2");
+    let file10 = ns_string!("file10");
+    let options = NSStringCompareOptions::NumericSearch;
+
+    // Without a locale, this is the same as `cmp_with_options`.
+    assert_eq!(file2.cmp_with_locale(file10, options, None), Ordering::Less);
+
+    let locale = unsafe { NSLocale::currentLocale() };
+    assert_eq!(
+        file2.cmp_with_locale(file10, options, Some(&locale)),
+        Ordering::Less
+    );
+    assert_eq!(
+        ns_string!("a").cmp_with_locale(ns_string!("a"), options, Some(&locale)),
+        Ordering::Equal
+    );
+}
+
 #[test]
 fn display_debug() {
     let s = NSString::from_str("xyz\"123");
@@ -77,6 +142,29 @@ fn test_interior_nul() {
     assert_eq!(s.to_string(), expected);
 }
 
+#[test]
+#[cfg(target_vendor = "apple")]
+fn test_as_str_unchecked() {
+    // Long enough that it won't be stored as a tagged pointer.
+    let expected = "a string that is stored as ASCII";
+    let s = NSString::from_str(expected);
+    // The storage is an implementation detail, so we can't assert that this
+    // is `Some`, but if it is, it must match.
+    if let Some(view) = unsafe { s.as_str_unchecked() } {
+        assert_eq!(view.len(), expected.len());
+        assert_eq!(view.as_bytes(), expected.as_bytes());
+    }
+
+    let s = NSString::from_str("Hello\0World, with interior NUL");
+    if let Some(view) = unsafe { s.as_str_unchecked() } {
+        assert_eq!(view, "Hello\0World, with interior NUL");
+    }
+
+    // Non-ASCII strings can't be viewed as ASCII.
+    let s = NSString::from_str("ประเทศไทย中华Việt Nam");
+    assert_eq!(unsafe { s.as_str_unchecked() }, None);
+}
+
 #[test]
 #[cfg(feature = "NSObject")]
 fn test_copy() {
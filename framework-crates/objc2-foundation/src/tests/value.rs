@@ -96,6 +96,41 @@ fn nsrect() {
     assert_eq!(unsafe { val.get::<NSRect>() }, rect);
 }
 
+#[test]
+fn custom_struct() {
+    use objc2::encode::{Encode, Encoding};
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    unsafe impl Encode for Point {
+        const ENCODING: Encoding = Encoding::Struct("Point", &[f64::ENCODING, f64::ENCODING]);
+    }
+
+    let point = Point { x: 1.0, y: 2.0 };
+    let val = NSValue::new(point);
+    assert!(val.contains_encoding::<Point>());
+    // NSValue -getValue is broken on GNUStep for some types
+    #[cfg(not(feature = "gnustep-1-7"))]
+    assert_eq!(unsafe { val.try_get::<Point>() }, Some(point));
+
+    // Mismatched types are rejected instead of read.
+    assert_eq!(unsafe { val.try_get::<f64>() }, None);
+    assert_eq!(unsafe { val.try_get::<[f64; 2]>() }, None);
+}
+
+#[test]
+fn try_get_primitive() {
+    let val = NSValue::new(13u32);
+    assert_eq!(unsafe { val.try_get::<u32>() }, Some(13));
+    assert_eq!(unsafe { val.try_get::<i32>() }, None);
+    assert_eq!(unsafe { val.try_get::<u64>() }, None);
+}
+
 #[test]
 fn store_str() {
     let s = "abc";
@@ -35,6 +35,8 @@ impl NSValue {
     /// ```
     ///
     /// [`NSPoint`]: crate::NSPoint
+    #[doc(alias = "valueWithBytes:objCType:")]
+    #[doc(alias = "initWithBytes:objCType:")]
     pub fn new<T: 'static + Copy + Encode>(value: T) -> Retained<Self> {
         let bytes: NonNull<T> = NonNull::from(&value);
         let encoding = CString::new(T::ENCODING.to_string()).unwrap();
@@ -99,6 +101,60 @@ impl NSValue {
         unsafe { value.assume_init() }
     }
 
+    /// Retrieve the data contained in the `NSValue`, if it has the same
+    /// encoding as `T`.
+    ///
+    /// Unlike [`get`], this returns `None` instead of reading garbage if the
+    /// stored encoding does not match [`T::ENCODING`][Encode::ENCODING].
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Any safety invariants that `T` has must be upheld by the stored value.
+    ///
+    /// This is trivially true for plain C structs and primitives, but not
+    /// for types like `NonNull<T>` that share their encoding with a type
+    /// with fewer invariants, see [`get`] for details.
+    ///
+    /// [`get`]: Self::get
+    ///
+    ///
+    /// # Examples
+    ///
+    /// Store a struct in `NSValue`, and retrieve it again afterwards.
+    ///
+    /// ```
+    /// use objc2::encode::{Encode, Encoding};
+    /// use objc2_foundation::NSValue;
+    ///
+    /// #[repr(C)]
+    /// #[derive(Clone, Copy, Debug, PartialEq)]
+    /// struct Point {
+    ///     x: f64,
+    ///     y: f64,
+    /// }
+    ///
+    /// unsafe impl Encode for Point {
+    ///     const ENCODING: Encoding = Encoding::Struct("Point", &[f64::ENCODING, f64::ENCODING]);
+    /// }
+    ///
+    /// let point = Point { x: 1.0, y: 2.0 };
+    /// let val = NSValue::new(point);
+    /// // SAFETY: `Point` is a plain C struct without any invariants.
+    /// assert_eq!(unsafe { val.try_get::<Point>() }, Some(point));
+    /// // SAFETY: `u32` does not have any invariants.
+    /// assert_eq!(unsafe { val.try_get::<u32>() }, None);
+    /// ```
+    pub unsafe fn try_get<T: 'static + Copy + Encode>(&self) -> Option<T> {
+        if self.contains_encoding::<T>() {
+            // SAFETY: Just checked that the encoding matches, and the caller
+            // upholds any further invariants.
+            Some(unsafe { self.get::<T>() })
+        } else {
+            None
+        }
+    }
+
     #[cfg(feature = "NSRange")]
     pub fn get_range(&self) -> Option<crate::NSRange> {
         if self.contains_encoding::<crate::NSRange>() {
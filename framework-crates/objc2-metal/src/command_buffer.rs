@@ -0,0 +1,29 @@
+use objc2::rc::Retained;
+use objc2_foundation::NSError;
+
+use crate::{MTLCommandBuffer, MTLCommandBufferStatus};
+
+/// Helpers for checking the outcome of a command buffer.
+pub trait MTLCommandBufferExt: MTLCommandBuffer + objc2::Message {
+    /// The outcome of executing the command buffer, or `None` if it hasn't
+    /// finished executing yet.
+    ///
+    /// This combines [`status`][MTLCommandBuffer::status] and
+    /// [`error`][MTLCommandBuffer::error]; it returns `None` while the
+    /// status is not yet [`Completed`][MTLCommandBufferStatus::Completed] or
+    /// [`Error`][MTLCommandBufferStatus::Error], and otherwise `Err` if the
+    /// GPU reported an error.
+    fn result(&self) -> Option<Result<(), Retained<NSError>>> {
+        match self.status() {
+            MTLCommandBufferStatus::Completed | MTLCommandBufferStatus::Error => {
+                Some(match self.error() {
+                    Some(error) => Err(error),
+                    None => Ok(()),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<P: MTLCommandBuffer + objc2::Message> MTLCommandBufferExt for P {}
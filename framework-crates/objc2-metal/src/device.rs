@@ -1,7 +1,11 @@
 use crate::MTLDevice;
+#[cfg(feature = "MTLRenderPipeline")]
+use crate::{MTLRenderPipelineDescriptor, MTLRenderPipelineState};
 use objc2::rc::Retained;
 use objc2::runtime::ProtocolObject;
 use objc2_foundation::NSArray;
+#[cfg(feature = "MTLRenderPipeline")]
+use objc2_foundation::NSError;
 
 /// Returns all Metal devices in the system.
 ///
@@ -45,3 +49,36 @@ pub extern "C-unwind" fn MTLCopyAllDevices() -> Retained<NSArray<ProtocolObject<
         NSArray::from_slice(slice)
     }
 }
+
+/// Helpers for creating objects on a device.
+#[cfg(feature = "MTLRenderPipeline")]
+pub trait MTLDeviceExt: MTLDevice + objc2::Message {
+    /// Create a render pipeline state from the given descriptor, compiling
+    /// it synchronously.
+    ///
+    /// The descriptor can be created with
+    /// [`MTLRenderPipelineDescriptorBuilder`].
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns the error reported by Metal if the pipeline could not be
+    /// created, for example if the descriptor doesn't have a vertex function,
+    /// or if the functions' inputs and outputs don't match.
+    ///
+    /// If Metal fails without providing an error, an error in the
+    /// `"__objc2.missingError"` domain is returned instead.
+    ///
+    /// [`MTLRenderPipelineDescriptorBuilder`]: crate::MTLRenderPipelineDescriptorBuilder
+    #[inline]
+    #[doc(alias = "newRenderPipelineStateWithDescriptor:error:")]
+    fn new_render_pipeline(
+        &self,
+        descriptor: &MTLRenderPipelineDescriptor,
+    ) -> Result<Retained<ProtocolObject<dyn MTLRenderPipelineState>>, Retained<NSError>> {
+        self.newRenderPipelineStateWithDescriptor_error(descriptor)
+    }
+}
+
+#[cfg(feature = "MTLRenderPipeline")]
+impl<P: MTLDevice + objc2::Message> MTLDeviceExt for P {}
@@ -40,6 +40,8 @@ extern crate std;
 mod acceleration_structure_types;
 #[cfg(feature = "MTLCaptureManager")]
 mod capture;
+#[cfg(feature = "MTLCommandBuffer")]
+mod command_buffer;
 #[cfg(feature = "MTLCounters")]
 mod counters;
 #[cfg(feature = "MTLDevice")]
@@ -49,6 +51,8 @@ mod generated;
 mod private;
 #[cfg(feature = "MTLRasterizationRate")]
 mod rasterization_rate;
+#[cfg(feature = "MTLRenderPipeline")]
+mod render_pipeline;
 #[cfg(feature = "MTLResource")]
 mod resource;
 mod slice;
@@ -59,6 +63,8 @@ mod types;
 
 #[cfg(feature = "MTLAccelerationStructureTypes")]
 pub use self::acceleration_structure_types::MTLPackedFloat3;
+#[cfg(feature = "MTLCommandBuffer")]
+pub use self::command_buffer::MTLCommandBufferExt;
 #[cfg(feature = "MTLCounters")]
 pub use self::counters::*;
 #[cfg(feature = "MTLDevice")]
@@ -67,6 +73,8 @@ pub use self::device::*;
 pub use self::generated::*;
 #[cfg(feature = "unstable-private")]
 pub use self::private::MTLDevicePrivate;
+#[cfg(feature = "MTLRenderPipeline")]
+pub use self::render_pipeline::MTLRenderPipelineDescriptorBuilder;
 #[cfg(feature = "MTLResource")]
 pub use self::resource::*;
 #[cfg(all(feature = "MTLRenderCommandEncoder", feature = "MTLCommandEncoder"))]
@@ -0,0 +1,171 @@
+use objc2::rc::Retained;
+#[cfg(feature = "MTLLibrary")]
+use objc2::runtime::ProtocolObject;
+use objc2_foundation::NSString;
+
+#[cfg(feature = "MTLLibrary")]
+use crate::MTLFunction;
+#[cfg(feature = "MTLPixelFormat")]
+use crate::MTLPixelFormat;
+use crate::MTLRenderPipelineDescriptor;
+
+/// The number of color attachments that a render pipeline supports.
+///
+/// This is the same on all devices, see the [Metal feature set tables].
+///
+/// [Metal feature set tables]: https://developer.apple.com/metal/Metal-Feature-Set-Tables.pdf
+#[cfg(feature = "MTLPixelFormat")]
+const MAX_COLOR_ATTACHMENTS: usize = 8;
+
+/// A builder for a [`MTLRenderPipelineDescriptor`].
+///
+/// This wraps a descriptor, and provides typed setters for the most commonly
+/// used fields. For anything else, configure the descriptor returned from
+/// [`build`][Self::build] directly.
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use objc2_foundation::ns_string;
+/// use objc2_metal::{
+///     MTLCreateSystemDefaultDevice, MTLDevice, MTLDeviceExt, MTLLibrary, MTLPixelFormat,
+///     MTLRenderPipelineDescriptorBuilder,
+/// };
+///
+/// # #[link(name = "CoreGraphics", kind = "framework")]
+/// # extern "C" {}
+/// let device = MTLCreateSystemDefaultDevice().expect("no Metal device");
+/// let library = device
+///     .newLibraryWithSource_options_error(ns_string!("..."), None)
+///     .expect("failed compiling shaders");
+/// let vertex = library.newFunctionWithName(ns_string!("vertex_main")).unwrap();
+/// let fragment = library.newFunctionWithName(ns_string!("fragment_main")).unwrap();
+///
+/// let descriptor = MTLRenderPipelineDescriptorBuilder::new()
+///     .vertex_function(&vertex)
+///     .fragment_function(&fragment)
+///     .color_attachment_format(0, MTLPixelFormat::BGRA8Unorm)
+///     .build();
+///
+/// let pipeline = device.new_render_pipeline(&descriptor).unwrap();
+/// ```
+#[derive(Debug)]
+#[must_use = "a builder does nothing unless `build` is called"]
+pub struct MTLRenderPipelineDescriptorBuilder {
+    descriptor: Retained<MTLRenderPipelineDescriptor>,
+}
+
+impl MTLRenderPipelineDescriptorBuilder {
+    /// Create a new builder, starting with a default descriptor.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            descriptor: MTLRenderPipelineDescriptor::new(),
+        }
+    }
+
+    /// Set the label of the pipeline, used when debugging.
+    #[inline]
+    #[doc(alias = "setLabel:")]
+    pub fn label(self, label: &NSString) -> Self {
+        self.descriptor.setLabel(Some(label));
+        self
+    }
+
+    /// Set the function that processes vertices.
+    ///
+    /// This is required, creating a pipeline without a vertex function
+    /// fails.
+    #[cfg(feature = "MTLLibrary")]
+    #[inline]
+    #[doc(alias = "setVertexFunction:")]
+    pub fn vertex_function(self, function: &ProtocolObject<dyn MTLFunction>) -> Self {
+        self.descriptor.setVertexFunction(Some(function));
+        self
+    }
+
+    /// Set the function that processes fragments.
+    ///
+    /// This can be omitted if you're only writing to the depth or stencil
+    /// attachments.
+    #[cfg(feature = "MTLLibrary")]
+    #[inline]
+    #[doc(alias = "setFragmentFunction:")]
+    pub fn fragment_function(self, function: &ProtocolObject<dyn MTLFunction>) -> Self {
+        self.descriptor.setFragmentFunction(Some(function));
+        self
+    }
+
+    /// Set the pixel format of the color attachment at the given index.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than 8, the maximum number of color
+    /// attachments.
+    #[cfg(feature = "MTLPixelFormat")]
+    #[doc(alias = "colorAttachments")]
+    #[doc(alias = "setPixelFormat:")]
+    pub fn color_attachment_format(self, index: usize, format: MTLPixelFormat) -> Self {
+        assert!(
+            index < MAX_COLOR_ATTACHMENTS,
+            "color attachment index {index} out of bounds"
+        );
+        // SAFETY: Checked that the index is in bounds above.
+        let attachment = unsafe {
+            self.descriptor
+                .colorAttachments()
+                .objectAtIndexedSubscript(index)
+        };
+        attachment.setPixelFormat(format);
+        self
+    }
+
+    /// Set the pixel format of the depth attachment.
+    #[cfg(feature = "MTLPixelFormat")]
+    #[inline]
+    #[doc(alias = "setDepthAttachmentPixelFormat:")]
+    pub fn depth_attachment_format(self, format: MTLPixelFormat) -> Self {
+        self.descriptor.setDepthAttachmentPixelFormat(format);
+        self
+    }
+
+    /// Set the pixel format of the stencil attachment.
+    #[cfg(feature = "MTLPixelFormat")]
+    #[inline]
+    #[doc(alias = "setStencilAttachmentPixelFormat:")]
+    pub fn stencil_attachment_format(self, format: MTLPixelFormat) -> Self {
+        self.descriptor.setStencilAttachmentPixelFormat(format);
+        self
+    }
+
+    /// Set the number of samples per pixel when rasterizing.
+    ///
+    /// Check that the device supports this count with
+    /// `MTLDevice::supportsTextureSampleCount` first.
+    #[inline]
+    #[doc(alias = "setRasterSampleCount:")]
+    pub fn raster_sample_count(self, count: usize) -> Self {
+        self.descriptor.setRasterSampleCount(count);
+        self
+    }
+
+    /// Get the configured descriptor.
+    ///
+    /// Pass this to [`MTLDeviceExt::new_render_pipeline`] to create the
+    /// pipeline.
+    ///
+    /// [`MTLDeviceExt::new_render_pipeline`]: crate::MTLDeviceExt::new_render_pipeline
+    #[inline]
+    pub fn build(self) -> Retained<MTLRenderPipelineDescriptor> {
+        self.descriptor
+    }
+}
+
+impl Default for MTLRenderPipelineDescriptorBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
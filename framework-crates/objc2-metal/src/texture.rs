@@ -1,4 +1,11 @@
 #![allow(non_upper_case_globals)]
+#[cfg(all(feature = "alloc", feature = "MTLResource", feature = "MTLTypes"))]
+use alloc::vec::Vec;
+#[cfg(all(feature = "alloc", feature = "MTLResource", feature = "MTLTypes"))]
+use core::ptr::NonNull;
+
+#[cfg(all(feature = "alloc", feature = "MTLResource", feature = "MTLTypes"))]
+use crate::{MTLRegion, MTLResource, MTLStorageMode, MTLTexture};
 use crate::{MTLTextureSwizzle, MTLTextureSwizzleChannels};
 
 pub const MTLTextureSwizzleChannelsDefault: MTLTextureSwizzleChannels = MTLTextureSwizzleChannels {
@@ -7,3 +14,132 @@ pub const MTLTextureSwizzleChannelsDefault: MTLTextureSwizzleChannels = MTLTextu
     blue: MTLTextureSwizzle::Blue,
     alpha: MTLTextureSwizzle::Alpha,
 };
+
+/// Helpers for copying pixel data to and from a texture.
+#[cfg(all(feature = "alloc", feature = "MTLResource", feature = "MTLTypes"))]
+pub trait MTLTextureExt: MTLTexture + objc2::Message {
+    /// Copy the pixels in the given region of a 1D or 2D texture into a new
+    /// `Vec`.
+    ///
+    /// The rows are tightly packed, so the returned vector contains
+    /// `region.size.width * region.size.height * bytes_per_pixel` bytes.
+    ///
+    /// If the texture uses [`MTLStorageMode::Managed`], you must synchronize
+    /// it with a blit command encoder before reading it on the CPU.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the texture's storage mode is [`MTLStorageMode::Private`] or
+    /// [`MTLStorageMode::Memoryless`], since the CPU cannot access those.
+    ///
+    /// Panics if the region has a depth other than 1.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// - `bytes_per_pixel` must be the size of a pixel in the texture's
+    ///   pixel format (compressed formats are not supported).
+    /// - The region must be within the bounds of the given mipmap level.
+    /// - The GPU must not be writing to the texture concurrently.
+    #[doc(alias = "getBytes:bytesPerRow:fromRegion:mipmapLevel:")]
+    unsafe fn get_bytes(
+        &self,
+        region: MTLRegion,
+        mipmap_level: usize,
+        bytes_per_pixel: usize,
+    ) -> Vec<u8> {
+        assert_cpu_accessible(self.storageMode());
+        let (bytes_per_row, len) = region_layout(region, bytes_per_pixel);
+
+        let mut bytes = Vec::<u8>::with_capacity(len);
+        let ptr = NonNull::new(bytes.as_mut_ptr()).unwrap();
+        // SAFETY: The buffer is large enough to hold the region, the rest
+        // is upheld by the caller.
+        unsafe {
+            self.getBytes_bytesPerRow_fromRegion_mipmapLevel(
+                ptr.cast(),
+                bytes_per_row,
+                region,
+                mipmap_level,
+            )
+        };
+        // SAFETY: The bytes were initialized by `getBytes:...` above.
+        unsafe { bytes.set_len(len) };
+        bytes
+    }
+
+    /// Copy the given tightly packed pixels into a region of a 1D or 2D
+    /// texture.
+    ///
+    /// This is the counterpart to [`get_bytes`][Self::get_bytes].
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the texture's storage mode is [`MTLStorageMode::Private`] or
+    /// [`MTLStorageMode::Memoryless`], since the CPU cannot access those.
+    ///
+    /// Panics if the region has a depth other than 1, or if `bytes` does not
+    /// have the length `region.size.width * region.size.height * bytes_per_pixel`.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// - `bytes_per_pixel` must be the size of a pixel in the texture's
+    ///   pixel format (compressed formats are not supported).
+    /// - The region must be within the bounds of the given mipmap level.
+    /// - The GPU must not be accessing the texture concurrently.
+    #[doc(alias = "replaceRegion:mipmapLevel:withBytes:bytesPerRow:")]
+    unsafe fn replace_region(
+        &self,
+        region: MTLRegion,
+        mipmap_level: usize,
+        bytes: &[u8],
+        bytes_per_pixel: usize,
+    ) {
+        assert_cpu_accessible(self.storageMode());
+        let (bytes_per_row, len) = region_layout(region, bytes_per_pixel);
+        assert_eq!(bytes.len(), len, "invalid number of bytes for region");
+
+        let ptr = NonNull::new(bytes.as_ptr().cast_mut()).unwrap();
+        // SAFETY: The buffer contains the entire region, and Metal does not
+        // write to it. The rest is upheld by the caller.
+        unsafe {
+            self.replaceRegion_mipmapLevel_withBytes_bytesPerRow(
+                region,
+                mipmap_level,
+                ptr.cast(),
+                bytes_per_row,
+            )
+        };
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "MTLResource", feature = "MTLTypes"))]
+impl<P: MTLTexture + objc2::Message> MTLTextureExt for P {}
+
+#[cfg(all(feature = "alloc", feature = "MTLResource", feature = "MTLTypes"))]
+#[track_caller]
+fn assert_cpu_accessible(storage_mode: MTLStorageMode) {
+    assert!(
+        storage_mode != MTLStorageMode::Private && storage_mode != MTLStorageMode::Memoryless,
+        "texture with storage mode {storage_mode:?} cannot be accessed by the CPU",
+    );
+}
+
+/// Compute the bytes per row and the total length of a tightly packed region.
+#[cfg(all(feature = "alloc", feature = "MTLResource", feature = "MTLTypes"))]
+#[track_caller]
+fn region_layout(region: MTLRegion, bytes_per_pixel: usize) -> (usize, usize) {
+    assert_eq!(region.size.depth, 1, "only 1D and 2D regions are supported");
+    let bytes_per_row = region
+        .size
+        .width
+        .checked_mul(bytes_per_pixel)
+        .expect("region too large");
+    let len = bytes_per_row
+        .checked_mul(region.size.height)
+        .expect("region too large");
+    (bytes_per_row, len)
+}
@@ -0,0 +1,31 @@
+#![cfg(all(
+    feature = "MTLDevice",
+    feature = "MTLCommandQueue",
+    feature = "MTLCommandBuffer"
+))]
+use objc2_metal::{
+    MTLCommandBuffer, MTLCommandBufferExt, MTLCommandBufferStatus, MTLCommandQueue,
+    MTLCreateSystemDefaultDevice, MTLDevice,
+};
+
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {}
+
+#[test]
+#[ignore = "doesn't work in CI"]
+fn empty_buffer_completes() {
+    let device = MTLCreateSystemDefaultDevice().expect("no Metal device");
+    let queue = device.newCommandQueue().unwrap();
+    let buffer = queue.commandBuffer().unwrap();
+
+    // Not yet finished.
+    assert_eq!(buffer.status(), MTLCommandBufferStatus::NotEnqueued);
+    assert!(buffer.result().is_none());
+
+    buffer.commit();
+    buffer.waitUntilCompleted();
+
+    assert_eq!(buffer.status(), MTLCommandBufferStatus::Completed);
+    assert!(buffer.error().is_none());
+    assert!(buffer.result().unwrap().is_ok());
+}
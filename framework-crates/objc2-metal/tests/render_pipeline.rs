@@ -0,0 +1,71 @@
+#![cfg(all(
+    feature = "MTLDevice",
+    feature = "MTLLibrary",
+    feature = "MTLPixelFormat",
+    feature = "MTLRenderPipeline"
+))]
+use objc2_foundation::ns_string;
+use objc2_metal::{
+    MTLCreateSystemDefaultDevice, MTLDevice, MTLDeviceExt, MTLLibrary, MTLPixelFormat,
+    MTLRenderPipelineDescriptorBuilder, MTLRenderPipelineState,
+};
+
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {}
+
+const SHADERS: &str = r#"
+#include <metal_stdlib>
+using namespace metal;
+
+vertex float4 vertex_main(uint vid [[vertex_id]]) {
+    return float4(0.0, 0.0, 0.0, 1.0);
+}
+
+fragment half4 fragment_main() {
+    return half4(1.0);
+}
+"#;
+
+#[test]
+#[ignore = "doesn't work in CI"]
+fn trivial_pipeline() {
+    let device = MTLCreateSystemDefaultDevice().expect("no Metal device");
+    let library = device
+        .newLibraryWithSource_options_error(ns_string!(SHADERS), None)
+        .unwrap();
+    let vertex = library
+        .newFunctionWithName(ns_string!("vertex_main"))
+        .unwrap();
+    let fragment = library
+        .newFunctionWithName(ns_string!("fragment_main"))
+        .unwrap();
+
+    let descriptor = MTLRenderPipelineDescriptorBuilder::new()
+        .label(ns_string!("trivial"))
+        .vertex_function(&vertex)
+        .fragment_function(&fragment)
+        .color_attachment_format(0, MTLPixelFormat::BGRA8Unorm)
+        .build();
+
+    let pipeline = device.new_render_pipeline(&descriptor).unwrap();
+    assert_eq!(pipeline.label().unwrap().to_string(), "trivial");
+}
+
+#[test]
+#[ignore = "doesn't work in CI"]
+fn missing_vertex_function() {
+    let device = MTLCreateSystemDefaultDevice().expect("no Metal device");
+    let descriptor = MTLRenderPipelineDescriptorBuilder::new()
+        .color_attachment_format(0, MTLPixelFormat::BGRA8Unorm)
+        .build();
+
+    // The error out-parameter is converted to `Err`.
+    let _err = device.new_render_pipeline(&descriptor).unwrap_err();
+}
+
+#[test]
+#[should_panic = "color attachment index 8 out of bounds"]
+fn color_attachment_out_of_bounds() {
+    let _ = MTLRenderPipelineDescriptorBuilder::new()
+        .color_attachment_format(8, MTLPixelFormat::BGRA8Unorm);
+}
@@ -0,0 +1,47 @@
+#![cfg(all(
+    feature = "MTLDevice",
+    feature = "MTLTexture",
+    feature = "MTLResource",
+    feature = "MTLTypes",
+    feature = "MTLPixelFormat",
+    feature = "alloc"
+))]
+use objc2_metal::{
+    MTLCreateSystemDefaultDevice, MTLDevice, MTLOrigin, MTLPixelFormat, MTLRegion, MTLSize,
+    MTLStorageMode, MTLTextureDescriptor, MTLTextureExt,
+};
+
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {}
+
+#[test]
+#[ignore = "doesn't work in CI"]
+fn write_read_back() {
+    let device = MTLCreateSystemDefaultDevice().expect("no Metal device");
+
+    let texture = unsafe {
+        let desc = MTLTextureDescriptor::texture2DDescriptorWithPixelFormat_width_height_mipmapped(
+            MTLPixelFormat::RGBA8Unorm,
+            4,
+            4,
+            false,
+        );
+        // The CPU can only access shared (or managed) textures.
+        desc.setStorageMode(MTLStorageMode::Shared);
+        device.newTextureWithDescriptor(&desc).unwrap()
+    };
+
+    let region = MTLRegion {
+        origin: MTLOrigin { x: 0, y: 0, z: 0 },
+        size: MTLSize {
+            width: 4,
+            height: 4,
+            depth: 1,
+        },
+    };
+    let pixels: Vec<u8> = (0..4 * 4 * 4).collect();
+    unsafe { texture.replace_region(region, 0, &pixels, 4) };
+
+    let read = unsafe { texture.get_bytes(region, 0, 4) };
+    assert_eq!(read, pixels);
+}